@@ -1,4 +1,7 @@
+use crate::version::BumpKind;
 use clap::{Parser, ValueEnum};
+use log::LevelFilter;
+use semver::Version;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Default)]
 pub enum SupportedTypes {
@@ -7,6 +10,69 @@ pub enum SupportedTypes {
     TOML,
     PackageJSON,
     TauriConfig,
+    Ruby,
+    RDescription,
+    Julia,
+    Swift,
+    AssemblyInfo,
+    Plist,
+    Snapcraft,
+    AnsibleGalaxy,
+    Zig,
+    Vcpkg,
+    Conan,
+    Wix,
+    Maven,
+    GradleProperties,
+    Nimble,
+    Dockerfile,
+    Godot,
+    Cabal,
+    Dune,
+    Ros,
+    Lerna,
+    EnvFile,
+    SetupCfg,
+    Json,
+    GenericToml,
+    Pubspec,
+    Readme,
+    Unity,
+    BukkitPlugin,
+    Dotnet,
+    #[value(name = "cmake")]
+    CMake,
+}
+
+impl SupportedTypes {
+    /// The types `All` runs. Every other parser is opt-in through `--types`, so a plain run doesn't
+    /// rewrite vendored or unrelated files such as a third-party `CMakeLists.txt`.
+    pub const DEFAULT: [SupportedTypes; 4] =
+        [SupportedTypes::TOML, SupportedTypes::PackageJSON, SupportedTypes::TauriConfig, SupportedTypes::Lerna];
+
+    /// Expands `All` into the `DEFAULT` types; other variants map to themselves
+    pub fn expand(self) -> Vec<SupportedTypes> {
+        match self {
+            SupportedTypes::All => Self::DEFAULT.to_vec(),
+            other => vec![other],
+        }
+    }
+
+    /// Every type except `All`, in declaration order
+    pub fn concrete() -> Vec<SupportedTypes> {
+        Self::value_variants()
+            .iter()
+            .copied()
+            .filter(|t| *t != SupportedTypes::All)
+            .collect()
+    }
+
+    /// The command-line name of this type, e.g. `package-json`
+    pub fn name(self) -> String {
+        self.to_possible_value()
+            .map(|v| v.get_name().to_string())
+            .unwrap_or_default()
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Default)]
@@ -19,6 +85,29 @@ pub enum GitMode {
     CommitTag,
 }
 
+/// How a run reports the changes it made, or under `--dry-run` would make
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum, Default)]
+pub enum OutputFormat {
+    /// Log each file that changes
+    #[default]
+    Log,
+    /// Also print a unified diff of every change to stdout, ready for `git apply`. Requires `--dry-run`
+    Patch,
+    /// After the run, print a JSON report per path to stdout: whether it changed, the previous
+    /// and new versions, files, commit and tag. `--print-report-schema` describes it
+    Json,
+}
+
+/// What `--exclude-prerelease` does when every version tag is a prerelease
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum, Default)]
+pub enum PrereleaseFallback {
+    /// Use the highest prerelease tag
+    #[default]
+    Highest,
+    /// Fail instead of using a prerelease tag
+    Error,
+}
+
 #[derive(Debug, Parser)]
 #[command(author, version, about, bin_name = "uv")]
 pub struct Arguments {
@@ -26,19 +115,293 @@ pub struct Arguments {
     pub supported_types: SupportedTypes,
     #[arg(long, short, value_enum, ignore_case = true, default_value_t = GitMode::None)]
     pub git_mode: GitMode,
-    #[arg(long, short, default_value = "./")]
-    pub path: String,
+    /// Project directory; repeat to update several independent roots in one run
+    #[arg(long = "path", short, default_value = "./")]
+    pub paths: Vec<String>,
+    /// Repository to commit, tag and push in, instead of the one containing each --path
+    #[arg(long, value_name = "DIR")]
+    pub git_root: Option<String>,
     #[arg(long, short)]
     pub verbose: bool,
+    /// Only log errors
+    #[arg(long, short, conflicts_with = "verbose")]
+    pub quiet: bool,
+    /// Log the git step's progress (commit, tag, push) at debug level, so it only shows with --verbose, keeping file update logs
+    #[arg(long)]
+    pub quiet_git: bool,
+    /// Disable colored log output (also honored via the NO_COLOR environment variable)
+    #[arg(long)]
+    pub no_color: bool,
     /// Disable .gitignore and .uvignore file processing during file discovery
     #[arg(long)]
     pub no_ignore: bool,
     /// Disable TLS certificate verification for git operations
     #[arg(long)]
     pub allow_insecure: bool,
+    /// Branch to push HEAD to (defaults to the current branch; required on a detached HEAD)
+    #[arg(long)]
+    pub branch: Option<String>,
+    /// Branch on the remote to push to when it differs from the local one (defaults to the local branch's name)
+    #[arg(long, value_name = "NAME")]
+    pub push_branch: Option<String>,
+    /// Only tag and push when on this branch; on any other branch just commit and warn
+    #[arg(long, value_name = "BRANCH")]
+    pub release_branch: Option<String>,
+    /// Remote to push to; repeat or comma-separate to push to several (defaults to `origin`)
+    #[arg(long = "remote", value_name = "NAME", value_delimiter = ',')]
+    pub remotes: Vec<String>,
+    /// Move an existing release tag to the new commit instead of aborting before the commit
+    #[arg(long)]
+    pub force_tag: bool,
+    /// Fetch and fast-forward the branch from the remote before committing and pushing
+    #[arg(long)]
+    pub pull_before_push: bool,
+    /// Amend HEAD with the version changes instead of creating a new commit (refused if HEAD was pushed, unless --force-tag)
+    #[arg(long)]
+    pub amend: bool,
+    /// GPG-sign the release commit and tag (checked before committing; uses the `git` executable)
+    #[arg(long)]
+    pub sign: bool,
+    /// Commit and tag with the `git` executable instead of libgit2, so local git hooks run
+    #[arg(long)]
+    pub use_git_cli: bool,
+    /// Pass `--no-verify` to `git commit` to skip hooks (requires --use-git-cli)
+    #[arg(long, requires = "use_git_cli")]
+    pub no_verify: bool,
+    /// If a push fails, delete the new local tag and reset the branch to before the release commit, unless another remote took the push
+    #[arg(long)]
+    pub rollback_on_push_failure: bool,
+    /// Append a body listing the changed files to the release commit message
+    #[arg(long)]
+    pub detailed_commit: bool,
+    /// Tag name template; `{version}` is replaced by the version (default `v{version}`)
+    #[arg(long, value_name = "FORMAT", conflicts_with = "tag_prefix")]
+    pub tag_format: Option<String>,
+    /// Annotation message template for the release tag; `{version}`, `{tag}` and `{date}` are replaced (default `Release {tag}`)
+    #[arg(long, value_name = "TEMPLATE", conflicts_with = "lightweight_tag")]
+    pub tag_annotation: Option<String>,
+    /// Create a lightweight release tag instead of an annotated one
+    #[arg(long, conflicts_with = "sign")]
+    pub lightweight_tag: bool,
+    /// Tag (and with commit-push-tag, push) the existing HEAD for the version the files hold, without updating files or committing
+    #[arg(
+        long,
+        conflicts_with_all = ["new_version", "bump", "auto_bump", "flutter_bump", "amend", "changelog_unreleased", "print_tag"]
+    )]
+    pub tag_existing_commit: bool,
+    /// Tag to start the commit range from instead of the latest version tag
+    #[arg(long, value_name = "TAG")]
+    pub since_tag: Option<String>,
+    /// Only follow the first parent of merges when reading commits since the last tag, leaving out merged branch commits
+    #[arg(long)]
+    pub first_parent: bool,
+    /// Ignore prerelease tags (e.g. `v1.3.0-rc.1`) when finding the latest version tag
+    #[arg(long)]
+    pub exclude_prerelease: bool,
+    /// With --exclude-prerelease, what to do when every version tag is a prerelease
+    #[arg(long, value_enum, value_name = "FALLBACK", default_value_t = PrereleaseFallback::Highest, requires = "exclude_prerelease")]
+    pub prerelease_fallback: PrereleaseFallback,
+    /// Unix timestamp to use as the author and committer time of the release commit and tag,
+    /// for reproducible releases (defaults to `SOURCE_DATE_EPOCH` when set, otherwise now)
+    #[arg(long, value_name = "EPOCH", allow_negative_numbers = true)]
+    pub commit_date: Option<i64>,
+    /// Prefix for the tag name, shorthand for `--tag-format '<PREFIX>{version}'`
+    #[arg(long, value_name = "PREFIX")]
+    pub tag_prefix: Option<String>,
+    /// Tag releases with the bare version (`1.2.3` instead of `v1.2.3`), shorthand for `--tag-format '{version}'`
+    #[arg(long, conflicts_with_all = ["tag_format", "tag_prefix"])]
+    pub no_tag_prefix: bool,
+    /// Print the tag name the git step would create for the computed version, then exit
+    #[arg(long)]
+    pub print_tag: bool,
+    /// Identity for the release commit and tag as "Name <email>" (overrides git config)
+    #[arg(long, value_name = "NAME <EMAIL>")]
+    pub author: Option<String>,
+    /// Report what would change without writing files or running git operations
+    #[arg(long)]
+    pub dry_run: bool,
+    /// How changes are reported; `patch` prints a diff for `git apply` to stdout (requires --dry-run), `json` a report per path
+    #[arg(long, value_enum, value_name = "FORMAT", default_value_t = OutputFormat::Log, requires_if("patch", "dry_run"))]
+    pub output: OutputFormat,
+    /// Also write the final version string to this file, creating parent directories as needed
+    #[arg(long, value_name = "PATH")]
+    pub write_version_to: Option<String>,
+    /// Move the `## [Unreleased]` entries of CHANGELOG.md under a new `## [version] - date` heading, leaving an empty Unreleased section
+    #[arg(long)]
+    pub changelog_unreleased: bool,
+    /// Show a summary and ask for confirmation before writing anything
+    #[arg(long, short)]
+    pub interactive: bool,
+    /// Answer yes to the --interactive confirmation prompt
+    #[arg(long, short)]
+    pub yes: bool,
+    /// After the run, print the absolute paths of the updated files to stdout as a JSON array
+    #[arg(long)]
+    pub print_files_json: bool,
+    /// Print the JSON Schema of the `--output json` report objects, then exit
+    #[arg(long)]
+    pub print_report_schema: bool,
+    /// List the files each selected parser would consider, then exit without changing anything
+    #[arg(long)]
+    pub list_files: bool,
+    /// Shell command to run in each --path before anything is read or written; a nonzero exit aborts
+    #[arg(long, value_name = "COMMAND")]
+    pub pre_hook: Option<String>,
+    /// Version to start from when no file has one yet; files lacking a version field get one inserted
+    #[arg(long, value_name = "VERSION")]
+    pub initial_version: Option<String>,
+    /// Name of the variable updated by the `env-file` type
+    #[arg(long, value_name = "NAME", default_value = "VERSION")]
+    pub env_variable: String,
+    /// JSON Pointer to the version string updated by the `json` type, e.g. `/metadata/version`
+    #[arg(long, value_name = "POINTER", requires = "json_file")]
+    pub json_pointer: Option<String>,
+    /// Filename glob selecting the files the `json` type updates, e.g. `config/*.json`
+    #[arg(long, value_name = "GLOB", requires = "json_pointer")]
+    pub json_file: Option<String>,
+    /// Text around the version updated by the `readme` type, with a `{version}` placeholder (e.g. `cargo add my-crate@{version}`); repeatable
+    #[arg(long = "readme-pattern", value_name = "PATTERN")]
+    pub readme_patterns: Vec<String>,
+    /// Dotted key path to the version string updated by the `generic-toml` type, e.g. `package.metadata.app.version`
+    #[arg(long, value_name = "KEY", requires = "toml_file")]
+    pub toml_key: Option<String>,
+    /// Filename glob selecting the files the `generic-toml` type updates, e.g. `**/Cargo.toml`
+    #[arg(long, value_name = "GLOB", requires = "toml_key")]
+    pub toml_file: Option<String>,
+    /// Insert the version into matched files that lack one (e.g. a Dockerfile without a version label)
+    #[arg(long)]
+    pub insert_missing: bool,
+    /// Identifier of the Swift string constant that holds the version
+    #[arg(long, value_name = "IDENT", default_value = "version")]
+    pub swift_identifier: String,
+    /// Reset the 4th (revision) component of four-part versions such as WiX to 0 instead of preserving it
+    #[arg(long)]
+    pub reset_revision: bool,
+    /// Show a progress display while scanning and updating files (terminal only, hidden with --quiet)
+    #[arg(long)]
+    pub progress: bool,
+    /// Also increment the build number (`CFBundleVersion` in Info.plist, `versionCode` in gradle.properties, Unity's Android and iPhone build numbers)
+    #[arg(long, visible_alias = "increment-version-code")]
+    pub bump_build_number: bool,
+    /// Update every version occurrence in a file, not just the first, warning if they disagree
+    #[arg(long)]
+    pub all_occurrences: bool,
+    /// Exit with code 3 instead of 0 when every file already holds the target version
+    #[arg(long)]
+    pub error_on_noop: bool,
+    /// Skip matched files larger than this many bytes (e.g. huge generated JSON) instead of reading them
+    #[arg(long, value_name = "BYTES")]
+    pub max_file_size: Option<u64>,
+    /// Save each file's original contents to `<file>.bak` before rewriting it
+    #[arg(long)]
+    pub backup: bool,
+    /// Restore files from the `.bak` backups left by a previous `--backup` run, then exit
+    #[arg(long, conflicts_with = "backup")]
+    pub restore: bool,
+    /// Re-read every updated file after writing and fail if any doesn't hold the new version
+    #[arg(long)]
+    pub verify: bool,
+    /// Only update `[workspace.package]` in the nearest workspace root Cargo.toml, without walking members
+    #[arg(long)]
+    pub workspace_root_only: bool,
+    /// Leave the crates listed in the workspace root's `members` at their own versions and update only the root `Cargo.toml`
+    #[arg(long, conflicts_with = "workspace_root_only")]
+    pub exclude_workspace_members: bool,
+    /// After bumping, update dependency requirements on the bumped crates in every `Cargo.toml` (third-party deps are left alone)
+    #[arg(long)]
+    pub update_internal_deps: bool,
+    /// For `lerna`, also set every workspace package.json (required for independent-mode repos, which are otherwise skipped)
+    #[arg(long)]
+    pub fanout: bool,
+    /// Pad partial versions found in files (e.g. `1.2` -> `1.2.0`) instead of failing
+    #[arg(long, conflicts_with = "strict")]
+    pub coerce: bool,
+    /// Reject partial or malformed versions found in files, naming the offending file (default)
+    #[arg(long)]
+    pub strict: bool,
+    /// Which part of the version to bump when no new version is given (default patch)
+    #[arg(long, value_enum, value_name = "KIND", conflicts_with_all = ["new_version", "auto_bump"])]
+    pub bump: Option<BumpKind>,
+    /// Label for a prerelease cycle started by `--bump prerelease` from a stable version (`1.2.0` -> `1.2.1-<LABEL>.0`)
+    #[arg(long, value_name = "LABEL", default_value = crate::version::DEFAULT_PRERELEASE_LABEL)]
+    pub prerelease_label: String,
+    /// Keep the current prerelease on a major or minor bump (`1.2.0-rc.1` -> `1.3.0-rc.1`) instead of clearing it.
+    /// Patch bumps never clear it: they advance the prerelease (`1.2.3-beta.1` -> `1.2.3-beta.2`) with or without this flag
+    #[arg(long, conflicts_with = "new_version")]
+    pub keep_prerelease: bool,
+    /// Flutter-style bump: increment the `+build` number on every run, and the version only when --bump or --auto-bump says so.
+    /// Requires `--types pubspec`
+    #[arg(long, conflicts_with = "new_version")]
+    pub flutter_bump: bool,
+    /// Before updating, fail if the files matched by any one type hold different versions, listing them
+    #[arg(long)]
+    pub assert_consistent: bool,
+    /// Before updating, compare the files' current version with the latest version tag and abort if they differ
+    #[arg(long)]
+    pub abort_on_mismatch: bool,
+    /// Pick the bump from Conventional Commits since the last version tag (or --since-tag) instead of always bumping patch
+    #[arg(long, conflicts_with = "new_version")]
+    pub auto_bump: bool,
+    /// With --auto-bump, apply the normal mapping to 0.x versions instead of shifting it down one level
+    #[arg(long, requires = "auto_bump")]
+    pub strict_semver: bool,
+    /// Version for the `toml` type, overriding the new version for Cargo.toml files only
+    #[arg(long, value_name = "VERSION")]
+    pub toml_version: Option<Version>,
+    /// Version for the `package-json` type, overriding the new version for package.json files only
+    #[arg(long, value_name = "VERSION")]
+    pub package_json_version: Option<Version>,
+    /// The new version to set, or `-` to read it from stdin. If omitted, increments the patch version
     pub new_version: Option<String>,
 }
 
+impl Arguments {
+    /// The version given for `supported_type` alone (e.g. `--toml-version`), if any
+    pub fn version_override(&self, supported_type: SupportedTypes) -> Option<&Version> {
+        match supported_type {
+            SupportedTypes::TOML => self.toml_version.as_ref(),
+            SupportedTypes::PackageJSON => self.package_json_version.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// The log level from `--quiet` and `--verbose`
+    pub fn log_level(&self) -> LevelFilter {
+        if self.quiet {
+            LevelFilter::Error
+        } else if self.verbose {
+            LevelFilter::Debug
+        } else {
+            LevelFilter::Info
+        }
+    }
+
+    /// The tag name template from `--tag-format`, `--tag-prefix` or `--no-tag-prefix`, if any was given
+    pub fn tag_format(&self) -> Option<String> {
+        if self.no_tag_prefix {
+            return Some("{version}".to_string());
+        }
+        self.tag_format
+            .clone()
+            .or_else(|| self.tag_prefix.as_ref().map(|prefix| format!("{}{{version}}", prefix)))
+    }
+
+    /// The commit time from `--commit-date`, falling back to the `SOURCE_DATE_EPOCH` environment variable
+    pub fn commit_date(&self) -> anyhow::Result<Option<i64>> {
+        if self.commit_date.is_some() {
+            return Ok(self.commit_date);
+        }
+        match std::env::var("SOURCE_DATE_EPOCH") {
+            Ok(epoch) if !epoch.trim().is_empty() => epoch
+                .trim()
+                .parse()
+                .map(Some)
+                .map_err(|_| anyhow::anyhow!("SOURCE_DATE_EPOCH must be a Unix timestamp, got '{}'", epoch)),
+            _ => Ok(None),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -48,9 +411,82 @@ mod tests {
         let args = Arguments::parse_from(["uv"]);
         assert_eq!(args.supported_types, SupportedTypes::All);
         assert_eq!(args.git_mode, GitMode::None);
-        assert_eq!(args.path, "./");
+        assert_eq!(args.paths, ["./"]);
         assert!(!args.verbose);
+        assert!(!args.quiet);
+        assert!(!args.no_color);
+        assert!(args.git_root.is_none());
+        assert!(!args.quiet_git);
         assert!(!args.allow_insecure);
+        assert!(!args.dry_run);
+        assert_eq!(args.output, OutputFormat::Log);
+        assert!(args.branch.is_none());
+        assert!(args.remotes.is_empty());
+        assert!(!args.force_tag);
+        assert!(args.release_branch.is_none());
+        assert!(!args.rollback_on_push_failure);
+        assert!(!args.amend);
+        assert!(!args.sign);
+        assert!(args.tag_annotation.is_none());
+        assert!(!args.lightweight_tag);
+        assert!(!args.tag_existing_commit);
+        assert!(args.push_branch.is_none());
+        assert!(!args.use_git_cli);
+        assert!(!args.no_verify);
+        assert!(args.since_tag.is_none());
+        assert!(args.commit_date.is_none());
+        assert!(!args.first_parent);
+        assert!(!args.exclude_prerelease);
+        assert_eq!(args.prerelease_fallback, PrereleaseFallback::Highest);
+        assert!(args.bump.is_none());
+        assert_eq!(args.prerelease_label, "rc");
+        assert!(!args.keep_prerelease);
+        assert!(!args.flutter_bump);
+        assert!(!args.abort_on_mismatch);
+        assert!(!args.assert_consistent);
+        assert!(!args.auto_bump);
+        assert!(!args.strict_semver);
+        assert!(!args.pull_before_push);
+        assert!(!args.detailed_commit);
+        assert!(args.tag_format.is_none());
+        assert!(args.tag_prefix.is_none());
+        assert!(!args.no_tag_prefix);
+        assert!(!args.print_tag);
+        assert!(args.author.is_none());
+        assert!(args.write_version_to.is_none());
+        assert!(!args.changelog_unreleased);
+        assert!(!args.print_files_json);
+        assert!(!args.print_report_schema);
+        assert!(args.toml_version.is_none());
+        assert!(args.package_json_version.is_none());
+        assert!(!args.interactive);
+        assert!(!args.yes);
+        assert!(!args.list_files);
+        assert!(!args.coerce);
+        assert!(args.pre_hook.is_none());
+        assert!(args.initial_version.is_none());
+        assert!(!args.insert_missing);
+        assert_eq!(args.swift_identifier, "version");
+        assert_eq!(args.env_variable, "VERSION");
+        assert!(args.json_pointer.is_none());
+        assert!(args.json_file.is_none());
+        assert!(args.toml_key.is_none());
+        assert!(args.toml_file.is_none());
+        assert!(args.readme_patterns.is_empty());
+        assert!(!args.bump_build_number);
+        assert!(!args.reset_revision);
+        assert!(!args.workspace_root_only);
+        assert!(!args.exclude_workspace_members);
+        assert!(!args.update_internal_deps);
+        assert!(!args.fanout);
+        assert!(!args.progress);
+        assert!(!args.verify);
+        assert!(args.max_file_size.is_none());
+        assert!(!args.backup);
+        assert!(!args.restore);
+        assert!(!args.error_on_noop);
+        assert!(!args.all_occurrences);
+        assert!(!args.strict);
         assert!(args.new_version.is_none());
     }
 
@@ -60,6 +496,12 @@ mod tests {
         assert_eq!(args.new_version, Some("1.2.3".to_string()));
     }
 
+    #[test]
+    fn test_parse_version_from_stdin_marker() {
+        let args = Arguments::parse_from(["uv", "-"]);
+        assert_eq!(args.new_version, Some("-".to_string()));
+    }
+
     #[test]
     fn test_parse_types_toml() {
         let args = Arguments::parse_from(["uv", "-t", "toml"]);
@@ -105,7 +547,7 @@ mod tests {
     #[test]
     fn test_parse_path() {
         let args = Arguments::parse_from(["uv", "-p", "/some/path"]);
-        assert_eq!(args.path, "/some/path");
+        assert_eq!(args.paths, ["/some/path"]);
     }
 
     #[test]
@@ -114,6 +556,83 @@ mod tests {
         assert!(args.verbose);
     }
 
+    #[test]
+    fn test_parse_quiet_and_no_color() {
+        let args = Arguments::parse_from(["uv", "-q", "--no-color"]);
+        assert!(args.quiet);
+        assert!(args.no_color);
+    }
+
+    #[test]
+    fn test_quiet_conflicts_with_verbose() {
+        let result = Arguments::try_parse_from(["uv", "--quiet", "--verbose"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_interactive_yes() {
+        let args = Arguments::parse_from(["uv", "-i", "-y"]);
+        assert!(args.interactive);
+        assert!(args.yes);
+    }
+
+    #[test]
+    fn test_bump_kind_flag() {
+        let args = Arguments::parse_from(["uv", "--flutter-bump", "--bump", "patch"]);
+        assert!(args.flutter_bump);
+        assert_eq!(args.bump, Some(BumpKind::Patch));
+        assert!(Arguments::try_parse_from(["uv", "--bump", "minor", "--auto-bump"]).is_err());
+        assert!(Arguments::try_parse_from(["uv", "--bump", "huge"]).is_err());
+    }
+
+    #[test]
+    fn test_bump_build_number_flag() {
+        let args = Arguments::parse_from(["uv", "--bump-build-number"]);
+        assert!(args.bump_build_number);
+        let args = Arguments::parse_from(["uv", "--increment-version-code"]);
+        assert!(args.bump_build_number);
+        let help = <Arguments as clap::CommandFactory>::command().render_help().to_string();
+        assert!(help.contains("--increment-version-code"));
+    }
+
+    #[test]
+    fn test_remote_repeated_and_comma_separated() {
+        let args = Arguments::parse_from(["uv", "--remote", "origin", "--remote", "gitlab,backup"]);
+        assert_eq!(args.remotes, ["origin", "gitlab", "backup"]);
+    }
+
+    #[test]
+    fn test_parse_multiple_paths() {
+        let args = Arguments::parse_from(["uv", "--path", "./repo-a", "-p", "./repo-b"]);
+        assert_eq!(args.paths, ["./repo-a", "./repo-b"]);
+    }
+
+    #[test]
+    fn test_tag_format_conflicts_with_tag_prefix() {
+        assert!(Arguments::try_parse_from(["uv", "--tag-format", "release-{version}", "--tag-prefix", "r"]).is_err());
+    }
+
+    #[test]
+    fn test_output_patch_requires_dry_run_but_json_does_not() {
+        assert!(Arguments::try_parse_from(["uv", "--output", "patch"]).is_err());
+        assert_eq!(Arguments::parse_from(["uv", "--dry-run", "--output", "patch"]).output, OutputFormat::Patch);
+        assert_eq!(Arguments::parse_from(["uv", "--output", "json"]).output, OutputFormat::Json);
+    }
+
+    #[test]
+    fn test_no_tag_prefix() {
+        let args = Arguments::parse_from(["uv", "--no-tag-prefix"]);
+        assert_eq!(args.tag_format().as_deref(), Some("{version}"));
+        assert!(Arguments::try_parse_from(["uv", "--no-tag-prefix", "--tag-format", "v{version}"]).is_err());
+        assert!(Arguments::try_parse_from(["uv", "--no-tag-prefix", "--tag-prefix", "r"]).is_err());
+    }
+
+    #[test]
+    fn test_coerce_conflicts_with_strict() {
+        assert!(Arguments::try_parse_from(["uv", "--coerce"]).unwrap().coerce);
+        assert!(Arguments::try_parse_from(["uv", "--coerce", "--strict"]).is_err());
+    }
+
     #[test]
     fn test_parse_long_flags() {
         let args = Arguments::parse_from([
@@ -129,7 +648,7 @@ mod tests {
         ]);
         assert_eq!(args.supported_types, SupportedTypes::TOML);
         assert_eq!(args.git_mode, GitMode::Commit);
-        assert_eq!(args.path, "/test");
+        assert_eq!(args.paths, ["/test"]);
         assert!(args.verbose);
         assert_eq!(args.new_version, Some("2.0.0".to_string()));
     }
@@ -149,6 +668,217 @@ mod tests {
         assert_ne!(GitMode::Commit, GitMode::CommitPush);
     }
 
+    #[test]
+    fn test_parse_types_ruby() {
+        let args = Arguments::parse_from(["uv", "-t", "ruby"]);
+        assert_eq!(args.supported_types, SupportedTypes::Ruby);
+    }
+
+    #[test]
+    fn test_parse_types_r_description() {
+        let args = Arguments::parse_from(["uv", "-t", "r-description"]);
+        assert_eq!(args.supported_types, SupportedTypes::RDescription);
+    }
+
+    #[test]
+    fn test_parse_types_julia() {
+        let args = Arguments::parse_from(["uv", "-t", "julia"]);
+        assert_eq!(args.supported_types, SupportedTypes::Julia);
+    }
+
+    #[test]
+    fn test_parse_types_swift() {
+        let args = Arguments::parse_from(["uv", "-t", "swift"]);
+        assert_eq!(args.supported_types, SupportedTypes::Swift);
+    }
+
+    #[test]
+    fn test_parse_types_assembly_info() {
+        let args = Arguments::parse_from(["uv", "-t", "assembly-info"]);
+        assert_eq!(args.supported_types, SupportedTypes::AssemblyInfo);
+    }
+
+    #[test]
+    fn test_parse_types_plist() {
+        let args = Arguments::parse_from(["uv", "-t", "plist"]);
+        assert_eq!(args.supported_types, SupportedTypes::Plist);
+    }
+
+    #[test]
+    fn test_parse_types_snapcraft() {
+        let args = Arguments::parse_from(["uv", "-t", "snapcraft"]);
+        assert_eq!(args.supported_types, SupportedTypes::Snapcraft);
+    }
+
+    #[test]
+    fn test_parse_types_ansible_galaxy() {
+        let args = Arguments::parse_from(["uv", "-t", "ansible-galaxy"]);
+        assert_eq!(args.supported_types, SupportedTypes::AnsibleGalaxy);
+    }
+
+    #[test]
+    fn test_parse_types_zig() {
+        let args = Arguments::parse_from(["uv", "-t", "zig"]);
+        assert_eq!(args.supported_types, SupportedTypes::Zig);
+    }
+
+    #[test]
+    fn test_parse_types_vcpkg() {
+        let args = Arguments::parse_from(["uv", "-t", "vcpkg"]);
+        assert_eq!(args.supported_types, SupportedTypes::Vcpkg);
+    }
+
+    #[test]
+    fn test_supported_types_name() {
+        assert_eq!(SupportedTypes::PackageJSON.name(), "package-json");
+        assert_eq!(SupportedTypes::TOML.name(), "toml");
+    }
+
+    #[test]
+    fn test_parse_types_conan() {
+        let args = Arguments::parse_from(["uv", "-t", "conan"]);
+        assert_eq!(args.supported_types, SupportedTypes::Conan);
+    }
+
+    #[test]
+    fn test_parse_types_wix() {
+        let args = Arguments::parse_from(["uv", "-t", "wix"]);
+        assert_eq!(args.supported_types, SupportedTypes::Wix);
+    }
+
+    #[test]
+    fn test_parse_types_maven() {
+        let args = Arguments::parse_from(["uv", "-t", "maven"]);
+        assert_eq!(args.supported_types, SupportedTypes::Maven);
+    }
+
+    #[test]
+    fn test_parse_types_gradle_properties() {
+        let args = Arguments::parse_from(["uv", "-t", "gradle-properties"]);
+        assert_eq!(args.supported_types, SupportedTypes::GradleProperties);
+    }
+
+    #[test]
+    fn test_parse_types_nimble() {
+        let args = Arguments::parse_from(["uv", "-t", "nimble"]);
+        assert_eq!(args.supported_types, SupportedTypes::Nimble);
+    }
+
+    #[test]
+    fn test_parse_types_dockerfile() {
+        let args = Arguments::parse_from(["uv", "-t", "dockerfile"]);
+        assert_eq!(args.supported_types, SupportedTypes::Dockerfile);
+    }
+
+    #[test]
+    fn test_parse_types_godot() {
+        let args = Arguments::parse_from(["uv", "-t", "godot"]);
+        assert_eq!(args.supported_types, SupportedTypes::Godot);
+    }
+
+    #[test]
+    fn test_parse_types_cabal() {
+        let args = Arguments::parse_from(["uv", "-t", "cabal"]);
+        assert_eq!(args.supported_types, SupportedTypes::Cabal);
+    }
+
+    #[test]
+    fn test_parse_types_dune() {
+        let args = Arguments::parse_from(["uv", "-t", "dune"]);
+        assert_eq!(args.supported_types, SupportedTypes::Dune);
+    }
+
+    #[test]
+    fn test_parse_types_ros() {
+        let args = Arguments::parse_from(["uv", "-t", "ros"]);
+        assert_eq!(args.supported_types, SupportedTypes::Ros);
+    }
+
+    #[test]
+    fn test_parse_types_lerna() {
+        let args = Arguments::parse_from(["uv", "-t", "lerna"]);
+        assert_eq!(args.supported_types, SupportedTypes::Lerna);
+    }
+
+    #[test]
+    fn test_parse_types_env_file() {
+        let args = Arguments::parse_from(["uv", "-t", "env-file"]);
+        assert_eq!(args.supported_types, SupportedTypes::EnvFile);
+    }
+
+    #[test]
+    fn test_parse_types_setup_cfg() {
+        let args = Arguments::parse_from(["uv", "-t", "setup-cfg"]);
+        assert_eq!(args.supported_types, SupportedTypes::SetupCfg);
+    }
+
+    #[test]
+    fn test_parse_types_json() {
+        let args = Arguments::parse_from(["uv", "-t", "json"]);
+        assert_eq!(args.supported_types, SupportedTypes::Json);
+    }
+
+    #[test]
+    fn test_parse_types_generic_toml() {
+        let args = Arguments::parse_from(["uv", "-t", "generic-toml"]);
+        assert_eq!(args.supported_types, SupportedTypes::GenericToml);
+    }
+
+    #[test]
+    fn test_parse_types_pubspec() {
+        let args = Arguments::parse_from(["uv", "-t", "pubspec"]);
+        assert_eq!(args.supported_types, SupportedTypes::Pubspec);
+    }
+
+    #[test]
+    fn test_parse_types_readme() {
+        let args = Arguments::parse_from(["uv", "-t", "readme"]);
+        assert_eq!(args.supported_types, SupportedTypes::Readme);
+    }
+
+    #[test]
+    fn test_parse_types_unity() {
+        let args = Arguments::parse_from(["uv", "-t", "unity"]);
+        assert_eq!(args.supported_types, SupportedTypes::Unity);
+    }
+
+    #[test]
+    fn test_parse_types_bukkit_plugin() {
+        let args = Arguments::parse_from(["uv", "-t", "bukkit-plugin"]);
+        assert_eq!(args.supported_types, SupportedTypes::BukkitPlugin);
+    }
+
+    #[test]
+    fn test_parse_types_dotnet() {
+        let args = Arguments::parse_from(["uv", "-t", "dotnet"]);
+        assert_eq!(args.supported_types, SupportedTypes::Dotnet);
+    }
+
+    #[test]
+    fn test_parse_types_cmake() {
+        let args = Arguments::parse_from(["uv", "-t", "cmake"]);
+        assert_eq!(args.supported_types, SupportedTypes::CMake);
+    }
+
+    #[test]
+    fn test_version_override_per_type() {
+        let args = Arguments::parse_from(["uv", "--toml-version", "1.5.0", "--package-json-version", "2.0.0"]);
+        assert_eq!(args.version_override(SupportedTypes::TOML), Some(&Version::new(1, 5, 0)));
+        assert_eq!(args.version_override(SupportedTypes::PackageJSON), Some(&Version::new(2, 0, 0)));
+        assert_eq!(args.version_override(SupportedTypes::Ruby), None);
+        assert!(Arguments::try_parse_from(["uv", "--toml-version", "1.5"]).is_err());
+    }
+
+    #[test]
+    fn test_supported_types_expand() {
+        let all = SupportedTypes::All.expand();
+        assert!(!all.contains(&SupportedTypes::All));
+        assert_eq!(all[0], SupportedTypes::TOML);
+        assert!(!all.contains(&SupportedTypes::CMake));
+        assert!(SupportedTypes::concrete().contains(&SupportedTypes::CMake));
+        assert_eq!(SupportedTypes::Ruby.expand(), vec![SupportedTypes::Ruby]);
+    }
+
     #[test]
     fn test_supported_types_equality() {
         assert_eq!(SupportedTypes::All, SupportedTypes::All);
@@ -0,0 +1,36 @@
+use crate::arguments::SupportedTypes;
+use crate::parsers::{WalkOptions, backup_path};
+use crate::version::matching_files_for;
+use anyhow::{Context, Result};
+use log::info;
+use std::path::{Path, PathBuf};
+
+/// Restores the `<file>.bak` left by a `--backup` run over `<file>` and removes the backup, for
+/// every file under `path` that some parser matches. Other `.bak` files are left alone. Ignore
+/// rules apply as in a normal walk. Returns the restored files.
+pub fn restore_backups(path: impl AsRef<Path>, options: &WalkOptions) -> Result<Vec<PathBuf>> {
+    let path = path.as_ref();
+    let mut originals = Vec::new();
+    for supported_type in SupportedTypes::concrete() {
+        originals.extend(matching_files_for(supported_type, path, options)?);
+    }
+    originals.sort();
+    originals.dedup();
+
+    let mut restored = Vec::new();
+    for original in originals {
+        let backup = backup_path(&original);
+        if !backup.is_file() {
+            continue;
+        }
+        if options.dry_run {
+            info!("Would restore '{}' from '{}'", original.display(), backup.display());
+        } else {
+            std::fs::rename(&backup, &original)
+                .with_context(|| format!("Failed to restore '{}'", original.display()))?;
+            info!("Restored '{}'", original.display());
+        }
+        restored.push(original);
+    }
+    Ok(restored)
+}
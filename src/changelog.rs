@@ -0,0 +1,73 @@
+use crate::parsers::{WalkOptions, emit_patch};
+use anyhow::{Context, Result, bail};
+use log::info;
+use regex::Regex;
+use semver::Version;
+use std::path::{Path, PathBuf};
+
+/// Changelog that `--changelog-unreleased` edits, at the root of each project
+pub const CHANGELOG_FILE_NAME: &str = "CHANGELOG.md";
+
+/// Releases the `## [Unreleased]` section of a Keep a Changelog document: its entries move
+/// under a new `## [version] - date` heading, and an empty `## [Unreleased]` is added above it.
+/// Returns `None` if `contents` has no Unreleased heading.
+pub fn release_unreleased(contents: &str, version: &Version, date: &str) -> Result<Option<String>> {
+    let heading = Regex::new(r"(?mi)^##[ \t]+\[?unreleased\]?[ \t]*(\r?\n|$)")?;
+    let Some(captures) = heading.captures(contents) else {
+        return Ok(None);
+    };
+    let found = captures.get(0).map_or(0..0, |m| m.range());
+    let newline = if contents.contains("\r\n") { "\r\n" } else { "\n" };
+    let line_end = if captures[1].is_empty() { "" } else { newline };
+    Ok(Some(format!(
+        "{}## [Unreleased]{newline}{newline}## [{version}] - {date}{line_end}{}",
+        &contents[..found.start],
+        &contents[found.end..]
+    )))
+}
+
+/// Whether the `CHANGELOG.md` at `path` already has a `## [version]` heading, i.e. a release
+/// for `version` has nothing left to do
+pub fn is_released(path: &Path, version: &Version) -> Result<bool> {
+    let file = path.join(CHANGELOG_FILE_NAME);
+    let contents = std::fs::read_to_string(&file)
+        .with_context(|| format!("Failed to read '{}' for --changelog-unreleased", file.display()))?;
+    let heading = Regex::new(&format!(r"(?m)^##[ \t]+\[?{}\]?([ \t]|\r?$)", regex::escape(&version.to_string())))?;
+    Ok(heading.is_match(&contents))
+}
+
+/// A changelog with its Unreleased section already released in memory, ready to be written
+pub struct ChangelogRelease {
+    /// The changelog file.
+    pub file: PathBuf,
+    contents: String,
+    released: String,
+}
+
+/// Reads the `CHANGELOG.md` at `path` and applies `release_unreleased`, failing if the file or
+/// its Unreleased heading is missing. Nothing is written until `ChangelogRelease::write`, so
+/// callers can check the changelog before touching other files.
+pub fn prepare_release(path: &Path, version: &Version, date: &str) -> Result<ChangelogRelease> {
+    let file = path.join(CHANGELOG_FILE_NAME);
+    let contents = std::fs::read_to_string(&file)
+        .with_context(|| format!("Failed to read '{}' for --changelog-unreleased", file.display()))?;
+    let Some(released) = release_unreleased(&contents, version, date)? else {
+        bail!("'{}' has no '## [Unreleased]' heading", file.display());
+    };
+    Ok(ChangelogRelease { file, contents, released })
+}
+
+impl ChangelogRelease {
+    /// Writes the released changelog, or under `dry_run` only reports it. `root` is the project
+    /// directory patches are relative to. Returns the changelog's path.
+    pub fn write(self, root: &Path, options: &WalkOptions) -> Result<PathBuf> {
+        if options.dry_run {
+            info!("Would release the Unreleased section of '{}'", self.file.display());
+            emit_patch(options, root, &self.file, &self.contents, &self.released);
+        } else {
+            std::fs::write(&self.file, &self.released)?;
+            info!("Released the Unreleased section of '{}'", self.file.display());
+        }
+        Ok(self.file)
+    }
+}
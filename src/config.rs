@@ -0,0 +1,50 @@
+use crate::arguments::SupportedTypes;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Name of the optional per-project config file, read from the root of each `--path`
+pub const CONFIG_FILE_NAME: &str = "uv.toml";
+
+/// Project configuration loaded from `uv.toml`. Each top-level table is keyed by a type name
+/// (as passed to `--types`), e.g.
+///
+/// ```toml
+/// [toml]
+/// extra_files = ["version-config\\.toml"]
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Config {
+    #[serde(flatten)]
+    pub types: HashMap<String, TypeConfig>,
+}
+
+/// Settings for a single parser type
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TypeConfig {
+    /// Extra filename regexes OR'd into the parser's own, matched against the end of each path
+    #[serde(default)]
+    pub extra_files: Vec<String>,
+}
+
+impl Config {
+    /// Loads `uv.toml` from `root`, or returns an empty config if there is none
+    pub fn load(root: impl AsRef<Path>) -> Result<Self> {
+        let file = root.as_ref().join(CONFIG_FILE_NAME);
+        if !file.is_file() {
+            return Ok(Self::default());
+        }
+        let contents = std::fs::read_to_string(&file)?;
+        toml::from_str(&contents).with_context(|| format!("Failed to parse '{}'", file.display()))
+    }
+
+    /// The extra filename patterns configured for `supported_type`
+    pub fn extra_files(&self, supported_type: SupportedTypes) -> &[String] {
+        self.types
+            .get(&supported_type.name())
+            .map(|config| config.extra_files.as_slice())
+            .unwrap_or_default()
+    }
+}
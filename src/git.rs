@@ -1,14 +1,159 @@
 use anyhow::{Context, Result};
 use git2::{Cred, CredentialType, FetchOptions, PushOptions, RemoteCallbacks, Repository, Signature};
 use log::{debug, info, warn};
+use semver::Version;
 use std::cell::Cell;
 use std::path::{Path, PathBuf};
 
-use crate::arguments::GitMode;
+use crate::arguments::{GitMode, PrereleaseFallback, SupportedTypes};
+use crate::parsers::WalkOptions;
+use crate::version::{current_version_for, verify_version_for};
+
+/// Logs git progress at info level, or at debug level under `GitOptions::quiet` so it only
+/// shows with `--verbose`
+macro_rules! progress {
+    ($tracker:expr, $($arg:tt)+) => {
+        if $tracker.options.quiet {
+            debug!($($arg)+)
+        } else {
+            info!($($arg)+)
+        }
+    };
+}
+
+/// Tag name template used when no `tag_format` is configured
+pub const DEFAULT_TAG_FORMAT: &str = "v{version}";
+
+/// Where and how to re-read the project version before tagging, so a tag can't claim a version
+/// the files don't hold.
+#[derive(Debug, Clone)]
+pub struct VersionCheck {
+    /// Project root to read from.
+    pub path: PathBuf,
+    /// Parser types to read the current version with.
+    pub types: SupportedTypes,
+    /// Discovery options for the read.
+    pub options: WalkOptions,
+}
+
+/// What `execute_git_mode` created. Both are `None` under `GitMode::None`, `--dry-run`, or when
+/// there was nothing to commit.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GitRelease {
+    /// Id of the release commit
+    pub commit: Option<String>,
+    /// Name of the release tag
+    pub tag: Option<String>,
+}
+
+/// Options controlling how `execute_git_mode` commits, tags, and pushes.
+#[derive(Debug, Clone, Default)]
+pub struct GitOptions {
+    /// Branch to push HEAD to. Defaults to the current branch; required when HEAD is detached.
+    pub branch: Option<String>,
+    /// Branch on the remote to push to when it differs from the local one. Defaults to the
+    /// local branch's name.
+    pub push_branch: Option<String>,
+    /// Remotes to push to, in order. Defaults to `origin` when empty.
+    pub remotes: Vec<String>,
+    /// Move an existing release tag to the new commit (and force-push it) instead of aborting.
+    pub force_tag: bool,
+    /// Identity for commits and tags as `Name <email>`. Overrides git config when set.
+    pub author: Option<String>,
+    /// Fetch and fast-forward the push branch from the first remote before committing, so the
+    /// push doesn't fail as non-fast-forward when another release landed in the meantime.
+    pub pull_before_push: bool,
+    /// Fold the version changes into HEAD instead of creating a new commit, keeping its message.
+    /// Refused when HEAD is already on a remote-tracking branch unless `force_tag` is set.
+    pub amend: bool,
+    /// When a push fails, delete the just-created local tag and reset the branch to the commit
+    /// it was on before the release, keeping the version changes in the working tree for a retry.
+    /// Skipped when another remote already accepted the push.
+    pub rollback_on_push_failure: bool,
+    /// Append a body listing the changed files to the commit message.
+    pub detailed_commit: bool,
+    /// Tag name template; `{version}` is replaced by the version. Defaults to `v{version}`.
+    pub tag_format: Option<String>,
+    /// Only tag and push when the current branch is this one; elsewhere just commit and warn.
+    pub release_branch: Option<String>,
+    /// Annotation message template for release tags; `{version}`, `{tag}` and `{date}` (UTC
+    /// `YYYY-MM-DD` of `commit_date`, or today) are replaced. Defaults to `Release {tag}`.
+    pub tag_annotation: Option<String>,
+    /// Create lightweight tags, which carry no annotation message or tagger.
+    pub lightweight_tag: bool,
+    /// Tag (and push) the existing HEAD instead of committing, for a bump that was committed by
+    /// hand.
+    pub tag_existing_commit: bool,
+    /// Tag to start commit ranges from, instead of the latest version tag.
+    pub since_tag: Option<String>,
+    /// Follow only the first parent of merge commits when listing the commits since a tag, so
+    /// commits from merged branches are left out.
+    pub first_parent: bool,
+    /// Skip prerelease tags such as `v1.3.0-rc.1` when finding the latest version tag.
+    pub exclude_prerelease: bool,
+    /// With `exclude_prerelease`, what to do when every version tag is a prerelease.
+    pub prerelease_fallback: PrereleaseFallback,
+    /// Unix timestamp (UTC) used as the author and committer time of the commit and tag instead
+    /// of now, for reproducible releases.
+    pub commit_date: Option<i64>,
+    /// GPG-sign the commit and tag. Goes through the `git` executable, and is checked before
+    /// anything is committed.
+    pub sign: bool,
+    /// Commit and tag through the `git` executable instead of libgit2, so local hooks run.
+    pub use_git_cli: bool,
+    /// With `use_git_cli`, pass `--no-verify` to `git commit` to skip the pre-commit and
+    /// commit-msg hooks.
+    pub no_verify: bool,
+    /// When tagging, require the version read from the files to equal the tagged version.
+    pub version_check: Option<VersionCheck>,
+    /// Log the commit, tag and pushes that would happen and return without touching the repository.
+    pub dry_run: bool,
+    /// Log commit, tag and push progress at debug level instead of info.
+    pub quiet: bool,
+}
+
+impl GitOptions {
+    /// The tag name for `version`, applying `tag_format`
+    pub fn tag_name(&self, version: &str) -> String {
+        self.tag_format
+            .as_deref()
+            .unwrap_or(DEFAULT_TAG_FORMAT)
+            .replace("{version}", version)
+    }
+
+    /// The version part of `tag_name` with the `tag_format` prefix and suffix stripped, or `None`
+    /// if the tag doesn't follow the format
+    pub fn tag_version<'a>(&self, tag_name: &'a str) -> Option<&'a str> {
+        let format = self.tag_format.as_deref().unwrap_or(DEFAULT_TAG_FORMAT);
+        let (prefix, suffix) = format.split_once("{version}").unwrap_or((format, ""));
+        tag_name.strip_prefix(prefix)?.strip_suffix(suffix)
+    }
+
+    /// The annotation message for `tag_name`, rendering `tag_annotation`
+    pub fn tag_message(&self, tag_name: &str) -> String {
+        let version = self.tag_version(tag_name).unwrap_or(tag_name);
+        self.tag_annotation
+            .as_deref()
+            .unwrap_or("Release {tag}")
+            .replace("{version}", version)
+            .replace("{tag}", tag_name)
+            .replace("{date}", &release_date(self.commit_date))
+    }
+
+    /// The remotes pushes go to: the configured list, or `origin` if none were given
+    pub fn push_remotes(&self) -> Vec<String> {
+        if self.remotes.is_empty() {
+            vec!["origin".to_string()]
+        } else {
+            self.remotes.clone()
+        }
+    }
+}
 
 pub struct GitTracker {
     pub repository: Repository,
     pub allow_insecure: bool,
+    pub options: GitOptions,
 }
 
 impl GitTracker {
@@ -20,7 +165,7 @@ impl GitTracker {
 
         debug!("Opened repository at {:?}", repository.path());
 
-        Ok(GitTracker { repository, allow_insecure })
+        Ok(GitTracker { repository, allow_insecure, options: GitOptions::default() })
     }
 
     /// Creates authentication callbacks that use local git credentials
@@ -104,10 +249,40 @@ impl GitTracker {
         callbacks
     }
 
-    /// Gets the repository signature from local git config
+    /// Gets the signature for commits and tags: `--author` if given, then local git config,
+    /// then the `GIT_AUTHOR_NAME`/`GIT_AUTHOR_EMAIL` environment variables, timed at
+    /// `commit_date` when set
     fn get_signature(&self) -> Result<Signature<'_>> {
-        self.repository.signature()
-            .context("Failed to get git signature. Please configure user.name and user.email in git config")
+        let sig = self.get_identity()?;
+        match self.options.commit_date {
+            Some(epoch) => Ok(Signature::new(
+                sig.name().unwrap_or_default(),
+                sig.email().unwrap_or_default(),
+                &git2::Time::new(epoch, 0),
+            )?),
+            None => Ok(sig),
+        }
+    }
+
+    /// Resolves the name and email for `get_signature`
+    fn get_identity(&self) -> Result<Signature<'_>> {
+        if let Some(author) = &self.options.author {
+            let (name, email) = parse_author(author)?;
+            return Ok(Signature::now(&name, &email)?);
+        }
+        if let Ok(signature) = self.repository.signature() {
+            return Ok(signature);
+        }
+        match (std::env::var("GIT_AUTHOR_NAME"), std::env::var("GIT_AUTHOR_EMAIL")) {
+            (Ok(name), Ok(email)) => {
+                debug!("Using GIT_AUTHOR_NAME/GIT_AUTHOR_EMAIL for the signature");
+                Ok(Signature::now(&name, &email)?)
+            }
+            _ => anyhow::bail!(
+                "Failed to get git signature. Configure user.name and user.email in git config, \
+                 set GIT_AUTHOR_NAME and GIT_AUTHOR_EMAIL, or pass --author \"Name <email>\""
+            ),
+        }
     }
 
     /// Stages all modified and new files in the repository
@@ -175,7 +350,11 @@ impl GitTracker {
 
     /// Creates a commit with the given message
     pub fn create_commit(&self, message: &str) -> Result<git2::Oid> {
-        info!("Creating commit: {}", message);
+        progress!(self, "Creating commit: {}", message);
+
+        if self.uses_git_cli() {
+            return self.commit_with_git_cli(&["commit", "-m", message]);
+        }
 
         let mut index = self.repository.index()?;
         let tree_id = index.write_tree()?;
@@ -202,52 +381,219 @@ impl GitTracker {
             &parents,
         )?;
 
-        info!("Created commit: {}", commit_id);
+        progress!(self, "Created commit: {}", commit_id);
         Ok(commit_id)
     }
 
-    /// Creates a tag for the given commit
-    pub fn create_tag(&self, tag_name: &str, commit_id: git2::Oid) -> Result<()> {
-        info!("Creating tag: {}", tag_name);
+    /// Whether commits and tags go through the `git` executable: requested directly, or
+    /// needed for signing, which libgit2 doesn't do on its own
+    fn uses_git_cli(&self) -> bool {
+        self.options.use_git_cli || self.options.sign
+    }
+
+    /// Checks that signing will work before anything is committed: a `user.signingkey` must be
+    /// configured and the signing program (`gpg.program`, default `gpg`) must sign a test payload
+    pub fn check_signing(&self) -> Result<()> {
+        let config = self.repository.config()?;
+        let key = config.get_string("user.signingkey").map_err(|_| {
+            anyhow::anyhow!(
+                "--sign requires a signing key, but user.signingkey is not set. \
+                 Configure one with `git config user.signingkey <KEY-ID>`"
+            )
+        })?;
+        let program = config.get_string("gpg.program").unwrap_or_else(|_| "gpg".to_string());
+
+        let mut child = std::process::Command::new(&program)
+            .args(["--batch", "--status-fd=2", "-bsau", &key])
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .with_context(|| {
+                format!("Signing program '{}' could not be run; install it or set gpg.program", program)
+            })?;
+        if let Some(mut stdin) = child.stdin.take() {
+            std::io::Write::write_all(&mut stdin, b"update-version signing check")?;
+        }
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "Signing program '{}' could not sign with key '{}': {}",
+                program,
+                key,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        debug!("Signing preflight passed with key {}", key);
+        Ok(())
+    }
+
+    /// Runs a commit through the `git` executable so hooks apply, adding `--no-verify` when
+    /// configured, and returns the new HEAD
+    fn commit_with_git_cli(&self, args: &[&str]) -> Result<git2::Oid> {
+        let mut args = args.to_vec();
+        if self.options.no_verify {
+            args.push("--no-verify");
+        }
+        if self.options.sign {
+            args.push("-S");
+        }
+        self.run_git_cli(&args)?;
 
+        let commit_id = self.repository.head()?.peel_to_commit()?.id();
+        progress!(self, "Created commit: {}", commit_id);
+        Ok(commit_id)
+    }
+
+    /// Runs `git <args>` in the working directory with the resolved signature as author and
+    /// committer, failing with git's stderr on a nonzero exit
+    fn run_git_cli(&self, args: &[&str]) -> Result<()> {
+        let workdir = self.repository.workdir()
+            .ok_or_else(|| anyhow::anyhow!("Cannot run git in a bare repository"))?;
         let sig = self.get_signature()?;
+        let name = sig.name().unwrap_or_default();
+        let email = sig.email().unwrap_or_default();
+
+        debug!("Running git {}", args.join(" "));
+        let mut command = std::process::Command::new("git");
+        command
+            .args(args)
+            .current_dir(workdir)
+            .env("GIT_AUTHOR_NAME", name)
+            .env("GIT_AUTHOR_EMAIL", email)
+            .env("GIT_COMMITTER_NAME", name)
+            .env("GIT_COMMITTER_EMAIL", email);
+        if let Some(epoch) = self.options.commit_date {
+            let date = format!("@{} +0000", epoch);
+            command.env("GIT_AUTHOR_DATE", &date).env("GIT_COMMITTER_DATE", &date);
+        }
+        let output = command
+            .output()
+            .context("Failed to run git; is it installed and on PATH?")?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "git {} failed with {}: {}",
+                args.first().unwrap_or(&""),
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(())
+    }
+
+    /// Amends HEAD with the staged changes, keeping its author and message
+    pub fn amend_head(&self) -> Result<git2::Oid> {
+        progress!(self, "Amending HEAD with the version changes");
+
+        if self.uses_git_cli() {
+            return self.commit_with_git_cli(&["commit", "--amend", "--no-edit"]);
+        }
+
+        let head = self.repository.head()
+            .context("Cannot amend: the repository has no commits yet")?
+            .peel_to_commit()?;
+        let tree_id = self.repository.index()?.write_tree()?;
+        let tree = self.repository.find_tree(tree_id)?;
+        let sig = self.get_signature()?;
+
+        let commit_id = head.amend(Some("HEAD"), None, Some(&sig), None, None, Some(&tree))?;
+
+        progress!(self, "Amended commit: {}", commit_id);
+        Ok(commit_id)
+    }
+
+    /// Whether HEAD is already reachable from any remote-tracking branch
+    pub fn is_head_pushed(&self) -> Result<bool> {
+        let Some(head) = self.repository.head().ok().and_then(|head| head.target()) else {
+            return Ok(false);
+        };
+        for reference in self.repository.references_glob("refs/remotes/*")? {
+            let Some(target) = reference?.target() else { continue };
+            if target == head || self.repository.graph_descendant_of(target, head)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Creates a tag for the given commit, annotated with `tag_message` unless `lightweight_tag`
+    /// is set
+    pub fn create_tag(&self, tag_name: &str, commit_id: git2::Oid) -> Result<()> {
+        progress!(self, "Creating tag: {}", tag_name);
+
+        if self.uses_git_cli() {
+            let message = self.options.tag_message(tag_name);
+            let commit = commit_id.to_string();
+            let mut args = if self.options.lightweight_tag {
+                vec!["tag", tag_name, &commit]
+            } else {
+                let kind = if self.options.sign { "-s" } else { "-a" };
+                vec!["tag", kind, tag_name, "-m", &message, &commit]
+            };
+            if self.options.force_tag {
+                args.push("--force");
+            }
+            self.run_git_cli(&args)?;
+            progress!(self, "Created tag: {}", tag_name);
+            return Ok(());
+        }
+
         let commit_obj = self.repository
             .find_object(commit_id, Some(git2::ObjectType::Commit))?;
 
-        self.repository.tag(
-            tag_name,
-            &commit_obj,
-            &sig,
-            &format!("Release {}", tag_name),
-            false,
-        )?;
+        if self.options.lightweight_tag {
+            self.repository.tag_lightweight(tag_name, &commit_obj, self.options.force_tag)?;
+        } else {
+            let sig = self.get_signature()?;
+            self.repository.tag(
+                tag_name,
+                &commit_obj,
+                &sig,
+                &self.options.tag_message(tag_name),
+                self.options.force_tag,
+            )?;
+        }
 
-        info!("Created tag: {}", tag_name);
+        progress!(self, "Created tag: {}", tag_name);
         Ok(())
     }
 
-    /// Pushes commits to the remote
-    pub fn push_commits(&self, remote_name: &str, branch: &str) -> Result<()> {
-        info!("Pushing commits to {}/{}", remote_name, branch);
+    /// Pushes the commits of `local_branch` to `remote_branch` on the remote
+    pub fn push_commits(&self, remote_name: &str, local_branch: &str, remote_branch: &str) -> Result<()> {
+        progress!(self, "Pushing commits to {}/{}", remote_name, remote_branch);
 
-        let mut remote = self.repository.find_remote(remote_name)
-            .with_context(|| format!("Remote '{}' not found", remote_name))?;
+        let refspec = branch_refspec(local_branch, remote_branch);
+        self.push_refspec(remote_name, &refspec)?;
 
-        let callbacks = Self::create_auth_callbacks(self.allow_insecure);
-        let mut push_options = PushOptions::new();
-        push_options.remote_callbacks(callbacks);
+        progress!(self, "Pushed commits to {}/{}", remote_name, remote_branch);
+        Ok(())
+    }
 
-        let refspec = format!("refs/heads/{}:refs/heads/{}", branch, branch);
-        remote.push(&[&refspec], Some(&mut push_options))?;
+    /// Pushes the detached HEAD commit to a branch on the remote
+    pub fn push_head(&self, remote_name: &str, branch: &str) -> Result<()> {
+        progress!(self, "Pushing HEAD to {}/{}", remote_name, branch);
 
-        info!("Pushed commits to {}/{}", remote_name, branch);
+        let refspec = format!("HEAD:refs/heads/{}", branch);
+        self.push_refspec(remote_name, &refspec)?;
+
+        progress!(self, "Pushed HEAD to {}/{}", remote_name, branch);
         Ok(())
     }
 
     /// Pushes a tag to the remote
     pub fn push_tag(&self, remote_name: &str, tag_name: &str) -> Result<()> {
-        info!("Pushing tag {} to {}", tag_name, remote_name);
+        progress!(self, "Pushing tag {} to {}", tag_name, remote_name);
+
+        let force = if self.options.force_tag { "+" } else { "" };
+        let refspec = format!("{}refs/tags/{}:refs/tags/{}", force, tag_name, tag_name);
+        self.push_refspec(remote_name, &refspec)?;
 
+        progress!(self, "Pushed tag {} to {}", tag_name, remote_name);
+        Ok(())
+    }
+
+    /// Pushes a single refspec to the remote using local credentials
+    fn push_refspec(&self, remote_name: &str, refspec: &str) -> Result<()> {
         let mut remote = self.repository.find_remote(remote_name)
             .with_context(|| format!("Remote '{}' not found", remote_name))?;
 
@@ -255,13 +601,15 @@ impl GitTracker {
         let mut push_options = PushOptions::new();
         push_options.remote_callbacks(callbacks);
 
-        let refspec = format!("refs/tags/{}:refs/tags/{}", tag_name, tag_name);
-        remote.push(&[&refspec], Some(&mut push_options))?;
-
-        info!("Pushed tag {} to {}", tag_name, remote_name);
+        remote.push(&[refspec], Some(&mut push_options))?;
         Ok(())
     }
 
+    /// Returns true if HEAD points directly at a commit rather than a branch
+    pub fn is_head_detached(&self) -> Result<bool> {
+        Ok(self.repository.head_detached()?)
+    }
+
     /// Gets the current branch name
     pub fn current_branch(&self) -> Result<String> {
         let head = self.repository.head()?;
@@ -270,11 +618,115 @@ impl GitTracker {
         Ok(branch_name.to_string())
     }
 
-    /// Executes git operations based on the GitMode and version
-    pub fn execute_git_mode(&self, mode: GitMode, version: &str, files: &[PathBuf]) -> Result<()> {
+    /// Executes git operations based on the GitMode and version, returning what was created
+    pub fn execute_git_mode(&self, mode: GitMode, version: &str, files: &[PathBuf]) -> Result<GitRelease> {
         if mode == GitMode::None {
             debug!("GitMode::None - skipping git operations");
-            return Ok(());
+            return Ok(GitRelease::default());
+        }
+
+        // Off the release branch only the commit is made; tagging and pushing are skipped
+        let detached = self.is_head_detached()?;
+        let on_release_branch = match &self.options.release_branch {
+            None => true,
+            Some(release) if !detached && self.current_branch()? == *release => true,
+            Some(release) => {
+                warn!("Not on release branch '{}', skipping tag and push", release);
+                false
+            }
+        };
+
+        // Resolve the push target up front so a detached HEAD fails before anything is committed
+        let should_push = on_release_branch && matches!(mode, GitMode::CommitPush | GitMode::CommitPushTag);
+        let push_branch = if !should_push {
+            None
+        } else if let Some(branch) = &self.options.branch {
+            Some(branch.clone())
+        } else if detached {
+            anyhow::bail!(
+                "HEAD is detached, so there is no branch to push to. \
+                 Pass --branch <name> or check out a branch before pushing"
+            );
+        } else {
+            Some(self.current_branch()?)
+        };
+
+        let remote_branch = push_branch
+            .as_ref()
+            .map(|branch| self.options.push_branch.clone().unwrap_or_else(|| branch.clone()));
+
+        if let Some(branch) = &remote_branch
+            && self.options.pull_before_push
+            && self.options.dry_run
+        {
+            progress!(self, "Would fast-forward to {}/{}", self.options.push_remotes()[0], branch);
+        } else if let Some(branch) = &remote_branch
+            && self.options.pull_before_push
+        {
+            let remote = &self.options.push_remotes()[0];
+            let upstream = self.fetch_branch(remote, branch)?;
+            self.fast_forward(upstream)
+                .with_context(|| format!("Failed to fast-forward to {}/{}", remote, branch))?;
+        }
+
+        // Refuse to re-release an existing tag before anything is committed
+        let mut commit_message = format!("chore: bump version to {}", version);
+        if self.options.detailed_commit && !files.is_empty() {
+            commit_message.push_str(&self.changed_files_body(files));
+        }
+        let tag_name = self.options.tag_name(version);
+        let should_tag = on_release_branch && matches!(mode, GitMode::CommitPushTag | GitMode::CommitTag);
+        if should_tag && !self.options.force_tag && self.get_tags()?.contains(&tag_name) {
+            anyhow::bail!(
+                "Tag '{}' already exists; nothing was committed. Pass --force-tag to move it to the new commit",
+                tag_name
+            );
+        }
+
+        if self.options.sign {
+            self.check_signing()?;
+        }
+
+        if self.options.amend && !self.options.force_tag && self.is_head_pushed()? {
+            anyhow::bail!(
+                "HEAD has already been pushed, so amending it would rewrite published history. \
+                 Pass --force-tag to amend anyway"
+            );
+        }
+
+        if self.options.dry_run {
+            if self.options.tag_existing_commit {
+                progress!(self, "Would tag HEAD without creating a commit");
+            } else if self.options.amend {
+                progress!(self, "Would amend HEAD with the version changes");
+            } else {
+                progress!(self, "Would create commit: {}", commit_message);
+            }
+            if should_tag {
+                progress!(self, "Would create tag: {}", tag_name);
+            }
+            if let Some(branch) = &remote_branch {
+                for remote in self.options.push_remotes() {
+                    progress!(self, "Would push to {}/{}", remote, branch);
+                }
+            }
+            return Ok(GitRelease::default());
+        }
+
+        if should_tag && let Some(check) = &self.options.version_check {
+            current_version_for(check.types, &check.path, &check.options)
+                .context("Failed to read the current version to check it against the tag")?;
+            // Compared per parser, so formats that drop the prerelease still match its tag
+            let expected = Version::parse(version)?;
+            let mismatches = verify_version_for(check.types, &check.path, &expected, &check.options)?;
+            if !mismatches.is_empty() {
+                anyhow::bail!(
+                    "Refusing to tag '{}': the files don't hold version {}. Nothing was committed:\n  {}",
+                    tag_name,
+                    version,
+                    mismatches.join("\n  ")
+                );
+            }
         }
 
         // Stage only the files that were modified by version updates
@@ -282,34 +734,144 @@ impl GitTracker {
 
         // Check if there are changes to commit
         let statuses = self.repository.statuses(None)?;
-        if statuses.is_empty() {
+        if statuses.is_empty() && !self.options.tag_existing_commit {
             warn!("No changes to commit");
-            return Ok(());
+            return Ok(GitRelease::default());
         }
 
-        let commit_message = format!("chore: bump version to {}", version);
-        let tag_name = format!("v{}", version);
-
-        // Create commit for all modes except None
-        let commit_id = self.create_commit(&commit_message)?;
+        // Remember where we started so a failed push can be rolled back
+        let pre_commit = self.repository.head().ok().and_then(|head| head.peel_to_commit().ok()).map(|c| c.id());
+        let previous_tag = self.tag_target(&tag_name)?;
+
+        // Create commit for all modes except None, unless the bump was already committed
+        let commit_id = if self.options.tag_existing_commit {
+            pre_commit.ok_or_else(|| anyhow::anyhow!("There is no HEAD commit to tag"))?
+        } else if self.options.amend {
+            self.amend_head()?
+        } else {
+            self.create_commit(&commit_message)?
+        };
 
         // Create tag if mode includes tagging
-        let should_tag = matches!(mode, GitMode::CommitPushTag | GitMode::CommitTag);
         if should_tag {
             self.create_tag(&tag_name, commit_id)?;
         }
 
-        // Push if mode includes pushing
-        let should_push = matches!(mode, GitMode::CommitPush | GitMode::CommitPushTag);
-        if should_push {
-            let branch = self.current_branch()?;
-            self.push_commits("origin", &branch)?;
-
-            if should_tag {
-                self.push_tag("origin", &tag_name)?;
+        // Push if mode includes pushing, trying every remote before reporting failures
+        if let (Some(branch), Some(remote_branch)) = (push_branch, remote_branch) {
+            let tag = should_tag.then_some(tag_name.as_str());
+            // `--branch` names the target on the remote, not a local branch holding the release
+            let from_head = detached || self.options.branch.is_some();
+            let mut failures = Vec::new();
+            let mut pushed = Vec::new();
+            for remote in self.options.push_remotes() {
+                match self.push_to_remote(&remote, &branch, &remote_branch, from_head, tag) {
+                    Ok(()) => pushed.push(remote),
+                    Err(e) => {
+                        warn!("Failed to push to remote '{}': {:#}", remote, e);
+                        failures.push(format!("{}: {:#}", remote, e));
+                    }
+                }
             }
+            if !failures.is_empty() {
+                // Rolling back would leave the remotes that took the push ahead of a retry
+                if self.options.rollback_on_push_failure && !pushed.is_empty() {
+                    warn!(
+                        "Not rolling back: {} already {} the release commit{}",
+                        pushed.join(", "),
+                        if pushed.len() == 1 { "has" } else { "have" },
+                        if should_tag { " and tag" } else { "" }
+                    );
+                } else if self.options.rollback_on_push_failure && self.options.tag_existing_commit {
+                    if should_tag {
+                        self.rollback_tag(&tag_name, previous_tag)
+                            .context("Push failed and the local tag could not be rolled back")?;
+                    }
+                } else if self.options.rollback_on_push_failure {
+                    let tag = should_tag.then_some((tag_name.as_str(), previous_tag));
+                    self.rollback(pre_commit, tag)
+                        .context("Push failed and the local release commit could not be rolled back")?;
+                }
+                anyhow::bail!("Failed to push to {} remote(s):\n  {}", failures.len(), failures.join("\n  "));
+            }
+        }
+
+        Ok(GitRelease { commit: Some(commit_id.to_string()), tag: should_tag.then_some(tag_name) })
+    }
+
+    /// The commit a tag currently points to, or `None` if the tag doesn't exist
+    fn tag_target(&self, tag_name: &str) -> Result<Option<git2::Oid>> {
+        match self.repository.revparse_single(&format!("refs/tags/{}", tag_name)) {
+            Ok(object) => Ok(Some(object.peel_to_commit()?.id())),
+            Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Undoes a release after a failed push: removes the new tag (re-pointing it at
+    /// `previous_target` if `--force-tag` moved an existing one) and resets the branch to
+    /// `pre_commit` without touching the working tree. An initial commit has nothing to reset to
+    /// and is kept.
+    fn rollback(&self, pre_commit: Option<git2::Oid>, tag: Option<(&str, Option<git2::Oid>)>) -> Result<()> {
+        if let Some((tag_name, previous_target)) = tag {
+            self.rollback_tag(tag_name, previous_target)?;
+        }
+        let Some(pre_commit) = pre_commit else {
+            warn!("The release commit is the repository's first commit, so it was kept");
+            return Ok(());
+        };
+        let commit = self.repository.find_object(pre_commit, Some(git2::ObjectType::Commit))?;
+        self.repository.reset(&commit, git2::ResetType::Mixed, None)?;
+        progress!(self, "Rolled back release commit, HEAD is at {}", pre_commit);
+        Ok(())
+    }
+
+    /// Deletes a new release tag, re-pointing it at `previous_target` if it existed before
+    fn rollback_tag(&self, tag_name: &str, previous_target: Option<git2::Oid>) -> Result<()> {
+        self.repository.tag_delete(tag_name)?;
+        if let Some(target) = previous_target {
+            self.create_tag(tag_name, target)?;
+        }
+        progress!(self, "Rolled back tag '{}'", tag_name);
+        Ok(())
+    }
+
+    /// Builds a commit message body listing `files`, relative to the repository root where possible
+    fn changed_files_body(&self, files: &[PathBuf]) -> String {
+        let root = self.repository.workdir().and_then(|dir| dir.canonicalize().ok());
+        let mut body = String::from("\n\nChanged files:\n");
+        for file in files {
+            let display = match (&root, file.canonicalize()) {
+                (Some(root), Ok(abs)) => abs
+                    .strip_prefix(root)
+                    .map(|p| p.to_string_lossy().replace('\\', "/"))
+                    .unwrap_or_else(|_| file.display().to_string()),
+                _ => file.display().to_string(),
+            };
+            body.push_str(&format!("- {}\n", display));
+        }
+        body
+    }
+
+    /// Pushes the release commit, and the tag if given, to a single remote. With `from_head` the
+    /// commit is pushed as `HEAD` rather than through the local `branch`.
+    fn push_to_remote(
+        &self,
+        remote: &str,
+        branch: &str,
+        remote_branch: &str,
+        from_head: bool,
+        tag_name: Option<&str>,
+    ) -> Result<()> {
+        if from_head {
+            self.push_head(remote, remote_branch)?;
+        } else {
+            self.push_commits(remote, branch, remote_branch)?;
         }
 
+        if let Some(tag_name) = tag_name {
+            self.push_tag(remote, tag_name)?;
+        }
         Ok(())
     }
 
@@ -329,6 +891,60 @@ impl GitTracker {
         Ok(())
     }
 
+    /// Fetches `branch` from the remote into `refs/remotes/<remote>/<branch>` and returns the
+    /// fetched commit
+    pub fn fetch_branch(&self, remote_name: &str, branch: &str) -> Result<git2::Oid> {
+        debug!("Fetching {} from {}", branch, remote_name);
+
+        let mut remote = self.repository.find_remote(remote_name)
+            .with_context(|| format!("Remote '{}' not found", remote_name))?;
+
+        let callbacks = Self::create_auth_callbacks(self.allow_insecure);
+        let mut fetch_options = FetchOptions::new();
+        fetch_options.remote_callbacks(callbacks);
+
+        let tracking = format!("refs/remotes/{}/{}", remote_name, branch);
+        let refspec = format!("+refs/heads/{}:{}", branch, tracking);
+        remote.fetch(&[refspec.as_str()], Some(&mut fetch_options), None)?;
+
+        let target = self.repository.find_reference(&tracking)?.target()
+            .ok_or_else(|| anyhow::anyhow!("Fetched reference {} has no target", tracking))?;
+        debug!("Fetched {}/{} at {}", remote_name, branch, target);
+        Ok(target)
+    }
+
+    /// Moves HEAD forward to `target` if it is a descendant of the current HEAD commit.
+    /// Fails without changing anything if the histories have diverged.
+    pub fn fast_forward(&self, target: git2::Oid) -> Result<()> {
+        let head = self.repository.head()?;
+        let current = head.peel_to_commit()?.id();
+
+        if current == target || self.repository.graph_descendant_of(current, target)? {
+            debug!("Already up to date with {}", target);
+            return Ok(());
+        }
+        if !self.repository.graph_descendant_of(target, current)? {
+            anyhow::bail!(
+                "Local history has diverged from the remote ({} vs {}); \
+                 pull and resolve manually before releasing",
+                current, target
+            );
+        }
+
+        progress!(self, "Fast-forwarding {} -> {}", current, target);
+        let commit = self.repository.find_object(target, Some(git2::ObjectType::Commit))?;
+        let mut checkout = git2::build::CheckoutBuilder::new();
+        checkout.safe();
+        self.repository.checkout_tree(&commit, Some(&mut checkout))?;
+        if self.repository.head_detached()? {
+            self.repository.set_head_detached(target)?;
+        } else {
+            let mut branch_ref = head.resolve()?;
+            branch_ref.set_target(target, "update-version: fast-forward before push")?;
+        }
+        Ok(())
+    }
+
     /// Gets all tags from the repository
     pub fn get_tags(&self) -> Result<Vec<String>> {
         let mut tags = Vec::new();
@@ -343,4 +959,152 @@ impl GitTracker {
 
         Ok(tags)
     }
+
+    /// Gets the tags that start with `prefix`, with the prefix stripped
+    pub fn get_tags_with_prefix(&self, prefix: &str) -> Result<Vec<String>> {
+        let mut tags = Vec::new();
+
+        self.repository.tag_foreach(|_oid, name| {
+            if let Ok(name_str) = std::str::from_utf8(name)
+                && let Some(stripped) = name_str.trim_start_matches("refs/tags/").strip_prefix(prefix)
+            {
+                tags.push(stripped.to_string());
+            }
+            true
+        })?;
+
+        Ok(tags)
+    }
+
+    /// The newest tag in `tag_format` whose version parses as semver, if any. With
+    /// `exclude_prerelease` the newest stable tag wins; if every tag is a prerelease,
+    /// `prerelease_fallback` picks the highest one or fails.
+    pub fn latest_version_tag(&self) -> Result<Option<String>> {
+        Ok(self.latest_tagged_release()?.map(|(name, _)| name))
+    }
+
+    /// The version carried by `latest_version_tag`, if any
+    pub fn latest_tagged_version(&self) -> Result<Option<Version>> {
+        Ok(self.latest_tagged_release()?.map(|(_, version)| version))
+    }
+
+    fn latest_tagged_release(&self) -> Result<Option<(String, Version)>> {
+        let mut tags: Vec<(String, Version)> = self
+            .get_tags()?
+            .into_iter()
+            .filter_map(|tag| {
+                let version = Version::parse(self.options.tag_version(&tag)?).ok()?;
+                Some((tag, version))
+            })
+            .collect();
+        tags.sort_by(|(_, a), (_, b)| a.cmp(b));
+        if !self.options.exclude_prerelease {
+            return Ok(tags.pop());
+        }
+
+        let stable = tags.iter().rev().find(|(_, version)| version.pre.is_empty());
+        match (stable, tags.last(), self.options.prerelease_fallback) {
+            (Some(release), _, _) => Ok(Some(release.clone())),
+            (None, None, _) => Ok(None),
+            (None, Some(release), PrereleaseFallback::Highest) => {
+                warn!("Every version tag is a prerelease, using the highest: {}", release.0);
+                Ok(Some(release.clone()))
+            }
+            (None, Some(_), PrereleaseFallback::Error) => anyhow::bail!(
+                "Every version tag is a prerelease and --exclude-prerelease is set; tag a stable release \
+                 or pass --prerelease-fallback highest"
+            ),
+        }
+    }
+
+    /// Messages of the commits after the baseline tag up to HEAD, newest first. The baseline is
+    /// `since_tag` if set, otherwise `latest_version_tag`; with neither, all of history is used.
+    /// With `first_parent` only the mainline commits are listed.
+    pub fn commit_messages_since_tag(&self) -> Result<Vec<String>> {
+        let baseline = match &self.options.since_tag {
+            Some(tag) => Some(
+                self.tag_target(tag)?
+                    .with_context(|| format!("Tag '{}' given to --since-tag does not exist", tag))?,
+            ),
+            None => match self.latest_version_tag()? {
+                Some(tag) => self.tag_target(&tag)?,
+                None => None,
+            },
+        };
+
+        let mut revwalk = self.repository.revwalk()?;
+        revwalk.push_head()?;
+        if self.options.first_parent {
+            revwalk.simplify_first_parent()?;
+        }
+        if let Some(baseline) = baseline {
+            revwalk.hide(baseline)?;
+        }
+        revwalk
+            .map(|oid| {
+                let commit = self.repository.find_commit(oid?)?;
+                Ok(commit.message().unwrap_or_default().to_string())
+            })
+            .collect()
+    }
+
+    /// Gets all tags that parse as semver after stripping `prefix`, sorted ascending by version.
+    /// Tags that don't carry the prefix or aren't valid semver are skipped.
+    pub fn get_version_tags(&self, prefix: &str) -> Result<Vec<(String, Version)>> {
+        let mut tags: Vec<(String, Version)> = self
+            .get_tags_with_prefix(prefix)?
+            .into_iter()
+            .filter_map(|stripped| {
+                let version = Version::parse(&stripped).ok()?;
+                Some((format!("{}{}", prefix, stripped), version))
+            })
+            .collect();
+
+        tags.sort_by(|(_, a), (_, b)| a.cmp(b));
+        Ok(tags)
+    }
+}
+
+/// The refspec that pushes `local_branch` to `remote_branch` on a remote
+pub fn branch_refspec(local_branch: &str, remote_branch: &str) -> String {
+    format!("refs/heads/{}:refs/heads/{}", local_branch, remote_branch)
+}
+
+/// The UTC `YYYY-MM-DD` date of a release made at `commit_date`, or now when it's unset
+pub fn release_date(commit_date: Option<i64>) -> String {
+    let epoch = commit_date.unwrap_or_else(|| {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs() as i64)
+            .unwrap_or_default()
+    });
+    format_date(epoch)
+}
+
+/// Formats a Unix timestamp as a UTC `YYYY-MM-DD` date
+fn format_date(epoch: i64) -> String {
+    // Civil-from-days conversion over 400-year eras, as described by Howard Hinnant
+    let days = epoch.div_euclid(86_400);
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Splits an identity of the form `Name <email>` into its name and email
+pub fn parse_author(author: &str) -> Result<(String, String)> {
+    let invalid = || anyhow::anyhow!("Invalid author '{}': expected \"Name <email>\"", author);
+    let (name, rest) = author.split_once('<').ok_or_else(invalid)?;
+    let email = rest.strip_suffix('>').ok_or_else(invalid)?.trim();
+    let name = name.trim();
+    if name.is_empty() || email.is_empty() {
+        return Err(invalid());
+    }
+    Ok((name.to_string(), email.to_string()))
 }
@@ -1,3 +1,12 @@
 pub mod parsers;
 pub mod arguments;
-pub mod git;
\ No newline at end of file
+pub mod backup;
+pub mod changelog;
+pub mod config;
+pub mod git;
+pub mod report;
+pub mod version;
+
+pub use version::{
+    BumpKind, bump_version, bump_version_with_label, conventional_bump, keep_prerelease, next_version,
+};
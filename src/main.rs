@@ -1,90 +1,625 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Parser;
-use log::LevelFilter;
+use log::info;
+use pretty_env_logger::env_logger::WriteStyle;
 use semver::Version;
-use std::path::Path;
+use std::io::{IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
 use update_version::{
-    arguments::{Arguments, GitMode, SupportedTypes},
-    git::GitTracker,
-    parsers::{
-        Parser as UpdateVersionParser, WalkOptions, increment_semver,
-        package_json_parser::PackageJsonParser, tauri_config_parser::TauriConfigParser,
-        toml_parser::TomlParser,
+    arguments::{Arguments, GitMode, OutputFormat, SupportedTypes},
+    backup::restore_backups,
+    changelog,
+    config::Config,
+    git::{GitOptions, GitRelease, GitTracker, VersionCheck, release_date},
+    parsers::{ParsingError, WalkOptions, pubspec_parser::PubspecParser, toml_parser::TomlParser},
+    report::{RunReport, report_schema},
+    version::{
+        conventional_bump, current_version_for, matching_files_for, update_summary, update_version_for,
+        verify_version_for,
     },
+    BumpKind, bump_version_with_label, keep_prerelease,
 };
 
-fn main() -> Result<()> {
+/// Exit code for any failure without a more specific code below
+const FAILURE_EXIT_CODE: i32 = 1;
+/// Exit code used by `--error-on-noop` when every file already holds the target version
+const NOOP_EXIT_CODE: i32 = 3;
+/// Exit code when no file matched the selected types
+const NO_FILES_EXIT_CODE: i32 = 4;
+/// Exit code when files matched but none had a version field
+const NO_VERSION_EXIT_CODE: i32 = 5;
+/// Exit code when a git operation (commit, tag, push, ...) failed
+const GIT_EXIT_CODE: i32 = 6;
+/// Exit code for invalid versions, whether given on the command line or found in a file
+const VALIDATION_EXIT_CODE: i32 = 7;
+
+/// Failures with their own exit code, wrapping the underlying error without changing its message
+#[derive(Debug, thiserror::Error)]
+enum Failure {
+    #[error(transparent)]
+    Git(anyhow::Error),
+    #[error(transparent)]
+    Validation(anyhow::Error),
+}
+
+fn main() {
+    if let Err(e) = run() {
+        // Same rendering as returning the error from `main`
+        eprintln!("Error: {:?}", e);
+        std::process::exit(exit_code(&e));
+    }
+}
+
+/// Maps an error to the documented exit code for its cause
+fn exit_code(error: &anyhow::Error) -> i32 {
+    for cause in error.chain() {
+        match cause.downcast_ref::<ParsingError>() {
+            Some(ParsingError::NoMatchingFilesError(_)) => return NO_FILES_EXIT_CODE,
+            Some(ParsingError::NoVersionFoundError(_)) => return NO_VERSION_EXIT_CODE,
+            Some(ParsingError::InvalidVersionError { .. } | ParsingError::InconsistentVersionsError(_)) => {
+                return VALIDATION_EXIT_CODE;
+            }
+            _ => {}
+        }
+        match cause.downcast_ref::<Failure>() {
+            Some(Failure::Git(_)) => return GIT_EXIT_CODE,
+            Some(Failure::Validation(_)) => return VALIDATION_EXIT_CODE,
+            None => {}
+        }
+        if cause.is::<semver::Error>() {
+            return VALIDATION_EXIT_CODE;
+        }
+        if cause.is::<git2::Error>() {
+            return GIT_EXIT_CODE;
+        }
+    }
+    FAILURE_EXIT_CODE
+}
+
+fn run() -> Result<()> {
     let args = Arguments::parse();
+    let no_color = args.no_color || std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
     pretty_env_logger::env_logger::builder()
-        .filter_level(if args.verbose { LevelFilter::Debug } else { LevelFilter::Info })
+        .filter_level(args.log_level())
+        .write_style(if no_color { WriteStyle::Never } else { WriteStyle::Auto })
         .format_timestamp(None)
         .init();
 
-    let walk_options = WalkOptions { no_ignore: args.no_ignore };
-    let version = args.new_version.map(|v| Version::parse(&v)).transpose()?;
-    let path: &Path = args.path.as_ref();
+    if args.output == OutputFormat::Json && (args.print_files_json || args.print_tag) {
+        anyhow::bail!("--output json can't be combined with --print-files-json or --print-tag, which also print to stdout");
+    }
+    // The build number lives only in pubspec.yaml; other types would read and get a different version
+    if args.flutter_bump && args.supported_types != SupportedTypes::Pubspec {
+        anyhow::bail!("--flutter-bump only applies to pubspec.yaml; pass --types pubspec");
+    }
+
+    let walk_options = WalkOptions {
+        no_ignore: args.no_ignore,
+        dry_run: args.dry_run,
+        patch: args.output == OutputFormat::Patch,
+        coerce: args.coerce,
+        insert_missing: args.insert_missing || args.initial_version.is_some(),
+        swift_identifier: Some(args.swift_identifier.clone()),
+        env_variable: Some(args.env_variable.clone()),
+        json_pointer: args.json_pointer.clone(),
+        json_file_glob: args.json_file.clone(),
+        toml_key: args.toml_key.clone(),
+        toml_file_glob: args.toml_file.clone(),
+        readme_patterns: args.readme_patterns.clone(),
+        bump_build_number: args.bump_build_number,
+        reset_revision: args.reset_revision,
+        all_occurrences: args.all_occurrences,
+        backup: args.backup,
+        max_file_size: args.max_file_size,
+        assert_consistent: args.assert_consistent,
+        workspace_root_only: args.workspace_root_only,
+        exclude_workspace_members: args.exclude_workspace_members,
+        update_internal_deps: args.update_internal_deps,
+        fanout: args.fanout,
+        fanout_covered: args.supported_types.expand().contains(&SupportedTypes::PackageJSON),
+        lerna_strict: args.supported_types == SupportedTypes::Lerna || args.new_version.is_some(),
+        progress: args.progress && !args.quiet && std::io::stderr().is_terminal(),
+        // `uv.toml` is loaded per root, and the version dispatch scopes it per type
+        ..Default::default()
+    };
+    let initial_version = args.initial_version.as_deref().map(Version::parse).transpose()?;
+
+    if args.print_report_schema {
+        println!("{}", serde_json::to_string_pretty(&report_schema())?);
+        return Ok(());
+    }
+
+    if args.list_files {
+        for root in &args.paths {
+            list_files(args.supported_types, root.as_ref(), &walk_options)?;
+        }
+        return Ok(());
+    }
+
+    if args.restore {
+        for root in &args.paths {
+            let restored = restore_backups(root, &walk_options)?;
+            info!("Restored {} file(s) under '{}'", restored.len(), root);
+        }
+        return Ok(());
+    }
+
+    let version = match args.new_version.as_deref() {
+        Some("-") => Some(read_version_from_stdin()?),
+        Some(v) => Some(Version::parse(v)?),
+        None => None,
+    };
+
+    // Run every root even if one fails, then report the failures together
+    let mut outcomes = Vec::new();
+    let mut failures = Vec::new();
+    for root in &args.paths {
+        if args.paths.len() > 1 {
+            info!("Processing '{}'", root);
+        }
+        match run_root(&args, root.as_ref(), version.as_ref(), initial_version.as_ref(), &walk_options) {
+            Ok(outcome) => outcomes.push(outcome),
+            Err(e) if args.paths.len() == 1 => return Err(e),
+            Err(e) => {
+                log::error!("'{}': {:#}", root, e);
+                failures.push(root.as_str());
+            }
+        }
+    }
+    if !failures.is_empty() {
+        anyhow::bail!(
+            "Failed to update {} of {} paths: {}",
+            failures.len(),
+            args.paths.len(),
+            failures.join(", ")
+        );
+    }
+    if args.print_files_json {
+        print_files_json(&outcomes)?;
+    }
+    if args.output == OutputFormat::Json {
+        print_report_json(&args.paths, &outcomes)?;
+    }
+    if args.error_on_noop && outcomes.iter().all(|o| matches!(o, RunOutcome::NoOp(_))) {
+        std::process::exit(NOOP_EXIT_CODE);
+    }
+
+    Ok(())
+}
+
+/// How a run over a single `--path` root ended
+#[derive(Debug, PartialEq, Eq)]
+enum RunOutcome {
+    /// The files were bumped
+    Updated(Release),
+    /// Every file already held this target version
+    NoOp(Version),
+    /// The user declined the `--interactive` prompt
+    Aborted,
+    /// `--print-tag` printed the tag name without changing anything
+    Printed,
+}
+
+/// A bump of one `--path` root
+#[derive(Debug, PartialEq, Eq)]
+struct Release {
+    /// The version read from the files before the bump, if any
+    previous: Option<Version>,
+    /// The version the files were set to
+    version: Version,
+    /// The files the parsers (and any changelog) updated
+    files: Vec<PathBuf>,
+    /// The commit and tag the git step created
+    git: GitRelease,
+}
+
+/// Prints the absolute paths of every file the run updated, across all roots, as a JSON array
+fn print_files_json(outcomes: &[RunOutcome]) -> Result<()> {
+    let mut files = Vec::new();
+    for outcome in outcomes {
+        if let RunOutcome::Updated(release) = outcome {
+            for file in &release.files {
+                files.push(std::path::absolute(file)?.to_string_lossy().to_string());
+            }
+        }
+    }
+    println!("{}", serde_json::to_string(&files)?);
+    Ok(())
+}
+
+/// Prints one `RunReport` per root as a line of JSON
+fn print_report_json(paths: &[String], outcomes: &[RunOutcome]) -> Result<()> {
+    for (path, outcome) in paths.iter().zip(outcomes) {
+        let path = path.clone();
+        let report = match outcome {
+            RunOutcome::Updated(release) => RunReport {
+                path,
+                changed: true,
+                previous: release.previous.as_ref().map(Version::to_string),
+                version: Some(release.version.to_string()),
+                files: release
+                    .files
+                    .iter()
+                    .map(|file| Ok(std::path::absolute(file)?.to_string_lossy().to_string()))
+                    .collect::<Result<_>>()?,
+                commit: release.git.commit.clone(),
+                tag: release.git.tag.clone(),
+            },
+            RunOutcome::NoOp(version) => RunReport {
+                path,
+                previous: Some(version.to_string()),
+                version: Some(version.to_string()),
+                ..Default::default()
+            },
+            RunOutcome::Aborted | RunOutcome::Printed => RunReport { path, ..Default::default() },
+        };
+        println!("{}", serde_json::to_string(&report)?);
+    }
+    Ok(())
+}
+
+/// Runs the full hook, parser and git pipeline for one project root
+fn run_root(
+    args: &Arguments,
+    path: &Path,
+    version: Option<&Version>,
+    initial_version: Option<&Version>,
+    walk_options: &WalkOptions,
+) -> Result<RunOutcome> {
+    if let Some(command) = &args.pre_hook {
+        run_hook("pre-hook", command, path)?;
+    }
+
+    let walk_options = &WalkOptions { config: Config::load(path)?, ..walk_options.clone() };
+
+    if walk_options.assert_consistent {
+        // Check each type on its own, since reading `all` stops at the first type with a version
+        for supported_type in args.supported_types.expand() {
+            if let Err(e) = current_version_for(supported_type, path, walk_options)
+                && matches!(e.downcast_ref::<ParsingError>(), Some(ParsingError::InconsistentVersionsError(_)))
+            {
+                return Err(e);
+            }
+        }
+    }
+
+    if args.abort_on_mismatch {
+        check_tag_matches_files(args, path, walk_options)?;
+    }
+
+    if args.tag_existing_commit {
+        return tag_existing_commit(args, path, walk_options);
+    }
 
     // Get or determine the version to use
-    let final_version = match &version {
+    let final_version = match version {
         Some(v) => v.clone(),
         None => {
+            let bump = if args.auto_bump { Some(auto_bump_kind(args, path, walk_options)?) } else { args.bump };
             // Get current version from first available parser to determine what we'll increment to
-            get_next_version(path, &args.supported_types, &walk_options)?
+            let kind = bump.unwrap_or(BumpKind::Patch);
+            let next = current_version_for(args.supported_types, path, walk_options).and_then(|current| {
+                let next = if args.flutter_bump {
+                    PubspecParser::flutter_bump(&current, bump, &args.prerelease_label)?
+                } else {
+                    bump_version_with_label(&current, kind, &args.prerelease_label)?
+                };
+                Ok(if args.keep_prerelease { keep_prerelease(&current, next, kind) } else { next })
+            });
+            match (next, initial_version) {
+                (Err(e), Some(initial)) if is_no_version_found(&e) => {
+                    info!("No existing version found, starting at {}", initial);
+                    initial.clone()
+                }
+                (result, _) => result?,
+            }
         }
     };
 
-    let mut modified_files = Vec::new();
+    if args.print_tag {
+        println!("{}", git_options(args)?.tag_name(&final_version.to_string()));
+        return Ok(RunOutcome::Printed);
+    }
 
-    match args.supported_types {
-        SupportedTypes::All => {
-            modified_files.extend(apply_version::<TomlParser>(path, &final_version, &walk_options)?);
-            modified_files.extend(apply_version::<PackageJsonParser>(path, &final_version, &walk_options)?);
-            modified_files.extend(apply_version::<TauriConfigParser>(path, &final_version, &walk_options)?);
-        }
-        SupportedTypes::TOML => {
-            modified_files.extend(apply_version::<TomlParser>(path, &final_version, &walk_options)?);
+    // Skip everything, including git, when every file already holds the target version
+    let previous = current_version_for(args.supported_types, path, walk_options).ok();
+    let already_current = previous.is_some()
+        && verify_each_type(args, path, &final_version, walk_options)?.is_empty()
+        && !has_pending_extras(args, path, &final_version, walk_options)?;
+    if already_current {
+        if args.error_on_noop {
+            log::error!("Every file is already at version {}, nothing to do", final_version);
+        } else {
+            info!("Every file is already at version {}, nothing to do", final_version);
         }
-        SupportedTypes::PackageJSON => {
-            modified_files.extend(apply_version::<PackageJsonParser>(path, &final_version, &walk_options)?);
+        return Ok(RunOutcome::NoOp(final_version));
+    }
+
+    if args.interactive && !args.yes {
+        let current = current_version_for(args.supported_types, path, walk_options)
+            .map(|v| v.to_string())
+            .unwrap_or_else(|_| "unknown".to_string());
+        let files = matching_files_for(args.supported_types, path, walk_options)?;
+        if !confirm(&current, &final_version, &files)? {
+            info!("Aborted, no changes made");
+            return Ok(RunOutcome::Aborted);
         }
-        SupportedTypes::TauriConfig => {
-            modified_files.extend(apply_version::<TauriConfigParser>(path, &final_version, &walk_options)?);
+    }
+
+    // Check the changelog up front so a missing Unreleased heading fails before any file changes
+    let changelog_release = if args.changelog_unreleased {
+        Some(changelog::prepare_release(path, &final_version, &release_date(args.commit_date()?))?)
+    } else {
+        None
+    };
+
+    let mut updates = Vec::new();
+    for supported_type in args.supported_types.expand() {
+        let version = match args.version_override(supported_type) {
+            Some(version) => {
+                info!("Using version {} for {:?}", version, supported_type);
+                version
+            }
+            None => &final_version,
+        };
+        let files = update_version_for(supported_type, path, version, walk_options)?;
+        updates.push((supported_type, files));
+    }
+    if updates.iter().all(|(_, files)| files.is_empty()) {
+        return Err(ParsingError::NoMatchingFilesError(path.to_string_lossy().to_string()).into());
+    }
+    let summary = update_summary(&updates);
+    if args.dry_run {
+        info!("Would update {} to {}", summary, final_version);
+    } else {
+        info!("Updated {} to {}", summary, final_version);
+    }
+    let mut modified_files: Vec<PathBuf> = updates.into_iter().flat_map(|(_, files)| files).collect();
+
+    if args.verify && args.dry_run {
+        info!("Dry run: skipping verification");
+    } else if args.verify {
+        let mismatches = verify_each_type(args, path, &final_version, walk_options)?;
+        if !mismatches.is_empty() {
+            return Err(Failure::Validation(anyhow::anyhow!(
+                "Verification failed, {} file(s) don't hold version {}:\n  {}",
+                mismatches.len(),
+                final_version,
+                mismatches.join("\n  ")
+            ))
+            .into());
         }
+        info!("Verified version {} in {} file(s)", final_version, modified_files.len());
+    }
+
+    if let Some(target) = &args.write_version_to {
+        write_version_file(Path::new(target), &final_version, args.dry_run)?;
+    }
+
+    if let Some(release) = changelog_release {
+        modified_files.push(release.write(path, walk_options)?);
     }
 
-    // Handle git operations if mode is not None
+    // Handle git operations if mode is not None; under --dry-run this only logs what would happen
+    let mut git_release = GitRelease::default();
     if args.git_mode != GitMode::None {
-        let git = GitTracker::open(&args.path, args.allow_insecure)?;
-        git.execute_git_mode(args.git_mode, &final_version.to_string(), &modified_files)?;
+        let mut git = open_git(args, path)?;
+        // With per-type versions the files hold no single version for the tag to be checked against
+        let overridden = args.supported_types.expand().into_iter().any(|t| args.version_override(t).is_some());
+        git.options = GitOptions {
+            version_check: (!overridden).then(|| VersionCheck {
+                path: path.to_path_buf(),
+                types: args.supported_types,
+                options: walk_options.clone(),
+            }),
+            ..git_options(args)?
+        };
+        git_release = git
+            .execute_git_mode(args.git_mode, &final_version.to_string(), &modified_files)
+            .map_err(Failure::Git)?;
     }
 
+    Ok(RunOutcome::Updated(Release { previous, version: final_version, files: modified_files, git: git_release }))
+}
+
+/// Tags HEAD with the version the files already hold, without updating files or committing
+fn tag_existing_commit(args: &Arguments, path: &Path, walk_options: &WalkOptions) -> Result<RunOutcome> {
+    if !matches!(args.git_mode, GitMode::CommitTag | GitMode::CommitPushTag) {
+        anyhow::bail!("--tag-existing-commit needs a tagging git mode: commit-tag or commit-push-tag");
+    }
+    let version = current_version_for(args.supported_types, path, walk_options)?;
+    info!("Tagging the existing HEAD commit for version {}", version);
+
+    let mut git = open_git(args, path)?;
+    git.options = git_options(args)?;
+    let git_release = git
+        .execute_git_mode(args.git_mode, &version.to_string(), &[])
+        .map_err(Failure::Git)?;
+    Ok(RunOutcome::Updated(Release { previous: Some(version.clone()), version, files: Vec::new(), git: git_release }))
+}
+
+/// Checks every selected type against the version it was set to: its per-type override, or
+/// `version`
+fn verify_each_type(args: &Arguments, path: &Path, version: &Version, walk_options: &WalkOptions) -> Result<Vec<String>> {
+    let mut mismatches = Vec::new();
+    for supported_type in args.supported_types.expand() {
+        let expected = args.version_override(supported_type).unwrap_or(version);
+        mismatches.extend(verify_version_for(supported_type, path, expected, walk_options)?);
+    }
+    Ok(mismatches)
+}
+
+/// Whether the flags that change more than the version fields still have work to do for
+/// `version`, so a run whose files all hold it isn't a no-op
+fn has_pending_extras(args: &Arguments, path: &Path, version: &Version, walk_options: &WalkOptions) -> Result<bool> {
+    if args.changelog_unreleased && !changelog::is_released(path, version)? {
+        return Ok(true);
+    }
+    if args.update_internal_deps
+        && args.supported_types.expand().contains(&SupportedTypes::TOML)
+        && TomlParser::has_stale_internal_deps(path, version, walk_options)?
+    {
+        return Ok(true);
+    }
+    if let Some(target) = &args.write_version_to {
+        return Ok(std::fs::read_to_string(target).ok() != Some(version.to_string()));
+    }
+    Ok(false)
+}
+
+/// Fails when the files have drifted from the version of the latest version tag
+fn check_tag_matches_files(args: &Arguments, path: &Path, walk_options: &WalkOptions) -> Result<()> {
+    let mut git = open_git(args, path).map_err(Failure::Git)?;
+    git.options = git_options(args)?;
+    let Some(tagged) = git.latest_tagged_version().map_err(Failure::Git)? else {
+        info!("No version tag found, nothing to compare the files against");
+        return Ok(());
+    };
+    let mismatches = verify_version_for(args.supported_types, path, &tagged, walk_options)?;
+    if !mismatches.is_empty() {
+        return Err(Failure::Validation(anyhow::anyhow!(
+            "{} file(s) don't match the latest version tag {}:\n  {}",
+            mismatches.len(),
+            tagged,
+            mismatches.join("\n  ")
+        ))
+        .into());
+    }
     Ok(())
 }
 
-fn apply_version<P: UpdateVersionParser>(
-    path: &Path,
-    version: &Version,
-    options: &WalkOptions,
-) -> Result<Vec<std::path::PathBuf>> {
-    Ok(P::update_version(path, version, options)?)
+/// Reads the commits since the baseline tag and picks the bump they call for
+fn auto_bump_kind(args: &Arguments, path: &Path, walk_options: &WalkOptions) -> Result<BumpKind> {
+    let mut git = open_git(args, path)?;
+    git.options = git_options(args)?;
+    let messages = git.commit_messages_since_tag()?;
+    let current = current_version_for(args.supported_types, path, walk_options)?;
+    let bump = conventional_bump(&messages, &current, args.strict_semver)?;
+    info!("{} commit(s) since the last release call for a {:?} bump", messages.len(), bump);
+    Ok(bump)
 }
 
-/// Gets the next version by reading current version and incrementing patch
-fn get_next_version(
-    path: &Path,
-    supported_types: &SupportedTypes,
-    options: &WalkOptions,
-) -> Result<Version> {
-    // Try to get current version from available parsers
-    let current = match supported_types {
-        SupportedTypes::All | SupportedTypes::TOML => {
-            TomlParser::get_current_version(path, options)
-                .or_else(|_| PackageJsonParser::get_current_version(path, options))
-                .or_else(|_| TauriConfigParser::get_current_version(path, options))
+/// Opens the repository for `path`, or the one at `--git-root` when given
+fn open_git(args: &Arguments, path: &Path) -> Result<GitTracker> {
+    let root = args.git_root.as_deref().map(Path::new).unwrap_or(path);
+    GitTracker::open(root, args.allow_insecure)
+}
+
+/// Builds the git step's options from the command line
+fn git_options(args: &Arguments) -> Result<GitOptions> {
+    Ok(GitOptions {
+        branch: args.branch.clone(),
+        push_branch: args.push_branch.clone(),
+        release_branch: args.release_branch.clone(),
+        remotes: args.remotes.clone(),
+        force_tag: args.force_tag,
+        author: args.author.clone(),
+        pull_before_push: args.pull_before_push,
+        rollback_on_push_failure: args.rollback_on_push_failure,
+        amend: args.amend,
+        sign: args.sign,
+        use_git_cli: args.use_git_cli,
+        no_verify: args.no_verify,
+        detailed_commit: args.detailed_commit,
+        tag_format: args.tag_format(),
+        tag_annotation: args.tag_annotation.clone(),
+        lightweight_tag: args.lightweight_tag,
+        tag_existing_commit: args.tag_existing_commit,
+        since_tag: args.since_tag.clone(),
+        first_parent: args.first_parent,
+        exclude_prerelease: args.exclude_prerelease,
+        prerelease_fallback: args.prerelease_fallback,
+        commit_date: args.commit_date()?,
+        version_check: None,
+        dry_run: args.dry_run,
+        quiet: args.quiet_git,
+    })
+}
+
+/// Prints the files each parser would consider, grouped by type
+fn list_files(supported_types: SupportedTypes, path: &Path, options: &WalkOptions) -> Result<()> {
+    let options = &WalkOptions { config: Config::load(path)?, ..options.clone() };
+    for supported_type in supported_types.expand() {
+        println!("{}:", supported_type.name());
+        for file in matching_files_for(supported_type, path, options)? {
+            println!("  {}", file.display());
         }
-        SupportedTypes::PackageJSON => PackageJsonParser::get_current_version(path, options),
-        SupportedTypes::TauriConfig => TauriConfigParser::get_current_version(path, options),
-    }?;
+    }
+    Ok(())
+}
+
+/// Prints a summary of the pending change and asks the user to confirm it on a TTY
+fn confirm(current: &str, next: &Version, files: &[PathBuf]) -> Result<bool> {
+    if !std::io::stdin().is_terminal() {
+        anyhow::bail!("Cannot prompt for confirmation without a terminal; pass --yes to proceed");
+    }
+
+    eprintln!("Version: {} -> {}", current, next);
+    eprintln!("Files:");
+    for file in files {
+        eprintln!("  - {}", file.display());
+    }
+    eprint!("Proceed? [y/N] ");
+    std::io::stderr().flush()?;
+
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer)?;
+    Ok(matches!(answer.trim().to_ascii_lowercase().as_str(), "y" | "yes"))
+}
+
+/// Returns true if the error is a parser reporting that no version field, or no file at all, was found
+fn is_no_version_found(error: &anyhow::Error) -> bool {
+    matches!(
+        error.downcast_ref::<ParsingError>(),
+        Some(ParsingError::NoVersionFoundError(_) | ParsingError::NoMatchingFilesError(_))
+    )
+}
+
+/// Runs a user-supplied hook command through the platform shell with `cwd` as its working directory
+fn run_hook(name: &str, command: &str, cwd: &Path) -> Result<()> {
+    info!("Running {}: {}", name, command);
+    let mut shell = if cfg!(windows) {
+        let mut shell = std::process::Command::new("cmd");
+        shell.arg("/C");
+        shell
+    } else {
+        let mut shell = std::process::Command::new("sh");
+        shell.arg("-c");
+        shell
+    };
+    let status = shell
+        .arg(command)
+        .current_dir(cwd)
+        .status()
+        .with_context(|| format!("Failed to run {} '{}'", name, command))?;
+    if !status.success() {
+        anyhow::bail!("{} '{}' failed with {}, aborting", name, command, status);
+    }
+    Ok(())
+}
 
-    increment_semver(&current)
+/// Reads a version string from stdin, as passed via `uv -`
+fn read_version_from_stdin() -> Result<Version> {
+    let mut input = String::new();
+    std::io::stdin().read_to_string(&mut input)?;
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(Failure::Validation(anyhow::anyhow!("Expected a version on stdin but received nothing")).into());
+    }
+    Version::parse(input).with_context(|| format!("Invalid version read from stdin: '{}'", input))
+}
+
+/// Writes the bare version string to `target`, creating parent directories as needed
+fn write_version_file(target: &Path, version: &Version, dry_run: bool) -> Result<()> {
+    if dry_run {
+        info!("Would write version {} to '{}'", version, target.display());
+        return Ok(());
+    }
+
+    if let Some(parent) = target.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(target, version.to_string())?;
+    info!("Wrote version {} to '{}'", version, target.display());
+    Ok(())
 }
@@ -0,0 +1,60 @@
+use crate::parsers::Parser;
+use regex::Regex;
+use semver::Version;
+
+/// Parser for Ansible collection `galaxy.yml` files.
+///
+/// Updates the top-level `version:` scalar, quoted or bare. Galaxy requires strict semver, so
+/// prerelease and build metadata are written through unchanged.
+pub struct AnsibleGalaxyParser;
+
+impl Parser for AnsibleGalaxyParser {
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"(?m)^(version:[ \t]*['"]?)(\d+\.\d+\.\d+[^'"\s#]*)(['"]?)"#)?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\]galaxy\.yml$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}${{3}}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_unquoted() {
+        let regex = AnsibleGalaxyParser::version_match_regex().unwrap();
+        let content = "namespace: acme\nname: tools\nversion: 1.2.3\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3");
+    }
+
+    #[test]
+    fn test_version_regex_matches_quoted() {
+        let regex = AnsibleGalaxyParser::version_match_regex().unwrap();
+        let content = "namespace: acme\nversion: \"1.2.3-beta.2\"\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3-beta.2");
+        assert_eq!(captures.get(3).unwrap().as_str(), "\"");
+    }
+
+    #[test]
+    fn test_version_regex_ignores_dependency_versions() {
+        let regex = AnsibleGalaxyParser::version_match_regex().unwrap();
+        let content = "dependencies:\n  community.general: \">=1.0.0\"\n";
+        assert!(regex.captures(content).is_none());
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = AnsibleGalaxyParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/collection/galaxy.yml"));
+        assert!(regex.is_match("\\collection\\galaxy.yml"));
+        assert!(!regex.is_match("/collection/meta/runtime.yml"));
+    }
+}
@@ -0,0 +1,82 @@
+use crate::parsers::Parser;
+use regex::Regex;
+use semver::Version;
+
+/// Parser for legacy .NET `AssemblyInfo.cs` files.
+///
+/// Both `[assembly: AssemblyVersion("...")]` and `[assembly: AssemblyFileVersion("...")]` are
+/// updated. .NET versions have four components; the first three map to `major.minor.patch`
+/// and the fourth (revision or build number) is preserved as-is. Prerelease labels can't be
+/// represented in these attributes, so only `major.minor.patch` is written.
+pub struct AssemblyInfoParser;
+
+impl Parser for AssemblyInfoParser {
+    fn supports_prerelease() -> bool {
+        false
+    }
+
+    fn replace_all_matches() -> bool {
+        true
+    }
+
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(
+            r#"(\[\s*assembly\s*:\s*Assembly(?:File)?Version(?:Attribute)?\s*\(\s*")(\d+\.\d+\.\d+)((?:\.\d+)?"\s*\))"#,
+        )?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\]AssemblyInfo\.cs$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!(
+            "${{1}}{}.{}.{}${{3}}",
+            version.major, version.minor, version.patch
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_both_attributes() {
+        let regex = AssemblyInfoParser::version_match_regex().unwrap();
+        let content = r#"[assembly: AssemblyVersion("1.2.3.4")]
+[assembly: AssemblyFileVersion("1.2.3")]"#;
+        let versions: Vec<&str> = regex
+            .captures_iter(content)
+            .map(|c| c.get(2).unwrap().as_str())
+            .collect();
+        assert_eq!(versions, ["1.2.3", "1.2.3"]);
+    }
+
+    #[test]
+    fn test_version_regex_ignores_informational_version() {
+        let regex = AssemblyInfoParser::version_match_regex().unwrap();
+        let content = r#"[assembly: AssemblyInformationalVersion("1.2.3-beta")]"#;
+        assert!(regex.captures(content).is_none());
+    }
+
+    #[test]
+    fn test_replacement_preserves_revision() {
+        let regex = AssemblyInfoParser::version_match_regex().unwrap();
+        let version = Version::parse("2.0.0-beta.1").unwrap();
+        let format = AssemblyInfoParser::version_line_format(&version).unwrap();
+        let content = r#"[assembly: AssemblyVersion("1.2.3.42")]"#;
+        assert_eq!(
+            regex.replace_all(content, format.as_str()),
+            r#"[assembly: AssemblyVersion("2.0.0.42")]"#
+        );
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = AssemblyInfoParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/MyApp/Properties/AssemblyInfo.cs"));
+        assert!(regex.is_match("\\MyApp\\Properties\\AssemblyInfo.cs"));
+        assert!(!regex.is_match("/MyApp/GlobalAssemblyInfo.cs"));
+    }
+}
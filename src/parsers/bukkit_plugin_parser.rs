@@ -0,0 +1,45 @@
+use crate::parsers::Parser;
+use regex::Regex;
+use semver::Version;
+
+/// Parser for Minecraft Bukkit, Spigot and Paper plugin manifests (`plugin.yml` and
+/// `paper-plugin.yml`).
+///
+/// Updates the top-level `version:` scalar, quoted or bare. Other keys such as `name`, `main`
+/// and `api-version` are left as they are.
+pub struct BukkitPluginParser;
+
+impl Parser for BukkitPluginParser {
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"(?m)^(version:[ \t]*['"]?)(\d+\.\d+\.\d+[^'"\s#]*)(['"]?)"#)?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\](?:paper-)?plugin\.yml$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}${{3}}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_skips_api_version() {
+        let regex = BukkitPluginParser::version_match_regex().unwrap();
+        let content = "name: MyPlugin\napi-version: 1.20.1\nversion: '2.0.1'\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "2.0.1");
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = BukkitPluginParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/src/main/resources/plugin.yml"));
+        assert!(regex.is_match("/src/main/resources/paper-plugin.yml"));
+        assert!(!regex.is_match("/src/main/resources/bungee-plugin.yml"));
+    }
+}
@@ -0,0 +1,63 @@
+use crate::parsers::Parser;
+use regex::Regex;
+use semver::Version;
+
+/// Parser for Haskell `*.cabal` files: the package `version:`, keeping a PVP fourth component.
+pub struct CabalParser;
+
+impl Parser for CabalParser {
+    fn supports_prerelease() -> bool {
+        false
+    }
+
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"(?mi)^(version\s*:[ \t]*)(\d+\.\d+\.\d+)((?:\.\d+)?[ \t\r]*)$"#)?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\][^/\\]+\.cabal$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!(
+            "${{1}}{}.{}.{}${{3}}",
+            version.major, version.minor, version.patch
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_four_part() {
+        let regex = CabalParser::version_match_regex().unwrap();
+        let content = "cabal-version:      3.0\nname:               mylib\nversion:            0.1.2.0\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "0.1.2");
+        assert_eq!(captures.get(3).unwrap().as_str(), ".0");
+    }
+
+    #[test]
+    fn test_version_regex_matches_three_part() {
+        let regex = CabalParser::version_match_regex().unwrap();
+        let captures = regex.captures("Version: 1.2.3\n").unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3");
+    }
+
+    #[test]
+    fn test_version_regex_ignores_build_depends() {
+        let regex = CabalParser::version_match_regex().unwrap();
+        let content = "library\n  build-depends:\n    base >=4.14.0.0 && <5,\n    text ^>=2.0.1\n";
+        assert!(regex.captures(content).is_none());
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = CabalParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/project/mylib.cabal"));
+        assert!(regex.is_match("\\project\\mylib.cabal"));
+        assert!(!regex.is_match("/project/cabal.project"));
+    }
+}
@@ -0,0 +1,64 @@
+use crate::parsers::{Parser, WalkOptions};
+use regex::Regex;
+use semver::Version;
+use std::path::{Path, PathBuf};
+
+/// Parser for the root `CMakeLists.txt`: the `VERSION` of the `project()` call, keeping a tweak
+/// component. Nested ones are often vendored subprojects, so they aren't read.
+pub struct CMakeParser;
+
+impl Parser for CMakeParser {
+    fn supports_prerelease() -> bool {
+        false
+    }
+
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r"(\b(?i:project)\s*\([^)]*?\bVERSION\s+)(\d+\.\d+\.\d+)((?:\.\d+)?)")?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\]CMakeLists\.txt$"#)?)
+    }
+
+    fn get_matching_files(path: impl AsRef<Path>, options: &WalkOptions) -> anyhow::Result<Vec<PathBuf>> {
+        let path = path.as_ref();
+        let files = crate::parsers::walk_matching_files::<Self>(path, options)?;
+        Ok(files.into_iter().filter(|file| file.parent() == Some(path)).collect())
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!(
+            "${{1}}{}.{}.{}${{3}}",
+            version.major, version.minor, version.patch
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_skips_minimum_required() {
+        let regex = CMakeParser::version_match_regex().unwrap();
+        let content = "cmake_minimum_required(VERSION 3.20.0)\nproject(MyApp VERSION 1.2.3 LANGUAGES CXX)\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3");
+    }
+
+    #[test]
+    fn test_version_regex_matches_multiline_call_with_tweak() {
+        let regex = CMakeParser::version_match_regex().unwrap();
+        let content = "PROJECT(\n  MyApp\n  VERSION 1.2.3.4\n  DESCRIPTION \"An app\"\n)\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3");
+        assert_eq!(captures.get(3).unwrap().as_str(), ".4");
+    }
+
+    #[test]
+    fn test_version_regex_ignores_project_without_version() {
+        let regex = CMakeParser::version_match_regex().unwrap();
+        let content = "project(MyApp LANGUAGES C)\nset(MY_VERSION 1.2.3)\n";
+        assert!(regex.captures(content).is_none());
+    }
+}
@@ -0,0 +1,48 @@
+use crate::parsers::Parser;
+use regex::Regex;
+use semver::Version;
+
+/// Parser for Conan `conanfile.py` recipes: the recipe's `version` class attribute.
+pub struct ConanParser;
+
+impl Parser for ConanParser {
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"(?m)^([ \t]*version\s*=\s*["'])(\d+\.\d+\.\d+[^"']*)(["'])"#)?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\]conanfile\.py$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}${{3}}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_class_attribute() {
+        let regex = ConanParser::version_match_regex().unwrap();
+        let content = "class Recipe(ConanFile):\n    name = \"mylib\"\n    version = \"1.2.3\"\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3");
+    }
+
+    #[test]
+    fn test_version_regex_ignores_requirements() {
+        let regex = ConanParser::version_match_regex().unwrap();
+        let content = "    requires = \"zlib/1.2.11\"\n    tool_requires = \"cmake/[>=3.20.0]\"\n";
+        assert!(regex.captures(content).is_none());
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = ConanParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/project/conanfile.py"));
+        assert!(regex.is_match("\\project\\conanfile.py"));
+        assert!(!regex.is_match("/project/conanfile.txt"));
+    }
+}
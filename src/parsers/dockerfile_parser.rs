@@ -0,0 +1,84 @@
+use crate::parsers::Parser;
+use regex::Regex;
+use semver::Version;
+
+/// Parser for Dockerfiles and Containerfiles: the OCI `org.opencontainers.image.version` label.
+/// With `insert_missing`, the label is added after the last `FROM`, so it lands in the final stage.
+pub struct DockerfileParser;
+
+impl Parser for DockerfileParser {
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"(\borg\.opencontainers\.image\.version=["']?)(\d+\.\d+\.\d+[^"'\s]*)(["']?)"#)?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\](?:Dockerfile|Containerfile)(?:\.[^/\\]+)?$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}${{3}}"))
+    }
+
+    fn insert_version(contents: &str, version: &Version) -> anyhow::Result<Option<String>> {
+        let Some(from) = Regex::new(r"(?mi)^[ \t]*FROM[ \t][^\n]*$")?.find_iter(contents).last() else {
+            return Ok(None);
+        };
+
+        let mut updated = contents.to_string();
+        updated.insert_str(from.end(), &format!("\nLABEL org.opencontainers.image.version=\"{version}\""));
+        Ok(Some(updated))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_quoted_label() {
+        let regex = DockerfileParser::version_match_regex().unwrap();
+        let content = "FROM alpine:3.19\nLABEL org.opencontainers.image.version=\"1.2.3\"\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3");
+    }
+
+    #[test]
+    fn test_version_regex_matches_bare_label_among_others() {
+        let regex = DockerfileParser::version_match_regex().unwrap();
+        let content = "LABEL org.opencontainers.image.title=app org.opencontainers.image.version=2.0.0-rc.1 maintainer=me\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "2.0.0-rc.1");
+    }
+
+    #[test]
+    fn test_version_regex_ignores_base_image_tag() {
+        let regex = DockerfileParser::version_match_regex().unwrap();
+        assert!(regex.captures("FROM rust:1.75.0 AS build\n").is_none());
+    }
+
+    #[test]
+    fn test_insert_after_last_from() {
+        let content = "FROM rust:1.75.0 AS build\nRUN cargo build\n\nFROM debian:12-slim\nCOPY --from=build /app /app\n";
+        let updated = DockerfileParser::insert_version(content, &Version::new(1, 0, 0)).unwrap().unwrap();
+        assert_eq!(
+            updated,
+            "FROM rust:1.75.0 AS build\nRUN cargo build\n\nFROM debian:12-slim\nLABEL org.opencontainers.image.version=\"1.0.0\"\nCOPY --from=build /app /app\n"
+        );
+    }
+
+    #[test]
+    fn test_insert_without_from() {
+        assert!(DockerfileParser::insert_version("# empty\n", &Version::new(1, 0, 0)).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = DockerfileParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/project/Dockerfile"));
+        assert!(regex.is_match("\\project\\Dockerfile"));
+        assert!(regex.is_match("/project/Dockerfile.release"));
+        assert!(regex.is_match("/project/Containerfile"));
+        assert!(!regex.is_match("/project/Dockerfile/notes.md"));
+        assert!(!regex.is_match("/project/my-Dockerfile"));
+    }
+}
@@ -0,0 +1,162 @@
+use crate::parsers::{Parser, WalkOptions, split_bom};
+use regex::{Captures, Regex};
+use semver::Version;
+use std::path::Path;
+
+/// Parser for SDK-style .NET project files (`*.csproj`, `*.fsproj`, `*.vbproj`) and
+/// `Directory.Build.props`.
+///
+/// Updates the `<Version>` property, or the `<VersionPrefix>`/`<VersionSuffix>` pair when the
+/// version is split: the prefix holds `major.minor.patch` and the suffix the prerelease label.
+/// A suffix is only read alongside a prefix in the same file, but a file holding just a
+/// `<VersionSuffix>` still has it rewritten. Build metadata isn't written in the split form.
+/// `Directory.Packages.props` pins dependency versions rather than the project's, so it is not
+/// matched.
+pub struct DotnetProjectParser;
+
+impl DotnetProjectParser {
+    fn prefix_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r"(<VersionPrefix>[ \t]*)([^<]*?)([ \t]*</VersionPrefix>)")?)
+    }
+
+    fn suffix_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r"(<VersionSuffix>[ \t]*)([^<]*?)([ \t]*</VersionSuffix>)")?)
+    }
+
+    fn replace_value(body: &str, regex: &Regex, value: &str) -> String {
+        regex
+            .replace(body, |c: &Captures| format!("{}{}{}", &c[1], value, &c[3]))
+            .to_string()
+    }
+
+    /// Adds a `<VersionSuffix>` on the line after the `<VersionPrefix>` element, at the same
+    /// indentation.
+    fn insert_suffix(body: &str, prefix: &Regex, suffix: &str) -> String {
+        let Some(found) = prefix.find(body) else {
+            return body.to_string();
+        };
+        let line_start = body[..found.start()].rfind('\n').map_or(0, |i| i + 1);
+        let indent = &body[line_start..found.start()];
+        let indent = if indent.trim().is_empty() { indent } else { "" };
+        let newline = if body.contains("\r\n") { "\r\n" } else { "\n" };
+        format!(
+            "{}{}{}<VersionSuffix>{}</VersionSuffix>{}",
+            &body[..found.end()],
+            newline,
+            indent,
+            suffix,
+            &body[found.end()..]
+        )
+    }
+}
+
+impl Parser for DotnetProjectParser {
+    fn read_file_version(file: &Path, options: &WalkOptions) -> anyhow::Result<Option<Version>> {
+        let contents = std::fs::read_to_string(file)?;
+        let (_, body) = split_bom(&contents);
+        let Some(captures) = Self::version_match_regex()?.captures(body) else {
+            return Ok(None);
+        };
+        let mut version = captures[2].to_string();
+        if captures[1].starts_with("<VersionPrefix>")
+            && let Some(suffix) = Self::suffix_regex()?.captures(body)
+            && !suffix[2].is_empty()
+        {
+            version = format!("{}-{}", version, &suffix[2]);
+        }
+
+        Ok(Some(Self::parse_found_version(file, &version, options)?))
+    }
+
+    fn rewrite_version(body: &str, version: &Version, _options: &WalkOptions) -> anyhow::Result<Option<String>> {
+        let prefix = Self::prefix_regex()?;
+        let suffix = Self::suffix_regex()?;
+        let has_suffix = suffix.is_match(body);
+        if !prefix.is_match(body) {
+            if has_suffix && !Self::version_match_regex()?.is_match(body) {
+                return Ok(Some(Self::replace_value(body, &suffix, version.pre.as_str())));
+            }
+            return Ok(None);
+        }
+
+        let core = format!("{}.{}.{}", version.major, version.minor, version.patch);
+        let body = Self::replace_value(body, &prefix, &core);
+        let body = if has_suffix {
+            Self::replace_value(&body, &suffix, version.pre.as_str())
+        } else if !version.pre.is_empty() {
+            Self::insert_suffix(&body, &prefix, version.pre.as_str())
+        } else {
+            body
+        };
+        Ok(Some(body))
+    }
+
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(
+            r"(<(?:Version|VersionPrefix)>[ \t]*)(\d+\.\d+\.\d+[^<\s]*)([ \t]*</(?:Version|VersionPrefix)>)",
+        )?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"(?i)(?:[/\\][^/\\]+\.(?:cs|fs|vb)proj|[/\\]Directory\.Build\.props)$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}${{3}}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPLIT: &str = "<Project Sdk=\"Microsoft.NET.Sdk\">\n  <PropertyGroup>\n    <VersionPrefix>1.2.3</VersionPrefix>\n    <VersionSuffix>beta.1</VersionSuffix>\n  </PropertyGroup>\n</Project>\n";
+
+    fn rewrite(body: &str, version: &str) -> String {
+        DotnetProjectParser::rewrite_version(body, &Version::parse(version).unwrap(), &WalkOptions::default())
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_plain_version_uses_regex_replacement() {
+        let body = "<PropertyGroup>\n  <Version>1.2.3</Version>\n</PropertyGroup>\n";
+        let rewritten =
+            DotnetProjectParser::rewrite_version(body, &Version::new(2, 0, 0), &WalkOptions::default()).unwrap();
+        assert!(rewritten.is_none());
+    }
+
+    #[test]
+    fn test_split_prerelease_writes_prefix_and_suffix() {
+        assert_eq!(rewrite(SPLIT, "1.3.0-rc.2"), SPLIT.replace("1.2.3", "1.3.0").replace("beta.1", "rc.2"));
+    }
+
+    #[test]
+    fn test_split_stable_clears_suffix() {
+        assert_eq!(rewrite(SPLIT, "1.3.0"), SPLIT.replace("1.2.3", "1.3.0").replace("beta.1", ""));
+    }
+
+    #[test]
+    fn test_split_inserts_missing_suffix() {
+        let body = "<PropertyGroup>\n    <VersionPrefix>1.2.3</VersionPrefix>\n</PropertyGroup>\n";
+        assert_eq!(
+            rewrite(body, "1.3.0-alpha.1"),
+            "<PropertyGroup>\n    <VersionPrefix>1.3.0</VersionPrefix>\n    <VersionSuffix>alpha.1</VersionSuffix>\n</PropertyGroup>\n"
+        );
+    }
+
+    #[test]
+    fn test_suffix_only_file_is_rewritten() {
+        let body = "<PropertyGroup>\n  <VersionSuffix>beta.1</VersionSuffix>\n</PropertyGroup>\n";
+        assert_eq!(rewrite(body, "1.3.0-rc.1"), body.replace("beta.1", "rc.1"));
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = DotnetProjectParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/src/App/App.csproj"));
+        assert!(regex.is_match("/src/Lib/Lib.fsproj"));
+        assert!(regex.is_match("/Directory.Build.props"));
+        assert!(!regex.is_match("/Directory.Packages.props"));
+    }
+}
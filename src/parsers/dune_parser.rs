@@ -0,0 +1,48 @@
+use crate::parsers::Parser;
+use regex::Regex;
+use semver::Version;
+
+/// Parser for OCaml `dune-project` files: the top-level `(version ...)` stanza.
+pub struct DuneParser;
+
+impl Parser for DuneParser {
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"(?m)^(\(version[ \t]+)(\d+\.\d+\.\d+[^\s)]*)([ \t]*\))"#)?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\]dune-project$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}${{3}}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_stanza() {
+        let regex = DuneParser::version_match_regex().unwrap();
+        let content = "(lang dune 3.0)\n(name mylib)\n(version 1.2.3)\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3");
+    }
+
+    #[test]
+    fn test_version_regex_ignores_lang_and_constraints() {
+        let regex = DuneParser::version_match_regex().unwrap();
+        let content = "(lang dune 3.11)\n(package\n (name mylib)\n (depends (ocaml (>= 4.14.0))))\n";
+        assert!(regex.captures(content).is_none());
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = DuneParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/project/dune-project"));
+        assert!(regex.is_match("\\project\\dune-project"));
+        assert!(!regex.is_match("/project/src/dune"));
+    }
+}
@@ -0,0 +1,83 @@
+use crate::parsers::{Parser, WalkOptions};
+use regex::Regex;
+use semver::Version;
+
+/// Parser for dotenv files (`.env`, `.env.production`, `ci.env`).
+///
+/// Updates one variable assignment, `VERSION` unless configured otherwise, bare or quoted and
+/// optionally prefixed with `export`. Other variables and comments are left alone. `.env` files
+/// are often git-ignored, so those are only found with `no_ignore`.
+pub struct EnvFileParser;
+
+impl EnvFileParser {
+    pub const DEFAULT_VARIABLE: &'static str = "VERSION";
+
+    fn variable_regex(variable: &str) -> anyhow::Result<Regex> {
+        Ok(Regex::new(&format!(
+            r#"(?m)^([ \t]*(?:export[ \t]+)?{}[ \t]*=[ \t]*["']?)(\d+\.\d+\.\d+[^"'\s#]*)(["']?)"#,
+            regex::escape(variable)
+        ))?)
+    }
+}
+
+impl Parser for EnvFileParser {
+    fn include_hidden_files() -> bool {
+        true
+    }
+
+    fn version_match_regex_with(options: &WalkOptions) -> anyhow::Result<Regex> {
+        Self::variable_regex(options.env_variable.as_deref().unwrap_or(Self::DEFAULT_VARIABLE))
+    }
+
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Self::variable_regex(Self::DEFAULT_VARIABLE)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\](?:\.env(?:\.[^/\\]+)?|[^/\\]+\.env)$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}${{3}}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_bare_and_quoted() {
+        let regex = EnvFileParser::version_match_regex().unwrap();
+        let captures = regex.captures("NAME=app\nVERSION=1.2.3\n").unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3");
+        let captures = regex.captures("export VERSION=\"2.0.0-rc.1\"\n").unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "2.0.0-rc.1");
+        assert_eq!(captures.get(3).unwrap().as_str(), "\"");
+    }
+
+    #[test]
+    fn test_version_regex_ignores_similar_names() {
+        let regex = EnvFileParser::version_match_regex().unwrap();
+        assert!(regex.captures("NODE_VERSION=20.1.0\nVERSION_CODE=1.2.3\n# VERSION=0.0.1\n").is_none());
+    }
+
+    #[test]
+    fn test_custom_variable() {
+        let options = WalkOptions { env_variable: Some("APP_VERSION".to_string()), ..Default::default() };
+        let regex = EnvFileParser::version_match_regex_with(&options).unwrap();
+        let captures = regex.captures("VERSION=9.9.9\nAPP_VERSION=1.4.0\n").unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.4.0");
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = EnvFileParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/project/.env"));
+        assert!(regex.is_match("\\project\\.env"));
+        assert!(regex.is_match("/project/.env.production"));
+        assert!(regex.is_match("/project/ci.env"));
+        assert!(!regex.is_match("/project/.envrc"));
+        assert!(!regex.is_match("/project/environment.ts"));
+    }
+}
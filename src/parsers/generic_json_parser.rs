@@ -0,0 +1,290 @@
+use crate::parsers::{Parser, WalkOptions, glob_regex, split_bom, split_v_prefix, walk_files};
+use regex::Regex;
+use semver::Version;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/// Parser for arbitrary JSON files, configured with a filename glob and a JSON Pointer
+/// (RFC 6901) to the version string, e.g. `/metadata/version`.
+///
+/// The version is read with `serde_json`, but written by replacing only the bytes of the
+/// pointed-at value, so the rest of the file keeps its formatting, key order and indentation.
+/// A leading `v` on the old version is kept. Without a pointer no files match.
+pub struct GenericJsonParser;
+
+impl GenericJsonParser {
+    /// Splits a JSON Pointer into its unescaped reference tokens
+    fn pointer_tokens(pointer: &str) -> anyhow::Result<Vec<String>> {
+        if pointer.is_empty() {
+            return Ok(Vec::new());
+        }
+        let Some(rest) = pointer.strip_prefix('/') else {
+            anyhow::bail!("Invalid JSON Pointer '{}': it must start with '/'", pointer);
+        };
+        Ok(rest.split('/').map(|t| t.replace("~1", "/").replace("~0", "~")).collect())
+    }
+
+    /// The version string at the configured pointer in `body`, if the file has one
+    fn pointer_value(body: &str, options: &WalkOptions) -> anyhow::Result<Option<String>> {
+        let Some(pointer) = &options.json_pointer else {
+            return Ok(None);
+        };
+        let Ok(json) = serde_json::from_str::<serde_json::Value>(body) else {
+            return Ok(None);
+        };
+        Ok(json.pointer(pointer).and_then(|v| v.as_str()).map(str::to_string))
+    }
+}
+
+impl Parser for GenericJsonParser {
+    fn get_matching_files(path: impl AsRef<Path>, options: &WalkOptions) -> anyhow::Result<Vec<PathBuf>> {
+        let (Some(_), Some(glob)) = (&options.json_pointer, &options.json_file_glob) else {
+            return Ok(Vec::new());
+        };
+        let mut files = Vec::new();
+        for file in walk_files(path, options, &glob_regex(glob)?)? {
+            let contents = std::fs::read_to_string(&file)?;
+            if Self::pointer_value(split_bom(&contents).1, options)?.is_some() {
+                files.push(file);
+            }
+        }
+        Ok(files)
+    }
+
+    fn read_file_version(file: &Path, options: &WalkOptions) -> anyhow::Result<Option<Version>> {
+        let contents = std::fs::read_to_string(file)?;
+        match Self::pointer_value(split_bom(&contents).1, options)? {
+            Some(raw) => Ok(Some(Self::parse_found_version(file, &raw, options)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn rewrite_version(body: &str, version: &Version, options: &WalkOptions) -> anyhow::Result<Option<String>> {
+        let Some(pointer) = &options.json_pointer else {
+            return Ok(Some(body.to_string()));
+        };
+        let tokens = Self::pointer_tokens(pointer)?;
+        let Some(span) = Scanner::new(body).find(&tokens)? else {
+            return Ok(Some(body.to_string()));
+        };
+        if !body[span.clone()].starts_with('"') {
+            anyhow::bail!("The value at JSON Pointer '{}' is not a string", pointer);
+        }
+
+        let prefix = split_v_prefix(&body[span.start + 1..span.end]).0;
+        let mut updated = body.to_string();
+        updated.replace_range(span, &format!("\"{prefix}{version}\""));
+        Ok(Some(updated))
+    }
+
+    fn accepts_v_prefix() -> bool {
+        true
+    }
+
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"("version"\s*:\s*")(\d+\.\d+\.\d+[^"]*)(")"#)?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"\.json$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}${{3}}"))
+    }
+}
+
+/// Minimal JSON scanner that finds the byte range of the value a pointer refers to, without
+/// re-serializing anything
+struct Scanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { bytes: text.as_bytes(), pos: 0 }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> anyhow::Result<()> {
+        self.skip_whitespace();
+        if self.peek() != Some(byte) {
+            anyhow::bail!("Malformed JSON: expected '{}' at byte {}", byte as char, self.pos);
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    /// Reads a string starting at the opening quote and returns its raw contents
+    fn string(&mut self) -> anyhow::Result<&'a str> {
+        self.expect(b'"')?;
+        let start = self.pos;
+        while let Some(byte) = self.peek() {
+            match byte {
+                b'\\' => self.pos += 2,
+                b'"' => {
+                    let raw = std::str::from_utf8(&self.bytes[start..self.pos])?;
+                    self.pos += 1;
+                    return Ok(raw);
+                }
+                _ => self.pos += 1,
+            }
+        }
+        anyhow::bail!("Malformed JSON: unterminated string")
+    }
+
+    fn skip_value(&mut self) -> anyhow::Result<()> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'"') => {
+                self.string()?;
+            }
+            Some(open @ (b'{' | b'[')) => {
+                let close = if open == b'{' { b'}' } else { b']' };
+                self.pos += 1;
+                loop {
+                    self.skip_whitespace();
+                    match self.peek() {
+                        Some(byte) if byte == close => {
+                            self.pos += 1;
+                            break;
+                        }
+                        Some(b',' | b':') => self.pos += 1,
+                        Some(_) => self.skip_value()?,
+                        None => anyhow::bail!("Malformed JSON: unterminated container"),
+                    }
+                }
+            }
+            Some(_) => {
+                while !matches!(self.peek(), None | Some(b',' | b'}' | b']' | b' ' | b'\t' | b'\n' | b'\r')) {
+                    self.pos += 1;
+                }
+            }
+            None => anyhow::bail!("Malformed JSON: unexpected end of input"),
+        }
+        Ok(())
+    }
+
+    /// Follows `tokens` from the current position and returns the span of the value they
+    /// lead to, or `None` if any step is missing
+    fn find(&mut self, tokens: &[String]) -> anyhow::Result<Option<Range<usize>>> {
+        self.skip_whitespace();
+        let Some((token, rest)) = tokens.split_first() else {
+            let start = self.pos;
+            self.skip_value()?;
+            return Ok(Some(start..self.pos));
+        };
+
+        match self.peek() {
+            Some(b'{') => {
+                self.pos += 1;
+                loop {
+                    self.skip_whitespace();
+                    if self.peek() == Some(b'}') {
+                        return Ok(None);
+                    }
+                    let key = self.string()?;
+                    self.expect(b':')?;
+                    if key == token {
+                        return self.find(rest);
+                    }
+                    self.skip_value()?;
+                    self.skip_whitespace();
+                    if self.peek() == Some(b',') {
+                        self.pos += 1;
+                    }
+                }
+            }
+            Some(b'[') => {
+                let Ok(index) = token.parse::<usize>() else {
+                    return Ok(None);
+                };
+                self.pos += 1;
+                for _ in 0..index {
+                    self.skip_whitespace();
+                    if self.peek() == Some(b']') {
+                        return Ok(None);
+                    }
+                    self.skip_value()?;
+                    self.skip_whitespace();
+                    if self.peek() == Some(b',') {
+                        self.pos += 1;
+                    }
+                }
+                self.skip_whitespace();
+                if self.peek() == Some(b']') {
+                    return Ok(None);
+                }
+                self.find(rest)
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pointer(p: &str) -> WalkOptions {
+        WalkOptions { json_pointer: Some(p.to_string()), ..Default::default() }
+    }
+
+    #[test]
+    fn test_pointer_tokens_unescape() {
+        assert_eq!(GenericJsonParser::pointer_tokens("/a~1b/c~0d/0").unwrap(), ["a/b", "c~d", "0"]);
+        assert!(GenericJsonParser::pointer_tokens("a/b").is_err());
+    }
+
+    #[test]
+    fn test_rewrite_nested_pointer_preserves_formatting() {
+        let body = "{\n    \"version\": \"9.9.9\",\n    \"a\": { \"b\": {\"version\" : \"1.0.0\", \"x\": [1, 2]} }\n}\n";
+        let updated = GenericJsonParser::rewrite_version(body, &Version::new(1, 1, 0), &pointer("/a/b/version"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(updated, body.replace("\"1.0.0\"", "\"1.1.0\""));
+    }
+
+    #[test]
+    fn test_rewrite_array_index() {
+        let body = r#"{"releases": [{"version": "1.0.0"}, {"version": "2.0.0"}]}"#;
+        let updated = GenericJsonParser::rewrite_version(body, &Version::new(2, 1, 0), &pointer("/releases/1/version"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(updated, r#"{"releases": [{"version": "1.0.0"}, {"version": "2.1.0"}]}"#);
+    }
+
+    #[test]
+    fn test_rewrite_keeps_v_prefix() {
+        let body = r#"{"meta": {"version": "V1.0.0"}}"#;
+        let updated = GenericJsonParser::rewrite_version(body, &Version::new(2, 0, 0), &pointer("/meta/version"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(updated, r#"{"meta": {"version": "V2.0.0"}}"#);
+    }
+
+    #[test]
+    fn test_rewrite_missing_pointer_leaves_body() {
+        let body = r#"{"name": "app"}"#;
+        let updated = GenericJsonParser::rewrite_version(body, &Version::new(1, 0, 0), &pointer("/meta/version"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(updated, body);
+    }
+
+    #[test]
+    fn test_rewrite_rejects_non_string() {
+        let body = r#"{"version": 3}"#;
+        assert!(GenericJsonParser::rewrite_version(body, &Version::new(1, 0, 0), &pointer("/version")).is_err());
+    }
+}
@@ -0,0 +1,200 @@
+use crate::parsers::{Parser, WalkOptions, glob_regex, split_bom, split_v_prefix, walk_files};
+use regex::Regex;
+use semver::Version;
+use std::path::{Path, PathBuf};
+use toml_edit::{DocumentMut, Item};
+
+/// Parser for arbitrary TOML files, configured with a filename glob and a dotted key path to
+/// the version string, e.g. `package.metadata.app.version` or `bin[0].version`.
+///
+/// The file is edited with `toml_edit`, so comments, key order and whitespace around the
+/// updated value are preserved, as is a leading `v` on the old version. Without a key path no
+/// files match.
+pub struct GenericTomlParser;
+
+/// One step of a key path: a table key or an index into an array (of tables)
+#[derive(Debug, PartialEq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+impl GenericTomlParser {
+    /// Splits a key path like `a.b[1].c` into its segments
+    fn path_segments(path: &str) -> anyhow::Result<Vec<Segment>> {
+        let mut segments = Vec::new();
+        for part in path.split('.') {
+            let (key, mut indices) = part.split_once('[').map_or((part, ""), |(key, rest)| (key, rest));
+            if key.is_empty() {
+                anyhow::bail!("Invalid TOML key path '{}': empty key", path);
+            }
+            segments.push(Segment::Key(key.to_string()));
+            while !indices.is_empty() {
+                let Some((index, rest)) = indices.split_once(']') else {
+                    anyhow::bail!("Invalid TOML key path '{}': unclosed '['", path);
+                };
+                let Ok(index) = index.parse::<usize>() else {
+                    anyhow::bail!("Invalid TOML key path '{}': '{}' is not an index", path, index);
+                };
+                segments.push(Segment::Index(index));
+                indices = rest.strip_prefix('[').unwrap_or(rest);
+                if !rest.is_empty() && !rest.starts_with('[') {
+                    anyhow::bail!("Invalid TOML key path '{}': unexpected '{}'", path, rest);
+                }
+            }
+        }
+        Ok(segments)
+    }
+
+    fn lookup<'a>(item: &'a Item, segments: &[Segment]) -> Option<&'a Item> {
+        segments.iter().try_fold(item, |item, segment| match segment {
+            Segment::Key(key) => item.get(key.as_str()),
+            Segment::Index(index) => item.get(*index),
+        })
+    }
+
+    fn lookup_mut<'a>(item: &'a mut Item, segments: &[Segment]) -> Option<&'a mut Item> {
+        segments.iter().try_fold(item, |item, segment| match segment {
+            Segment::Key(key) => item.get_mut(key.as_str()),
+            Segment::Index(index) => item.get_mut(*index),
+        })
+    }
+
+    /// The version string at the configured key path in `body`, if the file has one
+    fn key_value(body: &str, options: &WalkOptions) -> anyhow::Result<Option<String>> {
+        let Some(key) = &options.toml_key else {
+            return Ok(None);
+        };
+        let segments = Self::path_segments(key)?;
+        let Ok(document) = body.parse::<DocumentMut>() else {
+            return Ok(None);
+        };
+        Ok(Self::lookup(document.as_item(), &segments).and_then(Item::as_str).map(str::to_string))
+    }
+}
+
+impl Parser for GenericTomlParser {
+    fn get_matching_files(path: impl AsRef<Path>, options: &WalkOptions) -> anyhow::Result<Vec<PathBuf>> {
+        let (Some(_), Some(glob)) = (&options.toml_key, &options.toml_file_glob) else {
+            return Ok(Vec::new());
+        };
+        let mut files = Vec::new();
+        for file in walk_files(path, options, &glob_regex(glob)?)? {
+            let contents = std::fs::read_to_string(&file)?;
+            if Self::key_value(split_bom(&contents).1, options)?.is_some() {
+                files.push(file);
+            }
+        }
+        Ok(files)
+    }
+
+    fn read_file_version(file: &Path, options: &WalkOptions) -> anyhow::Result<Option<Version>> {
+        let contents = std::fs::read_to_string(file)?;
+        match Self::key_value(split_bom(&contents).1, options)? {
+            Some(raw) => Ok(Some(Self::parse_found_version(file, &raw, options)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn rewrite_version(body: &str, version: &Version, options: &WalkOptions) -> anyhow::Result<Option<String>> {
+        let Some(key) = &options.toml_key else {
+            return Ok(Some(body.to_string()));
+        };
+        let segments = Self::path_segments(key)?;
+        let mut document = body.parse::<DocumentMut>()?;
+        // `get_mut` inserts missing table keys, so check the path exists before borrowing mutably
+        if Self::lookup(document.as_item(), &segments).is_none() {
+            return Ok(Some(body.to_string()));
+        }
+        let Some(item) = Self::lookup_mut(document.as_item_mut(), &segments) else {
+            return Ok(Some(body.to_string()));
+        };
+        let Some(value) = item.as_value_mut().filter(|value| value.is_str()) else {
+            anyhow::bail!("The value at TOML key '{}' is not a string", key);
+        };
+
+        let prefix = value.as_str().map_or("", |old| split_v_prefix(old).0).to_string();
+        let decor = value.decor().clone();
+        *value = format!("{prefix}{version}").into();
+        *value.decor_mut() = decor;
+        Ok(Some(document.to_string()))
+    }
+
+    fn accepts_v_prefix() -> bool {
+        true
+    }
+
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"(?m)^(\s*version\s*=\s*")(\d+\.\d+\.\d+[^"]*)(")"#)?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"\.toml$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}${{3}}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(k: &str) -> WalkOptions {
+        WalkOptions { toml_key: Some(k.to_string()), ..Default::default() }
+    }
+
+    #[test]
+    fn test_path_segments() {
+        assert_eq!(
+            GenericTomlParser::path_segments("bin[1].meta.version").unwrap(),
+            [
+                Segment::Key("bin".to_string()),
+                Segment::Index(1),
+                Segment::Key("meta".to_string()),
+                Segment::Key("version".to_string()),
+            ]
+        );
+        assert_eq!(
+            GenericTomlParser::path_segments("grid[0][2]").unwrap(),
+            [Segment::Key("grid".to_string()), Segment::Index(0), Segment::Index(2)]
+        );
+        assert!(GenericTomlParser::path_segments("a..b").is_err());
+        assert!(GenericTomlParser::path_segments("a[x]").is_err());
+        assert!(GenericTomlParser::path_segments("a[0").is_err());
+    }
+
+    #[test]
+    fn test_rewrite_array_of_tables_index() {
+        let body = "[[bin]]\nname = \"a\"\nversion = \"1.0.0\"\n\n[[bin]]\nname = \"b\"\nversion = \"2.0.0\" # pinned\n";
+        let updated = GenericTomlParser::rewrite_version(body, &Version::new(2, 1, 0), &key("bin[1].version"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(updated, body.replace("2.0.0", "2.1.0"));
+    }
+
+    #[test]
+    fn test_rewrite_inline_table() {
+        let body = "tool = { name = \"x\", version = \"0.1.0\" }\n";
+        let updated = GenericTomlParser::rewrite_version(body, &Version::new(0, 2, 0), &key("tool.version"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(updated, "tool = { name = \"x\", version = \"0.2.0\" }\n");
+    }
+
+    #[test]
+    fn test_rewrite_missing_key_leaves_body() {
+        let body = "[package]\nname = \"app\"\n";
+        let updated = GenericTomlParser::rewrite_version(body, &Version::new(1, 0, 0), &key("package.metadata.version"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(updated, body);
+    }
+
+    #[test]
+    fn test_rewrite_rejects_non_string() {
+        let body = "version = 3\n";
+        assert!(GenericTomlParser::rewrite_version(body, &Version::new(1, 0, 0), &key("version")).is_err());
+    }
+}
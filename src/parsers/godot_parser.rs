@@ -0,0 +1,50 @@
+use crate::parsers::Parser;
+use regex::Regex;
+use semver::Version;
+
+/// Parser for Godot `project.godot` files: `config/version` in the `[application]` section.
+pub struct GodotParser;
+
+impl Parser for GodotParser {
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(
+            r#"(?m)(^\[application\][ \t]*\r?\n(?:[^\[\n][^\n]*\n|\r?\n)*?config/version\s*=\s*")(\d+\.\d+\.\d+[^"]*)(")"#,
+        )?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\]project\.godot$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}${{3}}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_application_section() {
+        let regex = GodotParser::version_match_regex().unwrap();
+        let content = "[application]\n\nconfig/name=\"My Game\"\nconfig/version=\"1.2.3\"\nrun/main_scene=\"res://main.tscn\"\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3");
+    }
+
+    #[test]
+    fn test_version_regex_ignores_other_sections() {
+        let regex = GodotParser::version_match_regex().unwrap();
+        let content = "[application]\n\nconfig/name=\"My Game\"\n\n[plugin]\n\nconfig/version=\"9.9.9\"\n";
+        assert!(regex.captures(content).is_none());
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = GodotParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/game/project.godot"));
+        assert!(regex.is_match("\\game\\project.godot"));
+        assert!(!regex.is_match("/game/export_presets.cfg"));
+    }
+}
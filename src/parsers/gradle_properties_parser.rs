@@ -0,0 +1,97 @@
+use crate::parsers::{Parser, WalkOptions};
+use anyhow::Context;
+use regex::{Captures, Regex};
+use semver::Version;
+
+/// Parser for Gradle `gradle.properties` files.
+///
+/// Updates the `version` or Android `versionName` property. `versionCode` is a plain integer
+/// rather than semver; it is left alone unless `bump_build_number` is set, in which case it is
+/// incremented by one.
+pub struct GradlePropertiesParser;
+
+impl GradlePropertiesParser {
+    fn version_code_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r"(?m)^([ \t]*versionCode[ \t]*[=:][ \t]*)(\d+)")?)
+    }
+}
+
+impl Parser for GradlePropertiesParser {
+    fn update_extra_fields(contents: String, options: &WalkOptions) -> anyhow::Result<String> {
+        if !options.bump_build_number {
+            return Ok(contents);
+        }
+        let regex = Self::version_code_regex()?;
+        let Some(captures) = regex.captures(&contents) else {
+            return Ok(contents);
+        };
+        let code: u64 = captures[2]
+            .parse()
+            .with_context(|| format!("Invalid versionCode '{}'", &captures[2]))?;
+        Ok(regex
+            .replace(&contents, |c: &Captures| format!("{}{}", &c[1], code + 1))
+            .to_string())
+    }
+
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(
+            r"(?m)^([ \t]*(?:version|versionName)[ \t]*[=:][ \t]*)(\d+\.\d+\.\d+[^\s]*)",
+        )?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\]gradle\.properties$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_version_name() {
+        let regex = GradlePropertiesParser::version_match_regex().unwrap();
+        let content = "org.gradle.jvmargs=-Xmx2g\nversionName=1.2.3\nversionCode=12\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3");
+    }
+
+    #[test]
+    fn test_version_regex_matches_version() {
+        let regex = GradlePropertiesParser::version_match_regex().unwrap();
+        let content = "group=org.acme\nversion = 0.4.0-SNAPSHOT\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "0.4.0-SNAPSHOT");
+    }
+
+    #[test]
+    fn test_version_regex_ignores_other_version_properties() {
+        let regex = GradlePropertiesParser::version_match_regex().unwrap();
+        let content = "kotlinVersion=1.9.22\nagp.version=8.2.0\n";
+        assert!(regex.captures(content).is_none());
+    }
+
+    #[test]
+    fn test_version_code_bumped_only_when_enabled() {
+        let content = "versionName=1.2.3\nversionCode=12\n".to_string();
+        let unchanged =
+            GradlePropertiesParser::update_extra_fields(content.clone(), &WalkOptions::default()).unwrap();
+        assert_eq!(unchanged, content);
+
+        let options = WalkOptions { bump_build_number: true, ..Default::default() };
+        let bumped = GradlePropertiesParser::update_extra_fields(content, &options).unwrap();
+        assert_eq!(bumped, "versionName=1.2.3\nversionCode=13\n");
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = GradlePropertiesParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/project/gradle.properties"));
+        assert!(regex.is_match("\\project\\app\\gradle.properties"));
+        assert!(!regex.is_match("/project/local.properties"));
+    }
+}
@@ -0,0 +1,70 @@
+use crate::parsers::Parser;
+use regex::Regex;
+use semver::Version;
+
+/// Parser for Julia `Project.toml` files: the `version` key before the first `[section]`.
+pub struct JuliaProjectParser;
+
+impl Parser for JuliaProjectParser {
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(
+            r##"\A((?:(?:[^\[\n][^\n]*)?\n)*?version\s*=\s*")(\d+\.\d+\.\d+[^"]*)""##,
+        )?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\](?:Julia)?Project\.toml$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}\""))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_top_level() {
+        let regex = JuliaProjectParser::version_match_regex().unwrap();
+        let content = r#"name = "MyPkg"
+uuid = "7876af07-990d-54b4-ab0e-23690620f79a"
+authors = ["Someone <someone@example.com>"]
+version = "0.3.1"
+
+[deps]
+LinearAlgebra = "37e2e46d-f89d-539d-b4ee-838fcccc9c8e"
+"#;
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "0.3.1");
+    }
+
+    #[test]
+    fn test_version_regex_ignores_section_keys() {
+        let regex = JuliaProjectParser::version_match_regex().unwrap();
+        let content = r#"name = "MyPkg"
+
+[extras]
+version = "1.0.0"
+"#;
+        assert!(regex.captures(content).is_none());
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = JuliaProjectParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/path/to/Project.toml"));
+        assert!(regex.is_match("\\path\\to\\JuliaProject.toml"));
+        assert!(!regex.is_match("/path/to/Manifest.toml"));
+        assert!(!regex.is_match("/path/to/Cargo.toml"));
+        assert!(!regex.is_match("/path/to/MyProject.toml"));
+    }
+
+    #[test]
+    fn test_version_line_format() {
+        let version = Version::parse("1.2.3").unwrap();
+        let formatted = JuliaProjectParser::version_line_format(&version).unwrap();
+        assert_eq!(formatted, "${1}1.2.3\"");
+    }
+}
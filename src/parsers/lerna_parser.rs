@@ -0,0 +1,97 @@
+use crate::parsers::{Parser, WalkOptions, package_json_parser::PackageJsonParser};
+use log::warn;
+use regex::Regex;
+use semver::Version;
+use std::path::{Path, PathBuf};
+
+/// Parser for Lerna `lerna.json` monorepo configs.
+///
+/// Updates the top-level `"version"`, the single source of truth in fixed mode. In independent
+/// mode (`"version": "independent"`) there is no version to read. Setting one needs `fanout`,
+/// which sets every workspace `package.json` instead; without it the config is skipped with a
+/// warning, or refused under `lerna_strict`.
+pub struct LernaParser;
+
+impl LernaParser {
+    /// Updates `lerna.json` and, with `fanout`, every `package.json` under `path` as well
+    /// (unless `fanout_covered`). Independent-mode configs without `fanout` are skipped with a
+    /// warning, or refused under `lerna_strict`.
+    pub fn update_workspace(
+        path: impl AsRef<Path>,
+        version: &Version,
+        options: &WalkOptions,
+    ) -> anyhow::Result<Vec<PathBuf>> {
+        let path = path.as_ref();
+        let independent = Regex::new(r#"(?m)^[ \t]{0,4}"version"\s*:\s*"independent""#)?;
+        let mut skipped = Vec::new();
+        if !options.fanout {
+            for file in Self::get_matching_files(path, options)? {
+                if !independent.is_match(&std::fs::read_to_string(&file)?) {
+                    continue;
+                }
+                if options.lerna_strict {
+                    anyhow::bail!(
+                        "'{}' uses independent versioning, so there is no single version to set. \
+                         Pass --fanout to set every package to {}",
+                        file.display(),
+                        version
+                    );
+                }
+                warn!(
+                    "Skipping '{}': it uses independent versioning (pass --fanout to set every package)",
+                    file.display()
+                );
+                skipped.push(file);
+            }
+        }
+
+        // Independent files have no version for the regex to match, so they're left as they are
+        let mut files = Self::update_version(path, version, options)?;
+        files.retain(|file| !skipped.contains(file));
+        if options.fanout && !options.fanout_covered {
+            files.extend(PackageJsonParser::update_version(path, version, options)?);
+        }
+        Ok(files)
+    }
+}
+
+impl Parser for LernaParser {
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"(?m)^([ \t]{0,4}"version"\s*:\s*")(\d+\.\d+\.\d+[^"]*)(")"#)?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\]lerna\.json$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}${{3}}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_fixed_version() {
+        let regex = LernaParser::version_match_regex().unwrap();
+        let content = "{\n  \"$schema\": \"node_modules/lerna/schemas/lerna-schema.json\",\n  \"version\": \"3.1.0\"\n}";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "3.1.0");
+    }
+
+    #[test]
+    fn test_version_regex_skips_independent() {
+        let regex = LernaParser::version_match_regex().unwrap();
+        assert!(regex.captures("{\n  \"version\": \"independent\"\n}").is_none());
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = LernaParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/repo/lerna.json"));
+        assert!(regex.is_match("\\repo\\lerna.json"));
+        assert!(!regex.is_match("/repo/package.json"));
+    }
+}
@@ -0,0 +1,124 @@
+use crate::parsers::Parser;
+use regex::Regex;
+use semver::Version;
+
+/// Parser for Maven `pom.xml` files.
+///
+/// The project's own `<version>` is the one directly under `<project>`, after `<modelVersion>`
+/// or the `<parent>` block; parent and dependency versions are never matched. When that version
+/// is a `${property}` placeholder (CI-friendly versions such as `${revision}`), the property
+/// definition in `<properties>` is updated instead.
+pub struct MavenParser;
+
+impl MavenParser {
+    /// Matches the project-level `<version>` whose value matches `value`, skipping only leaf
+    /// elements (`<groupId>`, `<artifactId>`, ...) and comments after `<modelVersion>`/`</parent>`.
+    fn project_version_regex(value: &str) -> anyhow::Result<Regex> {
+        Ok(Regex::new(&format!(
+            r"(?s)\A(.*?(?:</parent>|<modelVersion>[^<]*</modelVersion>)\s*(?:(?:<[\w.-]+>[^<]*</[\w.-]+>|<!--.*?-->)\s*)*?<version>)({value})(</version>)"
+        ))?)
+    }
+
+    fn property_regex(name: &str) -> anyhow::Result<Regex> {
+        let name = regex::escape(name);
+        Ok(Regex::new(&format!(
+            r"(?s)(<properties>.*?<{name}>\s*)(\d+\.\d+\.\d+[^<]*?)(\s*</{name}>)"
+        ))?)
+    }
+}
+
+impl Parser for MavenParser {
+    fn file_match_regex(contents: &str, regex: &Regex) -> anyhow::Result<Regex> {
+        let placeholder = Self::project_version_regex(r"\$\{[\w.-]+\}")?;
+        match placeholder.captures(contents) {
+            Some(captures) => {
+                let value = &captures[2];
+                Self::property_regex(&value[2..value.len() - 1])
+            }
+            None => Ok(regex.clone()),
+        }
+    }
+
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Self::project_version_regex(r"\d+\.\d+\.\d+[^<]*")
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\]pom\.xml$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}${{3}}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POM_WITH_PARENT: &str = r#"<project>
+  <modelVersion>4.0.0</modelVersion>
+  <parent>
+    <groupId>org.acme</groupId>
+    <artifactId>acme-parent</artifactId>
+    <version>9.9.9</version>
+  </parent>
+  <groupId>org.acme</groupId>
+  <artifactId>app</artifactId>
+  <!-- bumped by release tooling -->
+  <version>1.2.3-SNAPSHOT</version>
+  <dependencies>
+    <dependency>
+      <groupId>junit</groupId>
+      <artifactId>junit</artifactId>
+      <version>4.13.2</version>
+    </dependency>
+  </dependencies>
+</project>"#;
+
+    #[test]
+    fn test_version_regex_skips_parent_version() {
+        let regex = MavenParser::version_match_regex().unwrap();
+        let captures = regex.captures(POM_WITH_PARENT).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3-SNAPSHOT");
+    }
+
+    #[test]
+    fn test_version_regex_without_parent() {
+        let regex = MavenParser::version_match_regex().unwrap();
+        let content = "<project>\n  <modelVersion>4.0.0</modelVersion>\n  <groupId>a</groupId>\n  <artifactId>b</artifactId>\n  <version>0.1.0</version>\n</project>";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "0.1.0");
+    }
+
+    #[test]
+    fn test_version_regex_ignores_inherited_version() {
+        let regex = MavenParser::version_match_regex().unwrap();
+        let content = POM_WITH_PARENT.replace("  <version>1.2.3-SNAPSHOT</version>\n", "");
+        assert!(regex.captures(&content).is_none());
+    }
+
+    #[test]
+    fn test_file_regex_follows_property_placeholder() {
+        let content = r#"<project>
+  <modelVersion>4.0.0</modelVersion>
+  <artifactId>app</artifactId>
+  <version>${revision}</version>
+  <properties>
+    <java.version>17</java.version>
+    <revision>2.0.0</revision>
+  </properties>
+</project>"#;
+        let regex = MavenParser::file_match_regex(content, &MavenParser::version_match_regex().unwrap()).unwrap();
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "2.0.0");
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = MavenParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/project/pom.xml"));
+        assert!(regex.is_match("\\project\\module\\pom.xml"));
+        assert!(!regex.is_match("/project/pom.xml.bak"));
+    }
+}
@@ -1,26 +1,343 @@
-use anyhow::Result;
-use log::{debug, info};
+use crate::config::{CONFIG_FILE_NAME, Config};
+use anyhow::{Context, Result};
+use indicatif::{ProgressBar, ProgressStyle};
+use log::{Level, debug, info, log_enabled, warn};
 use semver::Version;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 
+pub mod ansible_galaxy_parser;
+pub mod assembly_info_parser;
+pub mod bukkit_plugin_parser;
+pub mod cabal_parser;
+pub mod cmake_parser;
+pub mod conan_parser;
+pub mod dockerfile_parser;
+pub mod dotnet_project_parser;
+pub mod dune_parser;
+pub mod generic_json_parser;
+pub mod generic_toml_parser;
+pub mod env_file_parser;
+pub mod godot_parser;
+pub mod gradle_properties_parser;
+pub mod julia_project_parser;
+pub mod lerna_parser;
+pub mod maven_parser;
+pub mod nimble_parser;
 pub mod package_json_parser;
+pub mod plist_parser;
+pub mod pubspec_parser;
+pub mod r_description_parser;
+pub mod readme_parser;
+pub mod ros_package_parser;
+pub mod ruby_gem_parser;
+pub mod setup_cfg_parser;
+pub mod snapcraft_parser;
+pub mod swift_parser;
 pub mod tauri_config_parser;
 pub mod toml_parser;
+pub mod unity_parser;
+pub mod vcpkg_parser;
+pub mod wix_parser;
+pub mod zig_zon_parser;
 
 #[derive(Debug, Error)]
 #[non_exhaustive]
 pub enum ParsingError {
+    #[error("No matching files found in directory: {0}")]
+    NoMatchingFilesError(String),
     #[error("No versions found in directory: {0}")]
     NoVersionFoundError(String),
+    #[error("Invalid version '{value}' in {file}: expected major.minor.patch (pass --coerce to accept partial versions)")]
+    InvalidVersionError { file: String, value: String },
+    #[error("Matched files hold different versions:\n  {0}")]
+    InconsistentVersionsError(String),
 }
 
-/// Options controlling how directory walking behaves with respect to ignore files.
+/// Options controlling how files are discovered and rewritten.
 #[derive(Debug, Clone, Default)]
 pub struct WalkOptions {
-    /// When `true`, disables all ignore file processing (.gitignore, .uvignore, etc.).
-    /// When `false` (default), ignore files are respected.
+    /// When `true`, disables all ignore file processing (.gitignore, .git/info/exclude, the
+    /// global excludes file, .uvignore). When `false` (default), ignore files are respected.
     pub no_ignore: bool,
+    /// When `true`, matched files are read and reported but never written.
+    pub dry_run: bool,
+    /// When `true` alongside `dry_run`, each would-be change is printed to stdout as a unified
+    /// diff that `git apply` accepts, with paths relative to the walked directory.
+    pub patch: bool,
+    /// When `true`, partial versions read from files (e.g. `1.2`) are padded to full semver
+    /// instead of being rejected.
+    pub coerce: bool,
+    /// When `true`, matched files without a version field have one inserted via
+    /// `Parser::insert_version` instead of being left untouched.
+    pub insert_missing: bool,
+    /// Identifier of the Swift string constant holding the version. Defaults to `version`.
+    pub swift_identifier: Option<String>,
+    /// Name of the dotenv variable holding the version. Defaults to `VERSION`.
+    pub env_variable: Option<String>,
+    /// When `true`, every version occurrence in a matched file is rewritten instead of only the
+    /// first, and a warning is logged if the existing occurrences disagree.
+    pub all_occurrences: bool,
+    /// When `true`, the fourth (revision) component of four-part versions such as WiX
+    /// `1.2.3.4` is reset to `0` instead of being preserved.
+    pub reset_revision: bool,
+    /// When `true`, each file's original contents are saved to `<file>.bak` before it is
+    /// rewritten, so the run can be undone with `backup::restore_backups`.
+    pub backup: bool,
+    /// When `true`, the TOML parser skips the walk and updates only the `[workspace.package]`
+    /// version of the nearest workspace root `Cargo.toml` at or above the path.
+    pub workspace_root_only: bool,
+    /// When `true`, the TOML parser skips the `Cargo.toml` of every crate listed in the workspace
+    /// root's `members`, so only the root (and any non-member manifests) are versioned.
+    pub exclude_workspace_members: bool,
+    /// When `true`, the TOML parser also updates dependency requirements in every `Cargo.toml`
+    /// that name one of the crates being versioned.
+    pub update_internal_deps: bool,
+    /// When `true`, the Lerna parser also sets every workspace `package.json`, and may set a
+    /// version on independent-mode repos.
+    pub fanout: bool,
+    /// When `true`, `fanout` leaves `package.json` files to the `package-json` type running in
+    /// the same pass, so they are rewritten once.
+    pub fanout_covered: bool,
+    /// When `true`, setting a version on an independent-mode `lerna.json` without `fanout` is an
+    /// error. Otherwise the file is skipped with a warning, so a run that only reaches Lerna
+    /// through `all` isn't failed by it.
+    pub lerna_strict: bool,
+    /// JSON Pointer (RFC 6901) to the version for the generic JSON parser, e.g. `/metadata/version`.
+    /// The generic JSON parser matches nothing unless this is set.
+    pub json_pointer: Option<String>,
+    /// Filename glob for the generic JSON parser, e.g. `manifest.json` or `config/*.json`.
+    pub json_file_glob: Option<String>,
+    /// Dotted key path to the version for the generic TOML parser, e.g. `package.metadata.app.version`.
+    /// Arrays of tables are indexed with `[n]`. The generic TOML parser matches nothing unless this is set.
+    pub toml_key: Option<String>,
+    /// Filename glob for the generic TOML parser, e.g. `Cargo.toml` or `config/*.toml`.
+    pub toml_file_glob: Option<String>,
+    /// `{version}` templates for the README parser, e.g. `cargo add my-crate@{version}`. The
+    /// README parser matches nothing unless at least one is set.
+    pub readme_patterns: Vec<String>,
+    /// When `true`, reading the current version checks that every file a parser matches holds the
+    /// same version, failing with `ParsingError::InconsistentVersionsError` otherwise.
+    pub assert_consistent: bool,
+    /// Files larger than this many bytes are skipped with a warning instead of being read.
+    pub max_file_size: Option<u64>,
+    /// Project configuration from `uv.toml`.
+    pub config: Config,
+    /// Extra filename regexes OR'd into the running parser's `filename_match_regex`. The
+    /// dispatch in `version` fills this per type from `config`.
+    pub extra_files: Vec<String>,
+    /// When `true`, a progress display is drawn on stderr while files are scanned and rewritten.
+    /// Callers should only enable this when stderr is a terminal.
+    pub progress: bool,
+    /// When `true`, parsers that track a separate build number (e.g. `CFBundleVersion`, `versionCode`)
+    /// increment it alongside the version.
+    pub bump_build_number: bool,
+}
+
+/// Splits a leading UTF-8 byte order mark off `contents` so anchored regexes see the real
+/// first line. Returns `(bom, body)`, where `bom` is empty if none was present.
+pub fn split_bom(contents: &str) -> (&str, &str) {
+    match contents.strip_prefix('\u{FEFF}') {
+        Some(body) => (&contents[..contents.len() - body.len()], body),
+        None => ("", contents),
+    }
+}
+
+/// Splits a leading `v` or `V` off a version read from a file, as in `v1.2.3`. Returns
+/// `(prefix, version)`, where `prefix` is empty unless the letter is followed by a digit.
+pub fn split_v_prefix(raw: &str) -> (&str, &str) {
+    match raw.strip_prefix(['v', 'V']) {
+        Some(rest) if rest.starts_with(|c: char| c.is_ascii_digit()) => raw.split_at(1),
+        _ => ("", raw),
+    }
+}
+
+/// Makes `new` end with a line break exactly when `original` does, reusing the original's
+/// `\r\n` or `\n` ending, so rewrites never add or drop a trailing newline.
+pub fn match_final_newline(original: &str, mut new: String) -> String {
+    match (original.ends_with('\n'), new.ends_with('\n')) {
+        (true, false) => new.push_str(if original.ends_with("\r\n") { "\r\n" } else { "\n" }),
+        (false, true) => {
+            new.pop();
+            if new.ends_with('\r') {
+                new.pop();
+            }
+        }
+        _ => {}
+    }
+    new
+}
+
+/// Logs a warning if the version occurrences `regex` finds in `body` don't all agree, since
+/// rewriting them all would hide that they had drifted apart.
+fn warn_on_divergent_versions(file: &Path, regex: &regex::Regex, body: &str) {
+    let mut found: Vec<&str> = regex
+        .captures_iter(body)
+        .filter_map(|c| c.get(2).map(|m| m.as_str()))
+        .collect();
+    found.sort_unstable();
+    found.dedup();
+    if found.len() > 1 {
+        warn!(
+            "'{}' contains differing versions ({}); all will be overwritten",
+            file.display(),
+            found.join(", ")
+        );
+    }
+}
+
+/// The path `--backup` copies `file` to before rewriting it: the same name with `.bak` appended
+pub fn backup_path(file: &Path) -> PathBuf {
+    let mut name = file.as_os_str().to_owned();
+    name.push(".bak");
+    PathBuf::from(name)
+}
+
+/// Files larger than this many bytes are read and rewritten line by line by parsers that support it
+/// (see `Parser::supports_streaming`), instead of being read into memory whole.
+pub const STREAMING_THRESHOLD: u64 = 1024 * 1024;
+
+/// Returns the version captured (group 2) by the first line of `file` that `regex` matches,
+/// reading line by line and stopping there, so memory use doesn't grow with the file.
+pub fn scan_version(file: &Path, regex: &regex::Regex) -> Result<Option<String>> {
+    use std::io::{BufRead, BufReader};
+
+    let mut reader = BufReader::new(std::fs::File::open(file)?);
+    let mut line = Vec::new();
+    while reader.read_until(b'\n', &mut line)? > 0 {
+        let text =
+            std::str::from_utf8(&line).with_context(|| format!("'{}' is not valid UTF-8", file.display()))?;
+        if let Some(version) = regex.captures(text).and_then(|c| c.get(2)) {
+            return Ok(Some(version.as_str().to_string()));
+        }
+        line.clear();
+    }
+    Ok(None)
+}
+
+/// Rewrites the first line of `file` that `regex` matches using `replacement`, copying the
+/// rest through a temporary file that is then renamed over the original, so memory use doesn't
+/// grow with the file. Returns whether a line matched; the file is left untouched when none
+/// does, or under `dry_run`.
+pub fn stream_version(file: &Path, regex: &regex::Regex, replacement: &str, options: &WalkOptions) -> Result<bool> {
+    use std::io::{BufRead, BufReader, BufWriter, Write};
+
+    let mut reader = BufReader::new(std::fs::File::open(file)?);
+    let mut name = std::ffi::OsString::from(".");
+    name.push(file.file_name().unwrap_or_default());
+    name.push(".uv-tmp");
+    let temp = file.with_file_name(name);
+    let mut writer = if options.dry_run {
+        None
+    } else {
+        Some(BufWriter::new(std::fs::File::create(&temp)?))
+    };
+
+    let copied = (|| -> Result<bool> {
+        let mut matched = false;
+        let mut line = Vec::new();
+        while reader.read_until(b'\n', &mut line)? > 0 {
+            if !matched {
+                let text = std::str::from_utf8(&line)
+                    .with_context(|| format!("'{}' is not valid UTF-8", file.display()))?;
+                if regex.is_match(text) {
+                    matched = true;
+                    let replaced = regex.replace(text, replacement).into_owned();
+                    line = replaced.into_bytes();
+                }
+            }
+            match writer.as_mut() {
+                Some(writer) => writer.write_all(&line)?,
+                None if matched => break,
+                None => {}
+            }
+            line.clear();
+        }
+        if let Some(writer) = writer.as_mut() {
+            writer.flush()?;
+        }
+        Ok(matched)
+    })();
+
+    let Some(writer) = writer else {
+        return copied;
+    };
+    drop(writer);
+    match copied {
+        Ok(true) => {
+            if options.backup {
+                std::fs::copy(file, backup_path(file))?;
+            }
+            std::fs::set_permissions(&temp, std::fs::metadata(file)?.permissions())?;
+            std::fs::rename(&temp, file)?;
+            Ok(true)
+        }
+        result => {
+            std::fs::remove_file(&temp)?;
+            result
+        }
+    }
+}
+
+/// Renders a unified diff between the original and rewritten contents of `file`.
+pub fn unified_diff(file: &Path, old: &str, new: &str) -> String {
+    let name = file.display().to_string();
+    similar::TextDiff::from_lines(old, new)
+        .unified_diff()
+        .context_radius(1)
+        .header(&name, &name)
+        .to_string()
+}
+
+/// Renders the change to `file` as a patch for `git apply`, naming it relative to `root` with
+/// the `a/` and `b/` prefixes git expects.
+pub fn git_patch(root: &Path, file: &Path, old: &str, new: &str) -> String {
+    let relative = file.strip_prefix(root).unwrap_or(file);
+    let name = relative.to_string_lossy().replace('\\', "/");
+    similar::TextDiff::from_lines(old, new)
+        .unified_diff()
+        .header(&format!("a/{}", name), &format!("b/{}", name))
+        .to_string()
+}
+
+/// Prints the `git_patch` for a would-be change to stdout when `options` asks for a patch
+pub fn emit_patch(options: &WalkOptions, root: &Path, file: &Path, old: &str, new: &str) {
+    if options.dry_run && options.patch && old != new {
+        print!("{}", git_patch(root, file, old, new));
+    }
+}
+
+/// Creates a progress display for `options`: a bar when `len` is known, otherwise a spinner.
+/// Hidden unless `options.progress` is set, so callers can drive it unconditionally.
+pub fn progress_bar(options: &WalkOptions, len: Option<u64>, template: &str) -> ProgressBar {
+    if !options.progress {
+        return ProgressBar::hidden();
+    }
+    let bar = match len {
+        Some(len) => ProgressBar::new(len),
+        None => ProgressBar::new_spinner(),
+    };
+    if let Ok(style) = ProgressStyle::with_template(template) {
+        bar.set_style(style);
+    }
+    bar
+}
+
+/// Pads a partial version such as `1` or `1.2` out to full semver (`1.0.0`, `1.2.0`),
+/// keeping any prerelease or build suffix. Full versions are parsed as-is.
+pub fn coerce_version(raw: &str) -> Result<Version> {
+    let core_end = raw.find(['-', '+']).unwrap_or(raw.len());
+    let (core, suffix) = raw.split_at(core_end);
+    let parts: Vec<&str> = core.split('.').collect();
+    if parts.is_empty() || parts.len() > 3 || parts.iter().any(|p| p.parse::<u64>().is_err()) {
+        return Ok(Version::parse(raw)?);
+    }
+
+    let mut padded = parts.join(".");
+    for _ in parts.len()..3 {
+        padded.push_str(".0");
+    }
+    Ok(Version::parse(&format!("{padded}{suffix}"))?)
 }
 
 /// Increments a semver version, preserving prerelease labels.
@@ -42,18 +359,18 @@ pub fn increment_semver(version: &Version) -> Result<Version> {
     let parts: Vec<&str> = pre_str.split('.').collect();
 
     // Check if the last identifier is numeric
-    if let Some(last) = parts.last() {
-        if let Ok(n) = last.parse::<u64>() {
-            // Increment the numeric suffix: alpha.0 -> alpha.1
-            let prefix = &parts[..parts.len() - 1];
-            let new_pre = if prefix.is_empty() {
-                format!("{}", n + 1)
-            } else {
-                format!("{}.{}", prefix.join("."), n + 1)
-            };
-            next.pre = semver::Prerelease::new(&new_pre)?;
-            return Ok(next);
-        }
+    if let Some(last) = parts.last()
+        && let Ok(n) = last.parse::<u64>()
+    {
+        // Increment the numeric suffix: alpha.0 -> alpha.1
+        let prefix = &parts[..parts.len() - 1];
+        let new_pre = if prefix.is_empty() {
+            format!("{}", n + 1)
+        } else {
+            format!("{}.{}", prefix.join("."), n + 1)
+        };
+        next.pre = semver::Prerelease::new(&new_pre)?;
+        return Ok(next);
     }
 
     // Non-numeric prerelease (e.g. "alpha"): bump patch, keep label
@@ -68,16 +385,66 @@ pub trait Parser {
         options: &WalkOptions,
     ) -> Result<Vec<PathBuf>> {
         info!("Updating version to {}", version);
-        let files = Self::get_matching_files(path, options)?;
-        let version_regex = Self::version_match_regex()?;
+        let path = path.as_ref();
+        let mut files = Self::get_matching_files(path, options)?;
+        let version_regex = Self::write_match_regex_with(options)?;
+        let progress = progress_bar(options, Some(files.len() as u64), "{bar:30} Updated {pos}/{len} files");
+        let streaming =
+            Self::supports_streaming() && !options.all_occurrences && !options.insert_missing && !options.patch;
         for file in &files {
+            progress.inc(1);
             debug!("Checking file: '{}'", file.display());
+            if streaming && std::fs::metadata(file)?.len() > STREAMING_THRESHOLD {
+                debug!("Streaming '{}' line by line", file.display());
+                let replacement = Self::version_line_format(version)?;
+                if stream_version(file, &version_regex, &replacement, options)? && options.dry_run {
+                    info!("Would update '{}'", file.display());
+                }
+                continue;
+            }
             let contents = std::fs::read_to_string(file)?;
-            let new_contents = version_regex
-                .replace(contents.as_str(), Self::version_line_format(version)?)
-                .to_string();
-            std::fs::write(file, new_contents)?;
+            let (bom, body) = split_bom(&contents);
+            let version_regex = Self::file_match_regex(body, &version_regex)?;
+            let inserted = if options.insert_missing && !version_regex.is_match(body) {
+                Self::insert_version(body, version)?
+            } else {
+                None
+            };
+            let new_body = match (Self::rewrite_version(body, version, options)?, inserted) {
+                (Some(rewritten), _) => rewritten,
+                (None, Some(inserted)) => {
+                    info!("Inserting version {} into '{}'", version, file.display());
+                    inserted
+                }
+                (None, None) if options.all_occurrences || Self::replace_all_matches() => {
+                    warn_on_divergent_versions(file, &version_regex, body);
+                    version_regex
+                        .replace_all(body, Self::version_line_format(version)?)
+                        .to_string()
+                }
+                (None, None) => version_regex
+                    .replace(body, Self::version_line_format(version)?)
+                    .to_string(),
+            };
+            let new_body = Self::update_extra_fields(new_body, options)?;
+            let new_body = match_final_newline(body, new_body);
+            let new_contents = format!("{bom}{new_body}");
+            if log_enabled!(Level::Debug) && new_contents != contents {
+                debug!("Diff for '{}':\n{}", file.display(), unified_diff(file, &contents, &new_contents));
+            }
+            if options.dry_run {
+                info!("Would update '{}'", file.display());
+                emit_patch(options, path, file, &contents, &new_contents);
+            } else {
+                if options.backup && new_contents != contents {
+                    std::fs::write(backup_path(file), &contents)?;
+                }
+                std::fs::write(file, new_contents)?;
+            }
         }
+        progress.finish_and_clear();
+        // Files are walked shallowest first; report them in plain path order
+        files.sort();
         Ok(files)
     }
     fn increment_version(path: impl AsRef<Path>, options: &WalkOptions) -> Result<Vec<PathBuf>> {
@@ -93,59 +460,466 @@ pub trait Parser {
     fn get_current_version(path: impl AsRef<Path>, options: &WalkOptions) -> Result<Version> {
         let path = path.as_ref();
         let files = Self::get_matching_files(path, options)?;
-        let version_regex = Self::version_match_regex()?;
-
+        if files.is_empty() {
+            return Err(ParsingError::NoMatchingFilesError(path.to_string_lossy().to_string()).into());
+        }
+        if options.assert_consistent {
+            return consistent_version::<Self>(path, &files, options);
+        }
         for file in files {
-            let contents = std::fs::read_to_string(file)?;
-            if let Some(captures) = version_regex.captures(contents.as_str())
-                && let Some(version) = captures.get(2)
-            {
-                let version = version.as_str();
-                debug!("Found current version: {}", version);
-                return Ok(Version::parse(version)?);
+            if let Some(version) = Self::read_file_version(&file, options)? {
+                return Ok(version);
             }
         }
 
         Err(ParsingError::NoVersionFoundError(path.to_string_lossy().to_string()).into())
     }
 
-    fn get_matching_files(path: impl AsRef<Path>, options: &WalkOptions) -> Result<Vec<PathBuf>> {
-        debug!("Checking matching files");
-        let mut files: Vec<PathBuf> = vec![];
-        let path = path.as_ref();
-        let filename_regex = Self::filename_match_regex()?;
+    /// Reads the version from a single `file`, or `None` if the file has no version field.
+    fn read_file_version(file: &Path, options: &WalkOptions) -> Result<Option<Version>> {
+        if Self::supports_streaming() && std::fs::metadata(file)?.len() > STREAMING_THRESHOLD {
+            debug!("Scanning '{}' line by line", file.display());
+            let Some(version) = scan_version(file, &Self::version_match_regex_with(options)?)? else {
+                return Ok(None);
+            };
+            return Ok(Some(Self::parse_found_version(file, &version, options)?));
+        }
+        let contents = std::fs::read_to_string(file)?;
+        let (_, body) = split_bom(&contents);
+        let version_regex = Self::file_match_regex(body, &Self::version_match_regex_with(options)?)?;
+        let Some(version) = version_regex.captures(body).and_then(|c| c.get(2)) else {
+            return Ok(None);
+        };
 
-        let mut builder = ignore::WalkBuilder::new(path);
+        Ok(Some(Self::parse_found_version(file, version.as_str(), options)?))
+    }
 
-        if options.no_ignore {
-            // Disable ignore file processing but keep hidden file filtering
-            // so .git/ and other hidden directories are still skipped
-            builder.git_ignore(false);
-            builder.git_global(false);
-            builder.git_exclude(false);
-        } else {
-            builder.add_custom_ignore_filename(".uvignore");
+    /// Parses a raw version read from `file`, padding partial versions when `options.coerce`
+    /// is set and otherwise failing with `ParsingError::InvalidVersionError`.
+    fn parse_found_version(file: &Path, version: &str, options: &WalkOptions) -> Result<Version> {
+        debug!("Found current version: {}", version);
+        let version = if Self::accepts_v_prefix() { split_v_prefix(version).1 } else { version };
+        match Self::parse_version(version) {
+            Ok(parsed) => Ok(parsed),
+            Err(_) if options.coerce && let Ok(coerced) = coerce_version(version) => {
+                debug!("Coerced version '{}' to {}", version, coerced);
+                Ok(coerced)
+            }
+            Err(e) => {
+                debug!("Failed to parse version '{}': {}", version, e);
+                Err(ParsingError::InvalidVersionError {
+                    file: file.display().to_string(),
+                    value: version.to_string(),
+                }
+                .into())
+            }
+        }
+    }
+
+    /// Re-reads every matching file and returns a description of each one whose version
+    /// doesn't equal `version`. Files without a version field are skipped, unless
+    /// `insert_missing` would insert one.
+    fn verify_version(
+        path: impl AsRef<Path>,
+        version: &Version,
+        options: &WalkOptions,
+    ) -> Result<Vec<String>> {
+        let mut expected = version.clone();
+        if !Self::supports_prerelease() {
+            expected.pre = semver::Prerelease::EMPTY;
+            expected.build = semver::BuildMetadata::EMPTY;
         }
 
-        for item in builder.build() {
-            let item = item?;
-            let path = item.path();
-            if filename_regex.is_match(path.to_string_lossy().as_ref()) {
-                files.push(path.to_path_buf());
+        let mut mismatches = Vec::new();
+        for file in Self::get_matching_files(path, options)? {
+            match Self::read_file_version(&file, options) {
+                Ok(Some(found)) if found == expected => {}
+                Ok(Some(found)) => mismatches.push(format!(
+                    "{}: found {}, expected {}",
+                    file.display(),
+                    found,
+                    expected
+                )),
+                Ok(None) if options.insert_missing => {
+                    let contents = std::fs::read_to_string(&file)?;
+                    if Self::insert_version(split_bom(&contents).1, version)?.is_some() {
+                        mismatches.push(format!("{}: no version, expected {}", file.display(), expected));
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => mismatches.push(format!("{}: {}", file.display(), e)),
             }
         }
+        Ok(mismatches)
+    }
 
-        // Sort by path depth (shallowest first) then lexicographically for deterministic ordering
-        files.sort_by(|a, b| {
-            a.components().count().cmp(&b.components().count())
-                .then_with(|| a.cmp(b))
-        });
+    fn get_matching_files(path: impl AsRef<Path>, options: &WalkOptions) -> Result<Vec<PathBuf>> {
+        walk_matching_files::<Self>(path, options)
+    }
 
-        debug!("Found files: {:?}", files);
-        Ok(files)
+    /// Returns `contents` with `version` inserted where this file format expects it, for files
+    /// that match the parser but have no version yet. `None` means insertion isn't supported
+    /// or the file shouldn't carry a version (e.g. a virtual workspace manifest).
+    fn insert_version(_contents: &str, _version: &Version) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    /// Returns `body` with the version set to `version`, for parsers that locate the version
+    /// structurally rather than with `version_match_regex`. `None` uses the regex replacement.
+    fn rewrite_version(_body: &str, _version: &Version, _options: &WalkOptions) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    /// Converts the raw version string captured from a file into a semver `Version`.
+    /// Parsers for ecosystems with non-semver version schemes override this to map them.
+    fn parse_version(raw: &str) -> Result<Version> {
+        Ok(Version::parse(raw)?)
+    }
+
+    /// Applies any parser-specific edits beyond the version itself (e.g. a build number) to
+    /// the rewritten `contents` before they are written.
+    fn update_extra_fields(contents: String, _options: &WalkOptions) -> Result<String> {
+        Ok(contents)
+    }
+
+    /// Whether this file format can store prerelease and build metadata. Formats that can't
+    /// write only `major.minor.patch`.
+    fn supports_prerelease() -> bool {
+        true
+    }
+
+    /// Whether versions read from the file may carry a leading `v` (`v1.2.3`). The prefix is
+    /// ignored when reading, and parsers opting in keep it when writing the new version.
+    fn accepts_v_prefix() -> bool {
+        false
+    }
+
+    /// When `true`, every match of the version regex in a file is rewritten rather than just
+    /// the first, for formats that repeat the version in several fields.
+    fn replace_all_matches() -> bool {
+        false
+    }
+
+    /// Whether the version always sits on one line that `version_match_regex` matches on its
+    /// own, with nothing else in the file to update. Such parsers read and rewrite files larger
+    /// than `STREAMING_THRESHOLD` line by line instead of reading them whole.
+    fn supports_streaming() -> bool {
+        false
+    }
+
+    /// Whether discovery should consider hidden files (dotfiles such as `.env`). Hidden
+    /// directories are skipped either way.
+    fn include_hidden_files() -> bool {
+        false
+    }
+
+    /// The version regex to use for a run with `options`. Parsers whose pattern depends on
+    /// user configuration override this; everyone else gets `version_match_regex`.
+    fn version_match_regex_with(_options: &WalkOptions) -> Result<regex::Regex> {
+        Self::version_match_regex()
+    }
+
+    /// Narrows `regex` for one file's `contents`. Parsers whose version location depends on the
+    /// file itself (e.g. a property referenced by a placeholder) override this.
+    fn file_match_regex(_contents: &str, regex: &regex::Regex) -> Result<regex::Regex> {
+        Ok(regex.clone())
+    }
+
+    /// The regex used to locate the version when writing. Defaults to the read regex; parsers
+    /// override it when a placeholder that can't be read as a version (e.g. `version: git`)
+    /// should still be replaced by an explicit set.
+    fn write_match_regex_with(options: &WalkOptions) -> Result<regex::Regex> {
+        Self::version_match_regex_with(options)
     }
 
     fn version_match_regex() -> Result<regex::Regex>;
     fn filename_match_regex() -> Result<regex::Regex>;
     fn version_line_format(version: &Version) -> Result<String>;
 }
+
+/// The version every one of `files` holds, failing with `ParsingError::InconsistentVersionsError`
+/// listing each file's version when they disagree. Files without a version field are skipped.
+fn consistent_version<P: Parser + ?Sized>(path: &Path, files: &[PathBuf], options: &WalkOptions) -> Result<Version> {
+    let mut versions = Vec::new();
+    for file in files {
+        if let Some(version) = P::read_file_version(file, options)? {
+            versions.push((file, version));
+        }
+    }
+    let Some((_, first)) = versions.first() else {
+        return Err(ParsingError::NoVersionFoundError(path.to_string_lossy().to_string()).into());
+    };
+    if versions.iter().any(|(_, version)| version != first) {
+        let listing: Vec<String> = versions
+            .iter()
+            .map(|(file, version)| format!("{}: {}", file.display(), version))
+            .collect();
+        return Err(ParsingError::InconsistentVersionsError(listing.join("\n  ")).into());
+    }
+    Ok(first.clone())
+}
+
+/// Walks `path` and collects every file matching `P::filename_match_regex`, honoring ignore
+/// files per `options`. This is the default `Parser::get_matching_files` and is exposed so
+/// parsers that override discovery can still build on it.
+pub fn walk_matching_files<P: Parser + ?Sized>(
+    path: impl AsRef<Path>,
+    options: &WalkOptions,
+) -> Result<Vec<PathBuf>> {
+    let filename_regex = P::filename_match_regex()?;
+    if options.extra_files.is_empty() {
+        return walk(path, options, &filename_regex, P::include_hidden_files());
+    }
+    let mut pattern = format!("(?:{})", filename_regex.as_str());
+    for extra in &options.extra_files {
+        pattern.push_str(&format!(r"|(?:[/\\](?:{})$)", extra));
+    }
+    let filename_regex = regex::Regex::new(&pattern)
+        .with_context(|| format!("Invalid extra filename pattern in {}", CONFIG_FILE_NAME))?;
+    walk(path, options, &filename_regex, P::include_hidden_files())
+}
+
+/// Converts a filename glob (`*`, `?`, `**/`) into a regex matching the end of a path, so
+/// `config/*.json` matches `/project/config/app.json` on any platform.
+pub fn glob_regex(glob: &str) -> Result<regex::Regex> {
+    let mut pattern = String::from(r"(?:^|[/\\])");
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if matches!(chars.peek(), Some('/') | Some('\\')) {
+                    chars.next();
+                    pattern.push_str(r"(?:[^/\\]+[/\\])*");
+                } else {
+                    pattern.push_str(".*");
+                }
+            }
+            '*' => pattern.push_str(r"[^/\\]*"),
+            '?' => pattern.push_str(r"[^/\\]"),
+            '/' | '\\' => pattern.push_str(r"[/\\]"),
+            other => pattern.push_str(&regex::escape(&other.to_string())),
+        }
+    }
+    pattern.push('$');
+    regex::Regex::new(&pattern).with_context(|| format!("Invalid file glob '{}'", glob))
+}
+
+/// Walks `path` and collects every file whose path matches `filename_regex`, honoring ignore
+/// files per `options`, sorted shallowest first.
+pub fn walk_files(
+    path: impl AsRef<Path>,
+    options: &WalkOptions,
+    filename_regex: &regex::Regex,
+) -> Result<Vec<PathBuf>> {
+    walk(path, options, filename_regex, false)
+}
+
+/// Implements `walk_files`. With `hidden_files`, dotfiles such as `.env` are considered too;
+/// hidden directories like `.git/` are always skipped.
+fn walk(
+    path: impl AsRef<Path>,
+    options: &WalkOptions,
+    filename_regex: &regex::Regex,
+    hidden_files: bool,
+) -> Result<Vec<PathBuf>> {
+    debug!("Checking matching files");
+    let mut files: Vec<PathBuf> = vec![];
+    let path = path.as_ref();
+
+    let mut builder = ignore::WalkBuilder::new(path);
+
+    if options.no_ignore {
+        // Disable ignore file processing but keep hidden file filtering
+        // so .git/ and other hidden directories are still skipped
+        builder.git_ignore(false);
+        builder.git_global(false);
+        builder.git_exclude(false);
+    } else {
+        // Match git's own view of what's tracked: .gitignore, .git/info/exclude and the
+        // global core.excludesFile all apply, alongside our .uvignore
+        builder.git_ignore(true);
+        builder.git_exclude(true);
+        builder.git_global(true);
+        builder.add_custom_ignore_filename(".uvignore");
+    }
+    if hidden_files {
+        builder.hidden(false);
+        builder.filter_entry(|entry| {
+            entry.depth() == 0
+                || !entry.file_type().is_some_and(|t| t.is_dir())
+                || !entry.file_name().to_string_lossy().starts_with('.')
+        });
+    }
+
+    let progress = progress_bar(options, None, "{spinner} Scanned {pos} files");
+    for item in builder.build() {
+        let item = item?;
+        progress.inc(1);
+        let path = item.path();
+        if !filename_regex.is_match(path.to_string_lossy().as_ref()) {
+            continue;
+        }
+        if let Some(limit) = options.max_file_size {
+            let size = item.metadata()?.len();
+            if size > limit {
+                warn!("Skipping '{}': {} bytes exceeds --max-file-size {}", path.display(), size, limit);
+                continue;
+            }
+        }
+        debug!("Including '{}'", path.display());
+        files.push(path.to_path_buf());
+    }
+    progress.finish_and_clear();
+    if log_enabled!(Level::Debug) {
+        log_ignored_files(path, options, filename_regex, hidden_files, &files)?;
+    }
+
+    // Sort by path depth (shallowest first) then lexicographically for deterministic ordering
+    files.sort_by(|a, b| {
+        a.components().count().cmp(&b.components().count())
+            .then_with(|| a.cmp(b))
+    });
+
+    debug!("Found files: {:?}", files);
+    Ok(files)
+}
+
+/// The ignore sources `walk` applies. `.ignore` files are honored even with `no_ignore`.
+const IGNORE_SOURCES: [&str; 5] =
+    [".uvignore", ".gitignore", ".git/info/exclude", "the global git excludes file", ".ignore"];
+
+/// Turns on the builder setting for one of `IGNORE_SOURCES`
+fn enable_ignore_source(builder: &mut ignore::WalkBuilder, source: &str) {
+    match source {
+        ".uvignore" => builder.add_custom_ignore_filename(".uvignore"),
+        ".gitignore" => builder.git_ignore(true),
+        ".git/info/exclude" => builder.git_exclude(true),
+        ".ignore" => builder.ignore(true),
+        _ => builder.git_global(true),
+    };
+}
+
+/// Files under `path` matching `filename_regex`, walked with every filter off except the ones
+/// `configure` turns on.
+fn unfiltered_files(
+    path: &Path,
+    filename_regex: &regex::Regex,
+    configure: impl FnOnce(&mut ignore::WalkBuilder),
+) -> Result<std::collections::HashSet<PathBuf>> {
+    let mut builder = ignore::WalkBuilder::new(path);
+    builder
+        .standard_filters(false)
+        .parents(true)
+        .filter_entry(|entry| entry.file_name() != ".git");
+    configure(&mut builder);
+    let mut files = std::collections::HashSet::new();
+    for item in builder.build() {
+        let item = item?;
+        if filename_regex.is_match(item.path().to_string_lossy().as_ref()) {
+            files.insert(item.path().to_path_buf());
+        }
+    }
+    Ok(files)
+}
+
+/// Logs, at debug level, each file `walk` left out despite matching `filename_regex`, naming
+/// the hidden-path rule or ignore source responsible. Each source is re-walked on its own, so
+/// this only runs under `--verbose`.
+fn log_ignored_files(
+    path: &Path,
+    options: &WalkOptions,
+    filename_regex: &regex::Regex,
+    hidden_files: bool,
+    included: &[PathBuf],
+) -> Result<()> {
+    let mut skipped: Vec<PathBuf> = unfiltered_files(path, filename_regex, |_| {})?
+        .into_iter()
+        .filter(|file| !included.contains(file))
+        .collect();
+    if skipped.is_empty() {
+        return Ok(());
+    }
+    skipped.sort();
+
+    let mut sources = Vec::new();
+    for name in IGNORE_SOURCES {
+        if name == ".ignore" || !options.no_ignore {
+            let kept = unfiltered_files(path, filename_regex, |builder| enable_ignore_source(builder, name))?;
+            sources.push((name, kept));
+        }
+    }
+    for file in skipped {
+        let relative = file.strip_prefix(path).unwrap_or(&file);
+        let components: Vec<_> = relative.components().collect();
+        let checked = if hidden_files { components.len().saturating_sub(1) } else { components.len() };
+        let hidden = components[..checked]
+            .iter()
+            .any(|c| c.as_os_str().to_string_lossy().starts_with('.'));
+        let reason = if hidden {
+            "a hidden path".to_string()
+        } else if let Some((name, _)) = sources.iter().find(|(_, kept)| !kept.contains(&file)) {
+            format!("a rule in {}", name)
+        } else {
+            "--max-file-size".to_string()
+        };
+        debug!("Excluding '{}': ignored by {}", file.display(), reason);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unified_diff_shows_version_change() {
+        let old = "[package]\nname = \"a\"\nversion = \"1.0.0\"\n";
+        let new = "[package]\nname = \"a\"\nversion = \"2.0.0\"\n";
+        let diff = unified_diff(Path::new("Cargo.toml"), old, new);
+        assert!(diff.contains("--- Cargo.toml"));
+        assert!(diff.contains("+++ Cargo.toml"));
+        assert!(diff.contains("-version = \"1.0.0\""));
+        assert!(diff.contains("+version = \"2.0.0\""));
+        assert!(!diff.contains("[package]"));
+    }
+
+    #[test]
+    fn test_split_v_prefix() {
+        assert_eq!(split_v_prefix("v1.2.3"), ("v", "1.2.3"));
+        assert_eq!(split_v_prefix("V1.2.3"), ("V", "1.2.3"));
+        assert_eq!(split_v_prefix("1.2.3"), ("", "1.2.3"));
+        assert_eq!(split_v_prefix("vnext"), ("", "vnext"));
+    }
+
+    #[test]
+    fn test_coerce_version_pads_partial() {
+        assert_eq!(coerce_version("1").unwrap(), Version::new(1, 0, 0));
+        assert_eq!(coerce_version("1.2").unwrap(), Version::new(1, 2, 0));
+        assert_eq!(coerce_version("1.2.3").unwrap(), Version::new(1, 2, 3));
+        assert_eq!(coerce_version("1.2-beta.1").unwrap(), Version::parse("1.2.0-beta.1").unwrap());
+    }
+
+    #[test]
+    fn test_coerce_version_rejects_garbage() {
+        assert!(coerce_version("abc").is_err());
+        assert!(coerce_version("1.x").is_err());
+        assert!(coerce_version("").is_err());
+    }
+
+    #[test]
+    fn test_match_final_newline() {
+        assert_eq!(match_final_newline("a\n", "b".to_string()), "b\n");
+        assert_eq!(match_final_newline("a", "b\n".to_string()), "b");
+        assert_eq!(match_final_newline("a\r\n", "b".to_string()), "b\r\n");
+        assert_eq!(match_final_newline("a\n", "b\n".to_string()), "b\n");
+        assert_eq!(match_final_newline("a\n\n", "b\n\n".to_string()), "b\n\n");
+        assert_eq!(match_final_newline("a", "b\r\n".to_string()), "b");
+    }
+
+    #[test]
+    fn test_split_bom() {
+        assert_eq!(split_bom("\u{FEFF}{}"), ("\u{FEFF}", "{}"));
+        assert_eq!(split_bom("{}"), ("", "{}"));
+    }
+}
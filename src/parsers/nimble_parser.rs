@@ -0,0 +1,49 @@
+use crate::parsers::Parser;
+use regex::Regex;
+use semver::Version;
+
+/// Parser for Nim `*.nimble` files: the top-level `version = "..."` assignment.
+pub struct NimbleParser;
+
+impl Parser for NimbleParser {
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"(?m)^(version\s*=\s*")(\d+\.\d+\.\d+[^"]*)(")"#)?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\][^/\\]+\.nimble$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}${{3}}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_assignment() {
+        let regex = NimbleParser::version_match_regex().unwrap();
+        let content = "# Package\n\nversion       = \"0.4.1\"\nauthor        = \"someone\"\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "0.4.1");
+    }
+
+    #[test]
+    fn test_version_regex_ignores_requires() {
+        let regex = NimbleParser::version_match_regex().unwrap();
+        let content = "requires \"nim >= 2.0.0\"\nrequires \"jester >= 0.6.0\"\n";
+        assert!(regex.captures(content).is_none());
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = NimbleParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/project/mylib.nimble"));
+        assert!(regex.is_match("\\project\\mylib.nimble"));
+        assert!(!regex.is_match("/project/mylib.nim"));
+        assert!(!regex.is_match("/project/.nimble"));
+    }
+}
@@ -5,6 +5,10 @@ use semver::Version;
 pub struct PackageJsonParser;
 
 impl Parser for PackageJsonParser {
+    fn supports_streaming() -> bool {
+        true
+    }
+
     fn version_match_regex() -> anyhow::Result<Regex> {
         Ok(Regex::new(r##"(?m)^(\s*"version"\s*:\s*")([^"]*)""##)?)
     }
@@ -16,6 +20,26 @@ impl Parser for PackageJsonParser {
     fn version_line_format(version: &Version) -> anyhow::Result<String> {
         Ok(format!("${{1}}{version}\""))
     }
+
+    /// Inserts `"version"` as the first top-level key, matching the indentation of the next key.
+    fn insert_version(contents: &str, version: &Version) -> anyhow::Result<Option<String>> {
+        let Some(open) = contents.find('{') else {
+            return Ok(None);
+        };
+        let rest = &contents[open + 1..];
+        let trimmed = rest.trim_start();
+
+        let updated = if trimmed.starts_with('}') {
+            format!("{}{{\n  \"version\": \"{version}\"\n{}", &contents[..open], trimmed)
+        } else {
+            let indent = Regex::new(r"^\s*?\n([ \t]*)")?
+                .captures(rest)
+                .and_then(|c| c.get(1))
+                .map_or("  ", |m| m.as_str());
+            format!("{}{{\n{indent}\"version\": \"{version}\",{rest}", &contents[..open])
+        };
+        Ok(Some(updated))
+    }
 }
 
 #[cfg(test)]
@@ -86,6 +110,21 @@ mod tests {
         assert!(!regex.is_match("/path/to/my-package.json"));
     }
 
+    #[test]
+    fn test_insert_version_as_first_key() {
+        let version = Version::parse("0.1.0").unwrap();
+        let content = "{\n    \"name\": \"pkg\"\n}\n";
+        let inserted = PackageJsonParser::insert_version(content, &version).unwrap().unwrap();
+        assert_eq!(inserted, "{\n    \"version\": \"0.1.0\",\n    \"name\": \"pkg\"\n}\n");
+    }
+
+    #[test]
+    fn test_insert_version_into_empty_object() {
+        let version = Version::parse("0.1.0").unwrap();
+        let inserted = PackageJsonParser::insert_version("{}\n", &version).unwrap().unwrap();
+        assert_eq!(inserted, "{\n  \"version\": \"0.1.0\"\n}\n");
+    }
+
     #[test]
     fn test_version_line_format() {
         let version = Version::parse("1.2.3").unwrap();
@@ -0,0 +1,153 @@
+use crate::parsers::{Parser, WalkOptions, coerce_version};
+use anyhow::bail;
+use regex::{Captures, Regex};
+use semver::Version;
+
+/// Parser for macOS/iOS `Info.plist` files.
+///
+/// The marketing version lives in the `<string>` following `<key>CFBundleShortVersionString</key>`.
+/// Xcode writes it as `major.minor` by default; such a version reads as `major.minor.0` and stays
+/// two-part when the new patch is `0`.
+/// `CFBundleVersion` is the build number; it is left alone unless `bump_build_number` is set,
+/// in which case its last numeric component is incremented (`41` → `42`, `1.2.3.9` → `1.2.3.10`).
+pub struct PlistParser;
+
+impl PlistParser {
+    fn build_number_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r"(<key>CFBundleVersion</key>\s*<string>)([^<]*)(</string>)")?)
+    }
+
+    fn increment_build_number(build: &str) -> anyhow::Result<String> {
+        let (head, last) = match build.rsplit_once('.') {
+            Some((head, last)) => (format!("{head}."), last),
+            None => (String::new(), build),
+        };
+        match last.trim().parse::<u64>() {
+            Ok(n) => Ok(format!("{head}{}", n + 1)),
+            Err(_) => bail!("Cannot increment non-numeric CFBundleVersion '{}'", build),
+        }
+    }
+}
+
+impl Parser for PlistParser {
+    fn parse_version(raw: &str) -> anyhow::Result<Version> {
+        coerce_version(raw)
+    }
+
+    fn rewrite_version(body: &str, version: &Version, _options: &WalkOptions) -> anyhow::Result<Option<String>> {
+        let regex = Self::version_match_regex()?;
+        let Some(captures) = regex.captures(body) else {
+            return Ok(None);
+        };
+        let old = &captures[2];
+        let core = &old[..old.find(['-', '+']).unwrap_or(old.len())];
+        if core.split('.').count() != 2 || version.patch != 0 {
+            return Ok(None);
+        }
+
+        let mut short = format!("{}.{}", version.major, version.minor);
+        if !version.pre.is_empty() {
+            short.push_str(&format!("-{}", version.pre));
+        }
+        if !version.build.is_empty() {
+            short.push_str(&format!("+{}", version.build));
+        }
+        Ok(Some(
+            regex
+                .replace(body, |c: &Captures| format!("{}{}{}", &c[1], short, &c[3]))
+                .to_string(),
+        ))
+    }
+
+    fn update_extra_fields(contents: String, options: &WalkOptions) -> anyhow::Result<String> {
+        if !options.bump_build_number {
+            return Ok(contents);
+        }
+        let regex = Self::build_number_regex()?;
+        let Some(captures) = regex.captures(&contents) else {
+            return Ok(contents);
+        };
+        let next = Self::increment_build_number(&captures[2])?;
+        Ok(regex
+            .replace(&contents, |c: &Captures| format!("{}{}{}", &c[1], next, &c[3]))
+            .to_string())
+    }
+
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(
+            r"(<key>CFBundleShortVersionString</key>\s*<string>)(\d+\.\d+(?:\.\d+)?[^<]*)(</string>)",
+        )?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\]Info\.plist$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}${{3}}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLIST: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<plist version="1.0">
+<dict>
+	<key>CFBundleVersion</key>
+	<string>41</string>
+	<key>CFBundleShortVersionString</key>
+	<string>1.2.3</string>
+</dict>
+</plist>"#;
+
+    #[test]
+    fn test_version_regex_targets_short_version_string() {
+        let regex = PlistParser::version_match_regex().unwrap();
+        let captures = regex.captures(PLIST).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3");
+    }
+
+    #[test]
+    fn test_two_part_version_keeps_arity() {
+        let body = PLIST.replace("1.2.3", "1.2");
+        assert_eq!(PlistParser::parse_version("1.2").unwrap(), Version::new(1, 2, 0));
+        let rewritten = PlistParser::rewrite_version(&body, &Version::new(1, 3, 0), &WalkOptions::default())
+            .unwrap()
+            .unwrap();
+        assert!(rewritten.contains("<string>1.3</string>"));
+        // A non-zero patch can't be written in two parts, so the default three-part write applies
+        let rewritten = PlistParser::rewrite_version(&body, &Version::new(1, 3, 1), &WalkOptions::default()).unwrap();
+        assert!(rewritten.is_none());
+    }
+
+    #[test]
+    fn test_build_number_untouched_by_default() {
+        let contents = PlistParser::update_extra_fields(PLIST.to_string(), &WalkOptions::default()).unwrap();
+        assert_eq!(contents, PLIST);
+    }
+
+    #[test]
+    fn test_build_number_bumped_when_enabled() {
+        let options = WalkOptions { bump_build_number: true, ..Default::default() };
+        let contents = PlistParser::update_extra_fields(PLIST.to_string(), &options).unwrap();
+        assert!(contents.contains("<key>CFBundleVersion</key>\n\t<string>42</string>"));
+        assert!(contents.contains("<string>1.2.3</string>"));
+    }
+
+    #[test]
+    fn test_increment_build_number() {
+        assert_eq!(PlistParser::increment_build_number("9").unwrap(), "10");
+        assert_eq!(PlistParser::increment_build_number("1.2.3.9").unwrap(), "1.2.3.10");
+        assert!(PlistParser::increment_build_number("$(CURRENT_PROJECT_VERSION)").is_err());
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = PlistParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/MyApp/Info.plist"));
+        assert!(regex.is_match("\\MyApp\\Info.plist"));
+        assert!(!regex.is_match("/MyApp/GoogleService-Info.plist"));
+    }
+}
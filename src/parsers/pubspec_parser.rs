@@ -0,0 +1,86 @@
+use crate::parsers::Parser;
+use crate::version::{BumpKind, bump_version_with_label};
+use anyhow::Context;
+use regex::Regex;
+use semver::{BuildMetadata, Version};
+
+/// Parser for Dart and Flutter `pubspec.yaml` files.
+///
+/// Updates the top-level `version:` scalar, quoted or bare. Flutter apps keep the build number
+/// after a `+` (`1.2.3+45`), which semver reads as build metadata; see `flutter_bump` for the
+/// coordinated semver and build number bump.
+pub struct PubspecParser;
+
+impl PubspecParser {
+    /// The Flutter release bump: always increments the build number after the `+`, and bumps
+    /// the semver part only when `bump` is given. So `1.2.3+45` becomes `1.2.3+46`, or
+    /// `1.2.4+46` with a patch bump. A version without a build number starts at `+1`. `label`
+    /// names a prerelease cycle started by a prerelease bump, as in `bump_version_with_label`.
+    pub fn flutter_bump(current: &Version, bump: Option<BumpKind>, label: &str) -> anyhow::Result<Version> {
+        let build: u64 = if current.build.is_empty() {
+            0
+        } else {
+            current.build.as_str().parse().with_context(|| {
+                format!("Build number '{}' in {} is not a number", current.build, current)
+            })?
+        };
+        let mut next = match bump {
+            Some(kind) => bump_version_with_label(current, kind, label)?,
+            None => current.clone(),
+        };
+        next.build = BuildMetadata::new(&(build + 1).to_string())?;
+        Ok(next)
+    }
+}
+
+impl Parser for PubspecParser {
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"(?m)^(version:[ \t]*['"]?)(\d+\.\d+\.\d+[^'"\s#]*)(['"]?)"#)?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\]pubspec\.yaml$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}${{3}}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_build_number() {
+        let regex = PubspecParser::version_match_regex().unwrap();
+        let content = "name: my_app\nversion: 1.2.3+45\n\nenvironment:\n  sdk: '>=3.0.0 <4.0.0'\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3+45");
+    }
+
+    #[test]
+    fn test_version_regex_ignores_nested_version() {
+        let regex = PubspecParser::version_match_regex().unwrap();
+        let content = "dependencies:\n  http:\n    version: 1.1.0\n";
+        assert!(regex.captures(content).is_none());
+    }
+
+    #[test]
+    fn test_flutter_bump_without_build_number() {
+        let next = PubspecParser::flutter_bump(&Version::parse("1.2.3").unwrap(), None, "rc").unwrap();
+        assert_eq!(next.to_string(), "1.2.3+1");
+    }
+
+    #[test]
+    fn test_flutter_bump_rejects_non_numeric_build() {
+        assert!(PubspecParser::flutter_bump(&Version::parse("1.2.3+abc").unwrap(), None, "rc").is_err());
+    }
+
+    #[test]
+    fn test_flutter_bump_uses_prerelease_label() {
+        let current = Version::parse("1.2.3+45").unwrap();
+        let next = PubspecParser::flutter_bump(&current, Some(BumpKind::Prerelease), "beta").unwrap();
+        assert_eq!(next.to_string(), "1.2.4-beta.0+46");
+    }
+}
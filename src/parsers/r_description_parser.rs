@@ -0,0 +1,106 @@
+use crate::parsers::{Parser, WalkOptions};
+use anyhow::bail;
+use regex::Regex;
+use semver::Version;
+use std::path::{Path, PathBuf};
+
+/// Parser for R package `DESCRIPTION` files.
+///
+/// R versions are two or more integers separated by `.` or `-` (e.g. `1.2`, `1.2.3`, `1.2-3`).
+/// When reading, the components map to `major.minor.patch` in order, so `1.2-3` reads as
+/// `1.2.3` and a missing patch component reads as `0`. Versions are always written back in
+/// dotted `major.minor.patch` form, since R has no notion of semver prerelease labels.
+pub struct RDescriptionParser;
+
+impl Parser for RDescriptionParser {
+    fn supports_prerelease() -> bool {
+        false
+    }
+
+    /// Only the `DESCRIPTION` file at the root of `path` describes the package.
+    fn get_matching_files(path: impl AsRef<Path>, options: &WalkOptions) -> anyhow::Result<Vec<PathBuf>> {
+        let path = path.as_ref();
+        let files = crate::parsers::walk_matching_files::<Self>(path, options)?;
+        Ok(files.into_iter().filter(|file| file.parent() == Some(path)).collect())
+    }
+
+    fn parse_version(raw: &str) -> anyhow::Result<Version> {
+        let parts = raw
+            .split(['.', '-'])
+            .map(str::parse::<u64>)
+            .collect::<Result<Vec<_>, _>>()?;
+        match parts[..] {
+            [major, minor] => Ok(Version::new(major, minor, 0)),
+            [major, minor, patch] => Ok(Version::new(major, minor, patch)),
+            _ => bail!("Unsupported R package version: {}", raw),
+        }
+    }
+
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r"(?m)^(Version:[ \t]*)(\d+[.-]\d+(?:[.-]\d+)*)")?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\]DESCRIPTION$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!(
+            "${{1}}{}.{}.{}",
+            version.major, version.minor, version.patch
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_description() {
+        let regex = RDescriptionParser::version_match_regex().unwrap();
+        let content = "Package: mypkg\nTitle: Things\nVersion: 1.2.3\nDepends: R (>= 3.5.0)\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3");
+    }
+
+    #[test]
+    fn test_version_regex_ignores_depends() {
+        let regex = RDescriptionParser::version_match_regex().unwrap();
+        let content = "Depends: R (>= 3.5.0)\n";
+        assert!(regex.captures(content).is_none());
+    }
+
+    #[test]
+    fn test_parse_version_dotted() {
+        let version = RDescriptionParser::parse_version("1.2.3").unwrap();
+        assert_eq!(version, Version::new(1, 2, 3));
+    }
+
+    #[test]
+    fn test_parse_version_dash_is_patch() {
+        let version = RDescriptionParser::parse_version("1.2-3").unwrap();
+        assert_eq!(version, Version::new(1, 2, 3));
+    }
+
+    #[test]
+    fn test_parse_version_two_components() {
+        let version = RDescriptionParser::parse_version("0.9").unwrap();
+        assert_eq!(version, Version::new(0, 9, 0));
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = RDescriptionParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/path/to/DESCRIPTION"));
+        assert!(!regex.is_match("/path/to/DESCRIPTION.md"));
+        assert!(!regex.is_match("/path/to/description"));
+    }
+
+    #[test]
+    fn test_version_line_format_strips_prerelease() {
+        let version = Version::parse("2.0.1-rc.1").unwrap();
+        let formatted = RDescriptionParser::version_line_format(&version).unwrap();
+        assert_eq!(formatted, "${1}2.0.1");
+    }
+}
@@ -0,0 +1,128 @@
+use crate::parsers::{Parser, WalkOptions, split_bom, walk_matching_files};
+use regex::Regex;
+use semver::Version;
+use std::path::{Path, PathBuf};
+
+/// Full semver, so partial versions such as `1.2` never match a pattern
+const VERSION_PATTERN: &str = r"\d+\.\d+\.\d+(?:-[0-9A-Za-z.-]+)?(?:\+[0-9A-Za-z.-]+)?";
+
+/// Opt-in parser for version strings in `README.md`, such as an install snippet or a badge URL.
+///
+/// READMEs mention plenty of unrelated versions, so nothing is matched unless patterns are
+/// given (`--readme-pattern`). Each pattern is literal text with a single `{version}`
+/// placeholder, e.g. `cargo add update-version@{version}`, and every full-version match of
+/// every pattern is replaced.
+pub struct ReadmeParser;
+
+impl ReadmeParser {
+    /// Compiles a `{version}` template into a regex capturing the text before the version,
+    /// the version, and the text after it
+    pub fn pattern_regex(pattern: &str) -> anyhow::Result<Regex> {
+        let parts: Vec<&str> = pattern.split("{version}").collect();
+        let [prefix, suffix] = parts[..] else {
+            anyhow::bail!("README pattern '{}' must contain exactly one {{version}} placeholder", pattern);
+        };
+        if prefix.is_empty() && suffix.is_empty() {
+            anyhow::bail!("README pattern '{}' needs text around {{version}}", pattern);
+        }
+        Ok(Regex::new(&format!(
+            "({})({})({})",
+            regex::escape(prefix),
+            VERSION_PATTERN,
+            regex::escape(suffix)
+        ))?)
+    }
+
+    fn pattern_regexes(options: &WalkOptions) -> anyhow::Result<Vec<Regex>> {
+        options.readme_patterns.iter().map(|pattern| Self::pattern_regex(pattern)).collect()
+    }
+
+    /// The first version matched by any pattern, in pattern order
+    fn first_match(body: &str, regexes: &[Regex]) -> Option<String> {
+        regexes.iter().find_map(|regex| regex.captures(body).map(|c| c[2].to_string()))
+    }
+}
+
+impl Parser for ReadmeParser {
+    fn get_matching_files(path: impl AsRef<Path>, options: &WalkOptions) -> anyhow::Result<Vec<PathBuf>> {
+        if options.readme_patterns.is_empty() {
+            return Ok(Vec::new());
+        }
+        let regexes = Self::pattern_regexes(options)?;
+        let mut files = Vec::new();
+        for file in walk_matching_files::<Self>(path, options)? {
+            let contents = std::fs::read_to_string(&file)?;
+            if Self::first_match(split_bom(&contents).1, &regexes).is_some() {
+                files.push(file);
+            }
+        }
+        Ok(files)
+    }
+
+    fn read_file_version(file: &Path, options: &WalkOptions) -> anyhow::Result<Option<Version>> {
+        let contents = std::fs::read_to_string(file)?;
+        match Self::first_match(split_bom(&contents).1, &Self::pattern_regexes(options)?) {
+            Some(raw) => Ok(Some(Self::parse_found_version(file, &raw, options)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn rewrite_version(body: &str, version: &Version, options: &WalkOptions) -> anyhow::Result<Option<String>> {
+        let mut updated = body.to_string();
+        for regex in Self::pattern_regexes(options)? {
+            updated = regex.replace_all(&updated, Self::version_line_format(version)?).to_string();
+        }
+        Ok(Some(updated))
+    }
+
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(&format!("()({VERSION_PATTERN})()"))?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\]README\.md$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}${{3}}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pattern_regex_escapes_literal_text() {
+        let regex = ReadmeParser::pattern_regex("update-version = \"{version}\"").unwrap();
+        let captures = regex.captures("[dependencies]\nupdate-version = \"1.2.3\"\n").unwrap();
+        assert_eq!(&captures[2], "1.2.3");
+        assert!(regex.captures("update-version = \"1.2\"").is_none());
+        assert!(regex.captures("update_version = \"1.2.3\"").is_none());
+    }
+
+    #[test]
+    fn test_pattern_regex_badge() {
+        let regex = ReadmeParser::pattern_regex("badge/version-{version}-blue").unwrap();
+        let captures = regex.captures("![v](https://img.shields.io/badge/version-1.2.3-rc.1-blue)").unwrap();
+        assert_eq!(&captures[2], "1.2.3-rc.1");
+    }
+
+    #[test]
+    fn test_pattern_regex_requires_one_placeholder() {
+        assert!(ReadmeParser::pattern_regex("update-version").is_err());
+        assert!(ReadmeParser::pattern_regex("{version}..{version}").is_err());
+        assert!(ReadmeParser::pattern_regex("{version}").is_err());
+    }
+
+    #[test]
+    fn test_rewrite_replaces_every_match() {
+        let options = WalkOptions {
+            readme_patterns: vec!["update-version@{version}".to_string()],
+            ..Default::default()
+        };
+        let body = "cargo add update-version@1.2.3\ncargo install update-version@1.2.3\nserde@1.0.200\n";
+        let updated = ReadmeParser::rewrite_version(body, &Version::new(1, 3, 0), &options).unwrap().unwrap();
+        assert_eq!(updated, "cargo add update-version@1.3.0\ncargo install update-version@1.3.0\nserde@1.0.200\n");
+    }
+}
@@ -0,0 +1,68 @@
+use crate::parsers::Parser;
+use regex::Regex;
+use semver::Version;
+
+/// Parser for ROS `package.xml` manifests.
+///
+/// Updates the package-level `<version>` element, i.e. the one that is a direct child of
+/// `<package>`. Only leaf elements (`<name>`, ...) and comments may precede it, so a `<version>`
+/// nested anywhere else is never matched.
+pub struct RosPackageParser;
+
+impl Parser for RosPackageParser {
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(
+            r#"(?s)(<package\b[^>]*>\s*(?:(?:<[\w.-]+(?:\s[^>]*)?>[^<]*</[\w.-]+>|<!--.*?-->)\s*)*?<version(?:\s[^>]*)?>\s*)(\d+\.\d+\.\d+[^<\s]*)(\s*</version>)"#,
+        )?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\]package\.xml$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}${{3}}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_package_version() {
+        let regex = RosPackageParser::version_match_regex().unwrap();
+        let content = r#"<?xml version="1.0"?>
+<package format="3">
+  <name>my_robot</name>
+  <!-- bumped by CI -->
+  <version>1.2.3</version>
+  <description>A robot</description>
+</package>"#;
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3");
+    }
+
+    #[test]
+    fn test_version_regex_matches_version_with_attribute() {
+        let regex = RosPackageParser::version_match_regex().unwrap();
+        let content = r#"<package format="3"><name>a</name><version compatibility="1.0.0">1.1.0</version></package>"#;
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.1.0");
+    }
+
+    #[test]
+    fn test_version_regex_ignores_nested_version() {
+        let regex = RosPackageParser::version_match_regex().unwrap();
+        let content = r#"<package><name>a</name><export><version>9.9.9</version></export></package>"#;
+        assert!(regex.captures(content).is_none());
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = RosPackageParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/ws/src/my_robot/package.xml"));
+        assert!(regex.is_match("\\ws\\src\\my_robot\\package.xml"));
+        assert!(!regex.is_match("/ws/src/my_robot/package.json"));
+    }
+}
@@ -0,0 +1,93 @@
+use crate::parsers::{Parser, WalkOptions};
+use regex::Regex;
+use semver::Version;
+use std::path::{Path, PathBuf};
+
+pub struct RubyGemParser;
+
+impl RubyGemParser {
+    fn is_version_rb(path: &Path) -> bool {
+        path.file_name().is_some_and(|n| n.eq_ignore_ascii_case("version.rb"))
+    }
+}
+
+impl Parser for RubyGemParser {
+    /// Gemspecs commonly `require` the gem's `version.rb` and reference its constant, so
+    /// `version.rb` files are ordered first and take precedence when reading the current version.
+    fn get_matching_files(path: impl AsRef<Path>, options: &WalkOptions) -> anyhow::Result<Vec<PathBuf>> {
+        let mut files = crate::parsers::walk_matching_files::<Self>(path, options)?;
+        files.sort_by_key(|file| !Self::is_version_rb(file));
+        Ok(files)
+    }
+
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(
+            r##"(?m)^(\s*(?:\w+\.version|VERSION)\s*=\s*["'])(\d+\.\d+\.\d+[^"']*)(["'])"##,
+        )?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"(?i)(?:[/\\][^/\\]+\.gemspec|[/\\]lib[/\\][^/\\]+[/\\]version\.rb)$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}${{3}}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_gemspec() {
+        let regex = RubyGemParser::version_match_regex().unwrap();
+        let content = r#"Gem::Specification.new do |spec|
+  spec.name          = "my_gem"
+  spec.version       = "1.2.3"
+end"#;
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3");
+    }
+
+    #[test]
+    fn test_version_regex_matches_version_rb() {
+        let regex = RubyGemParser::version_match_regex().unwrap();
+        let content = r#"module MyGem
+  VERSION = '0.4.0'.freeze
+end"#;
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "0.4.0");
+        assert_eq!(captures.get(3).unwrap().as_str(), "'");
+    }
+
+    #[test]
+    fn test_version_regex_ignores_constant_reference() {
+        let regex = RubyGemParser::version_match_regex().unwrap();
+        let content = "  spec.version = MyGem::VERSION";
+        assert!(regex.captures(content).is_none());
+    }
+
+    #[test]
+    fn test_filename_regex_matches() {
+        let regex = RubyGemParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/path/to/my_gem.gemspec"));
+        assert!(regex.is_match("/path/to/lib/my_gem/version.rb"));
+        assert!(regex.is_match("\\path\\lib\\my_gem\\version.rb"));
+    }
+
+    #[test]
+    fn test_filename_regex_no_false_positives() {
+        let regex = RubyGemParser::filename_match_regex().unwrap();
+        assert!(!regex.is_match("/path/to/version.rb"));
+        assert!(!regex.is_match("/path/to/my_gem.gemspec.bak"));
+        assert!(!regex.is_match("/path/to/lib/my_gem/other.rb"));
+    }
+
+    #[test]
+    fn test_version_line_format_preserves_quote() {
+        let version = Version::parse("1.2.3").unwrap();
+        let formatted = RubyGemParser::version_line_format(&version).unwrap();
+        assert_eq!(formatted, "${1}1.2.3${3}");
+    }
+}
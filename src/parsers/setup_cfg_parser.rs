@@ -0,0 +1,130 @@
+use crate::parsers::{Parser, WalkOptions};
+use regex::Regex;
+use semver::Version;
+use std::path::{Path, PathBuf};
+
+/// Parser for Python `setup.cfg` files: `version` under `[metadata]`. An `attr: pkg.__version__`
+/// reference updates `__version__` in that module instead.
+pub struct SetupCfgParser;
+
+impl SetupCfgParser {
+    /// Matches the start of the `[metadata]` section up to the `version` value
+    const METADATA_VERSION: &'static str =
+        r#"(?m)(^\[metadata\][ \t]*\r?\n(?:[^\[\n][^\n]*\n|\r?\n)*?version[ \t]*[=:][ \t]*)"#;
+
+    fn attr_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(&format!(r#"{}attr:[ \t]*([\w.]+)"#, Self::METADATA_VERSION))?)
+    }
+
+    fn module_version_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"(?m)^(__version__\s*(?::\s*str\s*)?=\s*["'])(\d+\.\d+\.\d+[^"']*)(["'])"#)?)
+    }
+
+    /// Resolves an `attr:` reference such as `mypkg.__version__` to the module file that
+    /// defines it, relative to the directory holding `setup.cfg`
+    fn resolve_attr(dir: &Path, reference: &str) -> anyhow::Result<PathBuf> {
+        let Some((module, attribute)) = reference.rsplit_once('.') else {
+            anyhow::bail!("Unsupported attr: reference '{}' in {}", reference, dir.display());
+        };
+        if attribute != "__version__" {
+            anyhow::bail!(
+                "attr: reference '{}' in {} must point at a __version__ attribute",
+                reference,
+                dir.display()
+            );
+        }
+
+        let module_path: PathBuf = module.split('.').collect();
+        for base in [dir.to_path_buf(), dir.join("src")] {
+            let candidates = [
+                base.join(&module_path).join("__init__.py"),
+                base.join(&module_path).with_extension("py"),
+            ];
+            if let Some(found) = candidates.into_iter().find(|c| c.is_file()) {
+                return Ok(found);
+            }
+        }
+        anyhow::bail!("Could not find module '{}' referenced by attr: in {}", module, dir.display())
+    }
+}
+
+impl Parser for SetupCfgParser {
+    fn get_matching_files(path: impl AsRef<Path>, options: &WalkOptions) -> anyhow::Result<Vec<PathBuf>> {
+        let attr = Self::attr_regex()?;
+        let mut files = Vec::new();
+        for file in crate::parsers::walk_matching_files::<Self>(path, options)? {
+            let contents = std::fs::read_to_string(&file)?;
+            match attr.captures(&contents) {
+                Some(captures) => {
+                    let dir = file.parent().unwrap_or(Path::new("."));
+                    files.push(Self::resolve_attr(dir, &captures[2])?);
+                }
+                None => files.push(file),
+            }
+        }
+        Ok(files)
+    }
+
+    /// Python modules reached through `attr:` use the `__version__` pattern instead.
+    fn file_match_regex(contents: &str, regex: &Regex) -> anyhow::Result<Regex> {
+        if regex.is_match(contents) || contents.contains("[metadata]") {
+            return Ok(regex.clone());
+        }
+        Self::module_version_regex()
+    }
+
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(&format!(r#"{}(\d+\.\d+\.\d+[^\s;#]*)()"#, Self::METADATA_VERSION))?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\]setup\.cfg$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}${{3}}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_metadata_version() {
+        let regex = SetupCfgParser::version_match_regex().unwrap();
+        let content = "[metadata]\nname = mypkg\nversion = 1.2.3\n\n[options]\npython_requires = >=3.8\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3");
+    }
+
+    #[test]
+    fn test_version_regex_ignores_other_sections() {
+        let regex = SetupCfgParser::version_match_regex().unwrap();
+        let content = "[metadata]\nname = mypkg\n\n[tool:bumpversion]\nversion = 9.9.9\n";
+        assert!(regex.captures(content).is_none());
+    }
+
+    #[test]
+    fn test_attr_regex() {
+        let regex = SetupCfgParser::attr_regex().unwrap();
+        let content = "[metadata]\nname = mypkg\nversion = attr: mypkg.__version__\n";
+        assert_eq!(&regex.captures(content).unwrap()[2], "mypkg.__version__");
+        assert!(SetupCfgParser::version_match_regex().unwrap().captures(content).is_none());
+    }
+
+    #[test]
+    fn test_module_version_regex() {
+        let regex = SetupCfgParser::module_version_regex().unwrap();
+        let content = "\"\"\"My package.\"\"\"\n\n__version__ = \"0.4.0\"\n";
+        assert_eq!(&regex.captures(content).unwrap()[2], "0.4.0");
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = SetupCfgParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/project/setup.cfg"));
+        assert!(regex.is_match("\\project\\setup.cfg"));
+        assert!(!regex.is_match("/project/setup.py"));
+    }
+}
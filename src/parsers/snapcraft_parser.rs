@@ -0,0 +1,71 @@
+use crate::parsers::{Parser, WalkOptions};
+use regex::Regex;
+use semver::Version;
+
+/// Parser for Snap package `snapcraft.yaml` files, at the project root or under `snap/`.
+///
+/// The top-level `version:` scalar may be quoted or bare. Snaps often use a keyword such as
+/// `version: git` to derive the version at build time; such values are skipped when reading
+/// the current version, but an explicitly set version still replaces them.
+pub struct SnapcraftParser;
+
+impl Parser for SnapcraftParser {
+    fn write_match_regex_with(_options: &WalkOptions) -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"(?m)^(version:[ \t]*['"]?)([^'"\s#]+)(['"]?)"#)?)
+    }
+
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"(?m)^(version:[ \t]*['"]?)(\d+\.\d+\.\d+[^'"\s#]*)(['"]?)"#)?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\]snapcraft\.yaml$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}${{3}}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_quoted() {
+        let regex = SnapcraftParser::version_match_regex().unwrap();
+        let content = "name: my-snap\nversion: '1.2.3'\nsummary: A snap\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3");
+    }
+
+    #[test]
+    fn test_version_regex_matches_unquoted() {
+        let regex = SnapcraftParser::version_match_regex().unwrap();
+        let content = "name: my-snap\nversion: 1.2.3-rc.1\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3-rc.1");
+    }
+
+    #[test]
+    fn test_version_regex_skips_keyword() {
+        let regex = SnapcraftParser::version_match_regex().unwrap();
+        assert!(regex.captures("name: my-snap\nversion: git\n").is_none());
+    }
+
+    #[test]
+    fn test_version_regex_ignores_nested_version() {
+        let regex = SnapcraftParser::version_match_regex().unwrap();
+        let content = "parts:\n  app:\n    version: 9.9.9\n";
+        assert!(regex.captures(content).is_none());
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = SnapcraftParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/project/snapcraft.yaml"));
+        assert!(regex.is_match("/project/snap/snapcraft.yaml"));
+        assert!(regex.is_match("\\project\\snap\\snapcraft.yaml"));
+        assert!(!regex.is_match("/project/snapcraft.yml.bak"));
+    }
+}
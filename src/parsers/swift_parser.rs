@@ -0,0 +1,99 @@
+use crate::parsers::{Parser, WalkOptions};
+use regex::Regex;
+use semver::Version;
+use std::path::{Path, PathBuf};
+
+/// Parser for a Swift string constant holding the app version, e.g. in `Version.swift`:
+/// `let version = "1.2.3"` or `public static let version = "1.2.3"`.
+///
+/// SwiftPM takes package versions from git tags, so this targets a source constant instead.
+/// Any `*.swift` file is considered, but only files that actually declare the constant are
+/// matched, so arbitrary string literals are never rewritten.
+pub struct SwiftParser;
+
+impl SwiftParser {
+    pub const DEFAULT_IDENTIFIER: &'static str = "version";
+
+    fn constant_regex(identifier: &str) -> anyhow::Result<Regex> {
+        Ok(Regex::new(&format!(
+            r#"(?m)^(\s*(?:(?:public|internal|fileprivate|private)\s+)?(?:static\s+)?let\s+{}\s*(?::\s*String\s*)?=\s*")(\d+\.\d+\.\d+[^"]*)""#,
+            regex::escape(identifier)
+        ))?)
+    }
+}
+
+impl Parser for SwiftParser {
+    fn get_matching_files(path: impl AsRef<Path>, options: &WalkOptions) -> anyhow::Result<Vec<PathBuf>> {
+        let version_regex = Self::version_match_regex_with(options)?;
+        let mut files = Vec::new();
+        for file in crate::parsers::walk_matching_files::<Self>(path, options)? {
+            if version_regex.is_match(&std::fs::read_to_string(&file)?) {
+                files.push(file);
+            }
+        }
+        Ok(files)
+    }
+
+    fn version_match_regex_with(options: &WalkOptions) -> anyhow::Result<Regex> {
+        Self::constant_regex(options.swift_identifier.as_deref().unwrap_or(Self::DEFAULT_IDENTIFIER))
+    }
+
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Self::constant_regex(Self::DEFAULT_IDENTIFIER)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"(?i)[/\\][^/\\]+\.swift$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}\""))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_let() {
+        let regex = SwiftParser::version_match_regex().unwrap();
+        let content = "let version = \"1.2.3\"\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3");
+    }
+
+    #[test]
+    fn test_version_regex_matches_static_let() {
+        let regex = SwiftParser::version_match_regex().unwrap();
+        let content = r#"enum AppVersion {
+    public static let version: String = "2.0.0-beta.1"
+}"#;
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "2.0.0-beta.1");
+    }
+
+    #[test]
+    fn test_version_regex_ignores_other_identifiers() {
+        let regex = SwiftParser::version_match_regex().unwrap();
+        let content = "let apiVersion = \"1.0.0\"\nlet versionString = \"1.0.0\"\n";
+        assert!(regex.captures(content).is_none());
+    }
+
+    #[test]
+    fn test_custom_identifier() {
+        let options = WalkOptions { swift_identifier: Some("appVersion".to_string()), ..Default::default() };
+        let regex = SwiftParser::version_match_regex_with(&options).unwrap();
+        let content = "static let appVersion = \"3.1.4\"\nlet version = \"9.9.9\"\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "3.1.4");
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = SwiftParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/Sources/App/Version.swift"));
+        assert!(regex.is_match("\\Sources\\App\\AppVersion.swift"));
+        assert!(!regex.is_match("/Sources/App/Version.swift.bak"));
+    }
+}
@@ -1,11 +1,286 @@
-use crate::parsers::Parser;
+use crate::parsers::{Parser, ParsingError, WalkOptions, backup_path, emit_patch, glob_regex, walk_matching_files};
+use log::{debug, info, warn};
 use regex::Regex;
 use semver::Version;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use toml_edit::{DocumentMut, Item, TableLike};
+
+/// Dependency tables that may name another crate of the workspace
+const DEPENDENCY_TABLES: [&str; 3] = ["dependencies", "dev-dependencies", "build-dependencies"];
 
 pub struct TomlParser;
+
+impl TomlParser {
+    /// Finds the nearest `Cargo.toml` at or above `path` that declares a `[workspace]` table
+    fn find_workspace_root(path: &Path) -> anyhow::Result<Option<PathBuf>> {
+        let header = Regex::new(r"(?m)^\[workspace\][ \t]*$")?;
+        let start = path.canonicalize()?;
+        for dir in start.ancestors() {
+            let manifest = dir.join("Cargo.toml");
+            if manifest.is_file() && header.is_match(&std::fs::read_to_string(&manifest)?) {
+                return Ok(Some(manifest));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Removes the manifests of the nearest workspace's `members` (globs included) from `files`,
+    /// keeping the workspace root itself
+    fn without_workspace_members(path: &Path, files: Vec<PathBuf>) -> anyhow::Result<Vec<PathBuf>> {
+        let Some(root_manifest) = Self::find_workspace_root(path)? else {
+            return Ok(files);
+        };
+        let root = root_manifest.parent().unwrap_or(Path::new(""));
+        let document = std::fs::read_to_string(&root_manifest)?.parse::<DocumentMut>()?;
+        let members = document
+            .get("workspace")
+            .and_then(|workspace| workspace.get("members"))
+            .and_then(Item::as_array)
+            .map(|members| members.iter().filter_map(|m| m.as_str()).map(glob_regex).collect())
+            .transpose()?
+            .unwrap_or_else(Vec::new);
+
+        Ok(files
+            .into_iter()
+            .filter(|file| {
+                let member = file
+                    .parent()
+                    .and_then(|dir| dir.canonicalize().ok())
+                    .and_then(|dir| Some(dir.strip_prefix(root).ok()?.to_string_lossy().into_owned()))
+                    .is_some_and(|relative| {
+                        !relative.is_empty()
+                            && members.iter().any(|m: &Regex| m.find(&relative).is_some_and(|found| found.start() == 0))
+                    });
+                if member {
+                    debug!("Skipping workspace member '{}'", file.display());
+                }
+                !member
+            })
+            .collect())
+    }
+
+    /// Updates the manifests like `update_version`, then with `update_internal_deps` also
+    /// points the dependency requirements on the crates just versioned at `version`
+    pub fn update_workspace(
+        path: impl AsRef<Path>,
+        version: &Version,
+        options: &WalkOptions,
+    ) -> anyhow::Result<Vec<PathBuf>> {
+        let path = path.as_ref();
+        let mut files = Self::update_version(path, version, options)?;
+        if options.update_internal_deps {
+            for file in Self::update_internal_deps(path, version, options)? {
+                if !files.contains(&file) {
+                    files.push(file);
+                }
+            }
+        }
+        Ok(files)
+    }
+
+    /// Whether `update_internal_deps` would change any manifest under `path` for `version`
+    pub fn has_stale_internal_deps(path: &Path, version: &Version, options: &WalkOptions) -> anyhow::Result<bool> {
+        Ok(!Self::internal_dep_changes(path, version, options)?.is_empty())
+    }
+
+    /// Rewrites dependency entries naming a versioned crate of the tree at `path` so their
+    /// requirement matches `version`, keeping any operator (`^`, `~`, `=`). Third-party
+    /// dependencies and path-only entries are left alone. Returns the manifests changed.
+    fn update_internal_deps(path: &Path, version: &Version, options: &WalkOptions) -> anyhow::Result<Vec<PathBuf>> {
+        let mut changed = Vec::new();
+        for (file, contents, new_contents) in Self::internal_dep_changes(path, version, options)? {
+            if options.dry_run {
+                info!("Would update internal dependencies in '{}'", file.display());
+                emit_patch(options, path, &file, &contents, &new_contents);
+            } else {
+                info!("Updating internal dependencies in '{}'", file.display());
+                if options.backup {
+                    std::fs::write(backup_path(&file), &contents)?;
+                }
+                std::fs::write(&file, new_contents)?;
+            }
+            changed.push(file);
+        }
+        Ok(changed)
+    }
+
+    /// The manifests `update_internal_deps` would change, with their old and new contents
+    fn internal_dep_changes(
+        path: &Path,
+        version: &Version,
+        options: &WalkOptions,
+    ) -> anyhow::Result<Vec<(PathBuf, String, String)>> {
+        // Root-only mode versions the members inheriting `[workspace.package]`, so read them all
+        let root = if options.workspace_root_only {
+            Self::find_workspace_root(path)?
+                .and_then(|manifest| manifest.parent().map(Path::to_path_buf))
+                .unwrap_or_else(|| path.to_path_buf())
+        } else {
+            path.to_path_buf()
+        };
+        let walk_options = WalkOptions { workspace_root_only: false, ..options.clone() };
+        let mut manifests = Vec::new();
+        for file in walk_matching_files::<Self>(&root, &walk_options)? {
+            let contents = std::fs::read_to_string(&file)?;
+            let document = contents.parse::<DocumentMut>()?;
+            manifests.push((file, contents, document));
+        }
+
+        // Only the crates that were bumped: `exclude_workspace_members` keeps members' versions
+        let bumped: HashSet<PathBuf> = if options.workspace_root_only {
+            manifests.iter().map(|(file, _, _)| file.clone()).collect()
+        } else {
+            Self::get_matching_files(path, options)?.into_iter().collect()
+        };
+        let crates: HashSet<String> = manifests
+            .iter()
+            .filter(|(file, _, _)| bumped.contains(file))
+            .filter_map(|(_, _, document)| Self::versioned_crate(document, options.workspace_root_only))
+            .collect();
+
+        let mut changed = Vec::new();
+        for (file, contents, mut document) in manifests {
+            if !Self::update_dependency_tables(document.as_table_mut(), &crates, version, &file) {
+                continue;
+            }
+            let new_contents = document.to_string();
+            if new_contents != contents {
+                changed.push((file, contents, new_contents));
+            }
+        }
+        Ok(changed)
+    }
+
+    /// The package name of a manifest whose version is being set: a literal `version`, or
+    /// `version.workspace = true` when only the workspace root is updated
+    fn versioned_crate(document: &DocumentMut, workspace_root_only: bool) -> Option<String> {
+        let package = document.get("package")?;
+        let version = package.get("version")?;
+        let inherited = version
+            .as_table_like()
+            .and_then(|table| table.get("workspace"))
+            .and_then(Item::as_bool)
+            .unwrap_or(false);
+        let versioned = if workspace_root_only { inherited } else { version.is_str() };
+        versioned.then(|| package.get("name")?.as_str().map(str::to_string))?
+    }
+
+    /// Updates the internal entries of every dependency table in `table`: the top-level kinds,
+    /// `[workspace.dependencies]` and `[target.'cfg'.*]`. Returns whether anything matched.
+    fn update_dependency_tables(
+        table: &mut dyn TableLike,
+        crates: &HashSet<String>,
+        version: &Version,
+        file: &Path,
+    ) -> bool {
+        let mut matched = false;
+        for kind in DEPENDENCY_TABLES {
+            if let Some(dependencies) = table.get_mut(kind).and_then(Item::as_table_like_mut) {
+                matched |= Self::update_dependencies(dependencies, crates, version, file);
+            }
+        }
+        if let Some(dependencies) = table
+            .get_mut("workspace")
+            .and_then(Item::as_table_like_mut)
+            .and_then(|workspace| workspace.get_mut("dependencies"))
+            .and_then(Item::as_table_like_mut)
+        {
+            matched |= Self::update_dependencies(dependencies, crates, version, file);
+        }
+        if let Some(targets) = table.get_mut("target").and_then(Item::as_table_like_mut) {
+            for (_, target) in targets.iter_mut() {
+                if let Some(target) = target.as_table_like_mut() {
+                    matched |= Self::update_dependency_tables(target, crates, version, file);
+                }
+            }
+        }
+        matched
+    }
+
+    fn update_dependencies(
+        dependencies: &mut dyn TableLike,
+        crates: &HashSet<String>,
+        version: &Version,
+        file: &Path,
+    ) -> bool {
+        let mut matched = false;
+        for (key, dependency) in dependencies.iter_mut() {
+            let key = key.get().to_string();
+            let requirement = if dependency.is_str() {
+                crates.contains(&key).then(|| dependency.as_value_mut()).flatten()
+            } else if let Some(entry) = dependency.as_table_like_mut() {
+                // A renamed dependency names the crate in `package`
+                let name = entry.get("package").and_then(Item::as_str).unwrap_or(&key);
+                if !crates.contains(name) {
+                    continue;
+                }
+                entry.get_mut("version").and_then(Item::as_value_mut)
+            } else {
+                None
+            };
+            let Some(requirement) = requirement else { continue };
+            let Some(current) = requirement.as_str() else { continue };
+            match Self::bump_requirement(current, version) {
+                Some(next) => {
+                    let decor = requirement.decor().clone();
+                    *requirement = next.into();
+                    *requirement.decor_mut() = decor;
+                    matched = true;
+                }
+                None => warn!(
+                    "Leaving the '{}' requirement '{}' in '{}' alone: it isn't a single version",
+                    key,
+                    current,
+                    file.display()
+                ),
+            }
+        }
+        matched
+    }
+
+    /// `^1.2.3` → `^{version}`, keeping a leading operator. Ranges and wildcards give `None`.
+    fn bump_requirement(requirement: &str, version: &Version) -> Option<String> {
+        let requirement = requirement.trim();
+        if requirement.contains([',', '*', '<', '>']) {
+            return None;
+        }
+        let operator = requirement.trim_end_matches(|c: char| c.is_ascii_alphanumeric() || ".-+".contains(c));
+        Some(format!("{}{}", operator, version))
+    }
+}
+
 impl Parser for TomlParser {
+    /// With `workspace_root_only`, skips the walk entirely and returns just the nearest
+    /// workspace root manifest. With `exclude_workspace_members`, drops the manifests of the
+    /// crates listed in the workspace root's `members`.
+    fn get_matching_files(path: impl AsRef<Path>, options: &WalkOptions) -> anyhow::Result<Vec<PathBuf>> {
+        let path = path.as_ref();
+        if !options.workspace_root_only {
+            let files = walk_matching_files::<Self>(path, options)?;
+            if !options.exclude_workspace_members {
+                return Ok(files);
+            }
+            return Self::without_workspace_members(path, files);
+        }
+        match Self::find_workspace_root(path)? {
+            Some(root) => Ok(vec![root]),
+            None => Err(ParsingError::NoMatchingFilesError(path.to_string_lossy().to_string()).into()),
+        }
+    }
+
+    /// With `workspace_root_only`, targets the `version` key inside `[workspace.package]`.
+    fn version_match_regex_with(options: &WalkOptions) -> anyhow::Result<Regex> {
+        if !options.workspace_root_only {
+            return Self::version_match_regex();
+        }
+        Ok(Regex::new(
+            r##"(?m)(^\[workspace\.package\][ \t]*\n(?:[^\[\n][^\n]*\n|\n)*?version\s*=\s*["'])(\d+\.\d+\.\d+[^"']*)(["'])"##,
+        )?)
+    }
+
     fn version_match_regex() -> anyhow::Result<Regex> {
-        Ok(Regex::new(r##"(?m)^(version\s*=\s*")(\d+\.\d+\.\d+[^"]*)""##)?)
+        Ok(Regex::new(r##"(?m)^(version\s*=\s*["'])(\d+\.\d+\.\d+[^"']*)(["'])"##)?)
     }
 
     fn filename_match_regex() -> anyhow::Result<Regex> {
@@ -13,7 +288,32 @@ impl Parser for TomlParser {
     }
 
     fn version_line_format(version: &Version) -> anyhow::Result<String> {
-        Ok(format!("${{1}}{version}\""))
+        // Group 3 is the closing quote, so single-quoted values stay single-quoted
+        Ok(format!("${{1}}{version}${{3}}"))
+    }
+
+    /// Inserts `version` into the `[package]` table, right after `name` when present.
+    /// Manifests without a `[package]` table or using `version.workspace` are left alone.
+    fn insert_version(contents: &str, version: &Version) -> anyhow::Result<Option<String>> {
+        if Regex::new(r"(?m)^version\s*[.=]")?.is_match(contents) {
+            return Ok(None);
+        }
+        let Some(header) = Regex::new(r"(?m)^\[package\][ \t]*$")?.find(contents) else {
+            return Ok(None);
+        };
+
+        let section_end = contents[header.end()..]
+            .find("\n[")
+            .map_or(contents.len(), |i| header.end() + i);
+        let section = &contents[header.end()..section_end];
+        let insert_at = match Regex::new(r"(?m)^name\s*=.*$")?.find(section) {
+            Some(name) => header.end() + name.end(),
+            None => header.end(),
+        };
+
+        let mut updated = contents.to_string();
+        updated.insert_str(insert_at, &format!("\nversion = \"{version}\""));
+        Ok(Some(updated))
     }
 }
 
@@ -37,6 +337,15 @@ mod tests {
         assert_eq!(captures.get(2).unwrap().as_str(), "0.1.0");
     }
 
+    #[test]
+    fn test_version_regex_matches_single_quotes() {
+        let regex = TomlParser::version_match_regex().unwrap();
+        let content = r#"version = '1.2.3'"#;
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3");
+        assert_eq!(captures.get(3).unwrap().as_str(), "'");
+    }
+
     #[test]
     fn test_version_regex_matches_in_file() {
         let regex = TomlParser::version_match_regex().unwrap();
@@ -87,17 +396,49 @@ serde = { version = "1.0" }
         assert!(!regex.is_match("/path/to/NotCargo.toml"));
     }
 
+    #[test]
+    fn test_insert_version_after_name() {
+        let version = Version::parse("0.1.0").unwrap();
+        let content = "[package]\nname = \"a\"\nedition = \"2021\"\n\n[dependencies]\n";
+        let inserted = TomlParser::insert_version(content, &version).unwrap().unwrap();
+        assert_eq!(
+            inserted,
+            "[package]\nname = \"a\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\n"
+        );
+    }
+
+    #[test]
+    fn test_insert_version_skips_workspace_inheritance() {
+        let version = Version::parse("0.1.0").unwrap();
+        let content = "[package]\nname = \"a\"\nversion.workspace = true\n";
+        assert!(TomlParser::insert_version(content, &version).unwrap().is_none());
+
+        let content = "[workspace]\nmembers = [\"a\"]\n";
+        assert!(TomlParser::insert_version(content, &version).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_bump_requirement_keeps_operator() {
+        let version = Version::parse("1.3.0").unwrap();
+        assert_eq!(TomlParser::bump_requirement("1.2.3", &version).as_deref(), Some("1.3.0"));
+        assert_eq!(TomlParser::bump_requirement("^1.2", &version).as_deref(), Some("^1.3.0"));
+        assert_eq!(TomlParser::bump_requirement("= 1.2.3", &version).as_deref(), Some("= 1.3.0"));
+        assert_eq!(TomlParser::bump_requirement("~1.2.3-rc.1", &version).as_deref(), Some("~1.3.0"));
+        assert!(TomlParser::bump_requirement(">=1.0, <2.0", &version).is_none());
+        assert!(TomlParser::bump_requirement("1.*", &version).is_none());
+    }
+
     #[test]
     fn test_version_line_format() {
         let version = Version::parse("1.2.3").unwrap();
         let formatted = TomlParser::version_line_format(&version).unwrap();
-        assert_eq!(formatted, "${1}1.2.3\"");
+        assert_eq!(formatted, "${1}1.2.3${3}");
     }
 
     #[test]
     fn test_version_line_format_with_prerelease() {
         let version = Version::parse("1.0.0-alpha.1").unwrap();
         let formatted = TomlParser::version_line_format(&version).unwrap();
-        assert_eq!(formatted, "${1}1.0.0-alpha.1\"");
+        assert_eq!(formatted, "${1}1.0.0-alpha.1${3}");
     }
 }
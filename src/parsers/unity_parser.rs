@@ -0,0 +1,88 @@
+use crate::parsers::{Parser, WalkOptions};
+use anyhow::Context;
+use regex::{Captures, Regex};
+use semver::Version;
+
+/// Parser for Unity `ProjectSettings/ProjectSettings.asset` files.
+///
+/// Unity's YAML dialect is targeted key by key rather than parsed: the version is the
+/// `bundleVersion:` value. The platform build numbers, `AndroidBundleVersionCode` and the
+/// `iPhone` entry of `buildNumber`, are left alone unless `bump_build_number` is set, in which
+/// case each is incremented by one.
+pub struct UnityParser;
+
+impl UnityParser {
+    fn android_version_code_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r"(?m)^([ \t]*AndroidBundleVersionCode:[ \t]*)(\d+)")?)
+    }
+
+    fn ios_build_number_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r"(?m)^([ \t]*buildNumber:[ \t]*\r?\n(?:[ \t]+\w+:[^\n]*\n)*?[ \t]+iPhone:[ \t]*)(\d+)")?)
+    }
+
+    fn increment(contents: &str, regex: &Regex, field: &str) -> anyhow::Result<String> {
+        let Some(captures) = regex.captures(contents) else {
+            return Ok(contents.to_string());
+        };
+        let number: u64 = captures[2]
+            .parse()
+            .with_context(|| format!("Invalid {} '{}'", field, &captures[2]))?;
+        Ok(regex
+            .replace(contents, |c: &Captures| format!("{}{}", &c[1], number + 1))
+            .to_string())
+    }
+}
+
+impl Parser for UnityParser {
+    fn update_extra_fields(contents: String, options: &WalkOptions) -> anyhow::Result<String> {
+        if !options.bump_build_number {
+            return Ok(contents);
+        }
+        let contents = Self::increment(&contents, &Self::android_version_code_regex()?, "AndroidBundleVersionCode")?;
+        Self::increment(&contents, &Self::ios_build_number_regex()?, "iPhone buildNumber")
+    }
+
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r"(?m)^([ \t]*bundleVersion:[ \t]*)(\d+\.\d+\.\d+[^\s]*)")?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\]ProjectSettings\.asset$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SETTINGS: &str = "%YAML 1.1\n%TAG !u! tag:unity3d.com,2011:\n--- !u!129 &1\nPlayerSettings:\n  productName: My Game\n  bundleVersion: 1.2.3\n  AndroidBundleVersionCode: 7\n  buildNumber:\n    Standalone: 0\n    iPhone: 12\n    tvOS: 0\n";
+
+    #[test]
+    fn test_version_regex_matches_bundle_version() {
+        let regex = UnityParser::version_match_regex().unwrap();
+        let captures = regex.captures(SETTINGS).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3");
+    }
+
+    #[test]
+    fn test_build_numbers_untouched_by_default() {
+        let updated = UnityParser::update_extra_fields(SETTINGS.to_string(), &WalkOptions::default()).unwrap();
+        assert_eq!(updated, SETTINGS);
+    }
+
+    #[test]
+    fn test_build_numbers_bumped_when_enabled() {
+        let options = WalkOptions { bump_build_number: true, ..Default::default() };
+        let updated = UnityParser::update_extra_fields(SETTINGS.to_string(), &options).unwrap();
+        assert_eq!(
+            updated,
+            SETTINGS
+                .replace("AndroidBundleVersionCode: 7", "AndroidBundleVersionCode: 8")
+                .replace("iPhone: 12", "iPhone: 13")
+        );
+    }
+}
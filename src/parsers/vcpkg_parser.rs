@@ -0,0 +1,70 @@
+use crate::parsers::Parser;
+use regex::Regex;
+use semver::Version;
+
+/// Parser for vcpkg `vcpkg.json` manifests: the top-level `version-semver`, `version` or
+/// `version-string`.
+pub struct VcpkgParser;
+
+impl Parser for VcpkgParser {
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(
+            r#"(?m)^([ \t]{0,4}"version(?:-semver|-string)?"\s*:\s*")(\d+\.\d+\.\d+[^"]*)""#,
+        )?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\]vcpkg\.json$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}\""))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_version_semver() {
+        let regex = VcpkgParser::version_match_regex().unwrap();
+        let content = "{\n  \"name\": \"app\",\n  \"version-semver\": \"1.2.3-rc.1\"\n}";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3-rc.1");
+    }
+
+    #[test]
+    fn test_version_regex_matches_version() {
+        let regex = VcpkgParser::version_match_regex().unwrap();
+        let content = "{\n    \"name\": \"app\",\n    \"version\": \"1.2.3\"\n}";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3");
+    }
+
+    #[test]
+    fn test_version_regex_ignores_nested_versions() {
+        let regex = VcpkgParser::version_match_regex().unwrap();
+        let content = r#"{
+  "name": "app",
+  "dependencies": [
+    { "name": "fmt", "version>=": "10.0.0" }
+  ],
+  "overrides": [
+    {
+      "name": "zlib",
+      "version": "1.3.1"
+    }
+  ]
+}"#;
+        assert!(regex.captures(content).is_none());
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = VcpkgParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/project/vcpkg.json"));
+        assert!(regex.is_match("\\project\\vcpkg.json"));
+        assert!(!regex.is_match("/project/vcpkg-configuration.json"));
+    }
+}
@@ -0,0 +1,91 @@
+use crate::parsers::{Parser, WalkOptions};
+use regex::{Captures, Regex};
+use semver::Version;
+
+/// Parser for WiX `*.wxs` sources: the `<Product>` or `<Package>` `Version`, keeping the fourth
+/// component unless `reset_revision` is set.
+pub struct WixParser;
+
+impl Parser for WixParser {
+    fn supports_prerelease() -> bool {
+        false
+    }
+
+    fn update_extra_fields(contents: String, options: &WalkOptions) -> anyhow::Result<String> {
+        if !options.reset_revision {
+            return Ok(contents);
+        }
+        let regex = Self::version_match_regex()?;
+        Ok(regex
+            .replace(&contents, |c: &Captures| {
+                let revision = if c[3].starts_with('.') { ".0\"" } else { "\"" };
+                format!("{}{}{}", &c[1], &c[2], revision)
+            })
+            .to_string())
+    }
+
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(
+            r#"(<(?:Product|Package)\b[^>]*?\sVersion\s*=\s*")(\d+\.\d+\.\d+)((?:\.\d+)?")"#,
+        )?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"(?i)[/\\][^/\\]+\.wxs$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!(
+            "${{1}}{}.{}.{}${{3}}",
+            version.major, version.minor, version.patch
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_product() {
+        let regex = WixParser::version_match_regex().unwrap();
+        let content = r#"<Product Id="*" Name="App" Language="1033" Version="1.2.3.0" Manufacturer="Acme">"#;
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "1.2.3");
+        assert_eq!(captures.get(3).unwrap().as_str(), ".0\"");
+    }
+
+    #[test]
+    fn test_version_regex_matches_wix4_package() {
+        let regex = WixParser::version_match_regex().unwrap();
+        let content = r#"<Package Name="App" Manufacturer="Acme" Version="2.0.1" UpgradeCode="{GUID}">"#;
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "2.0.1");
+    }
+
+    #[test]
+    fn test_version_regex_ignores_installer_version() {
+        let regex = WixParser::version_match_regex().unwrap();
+        let content = r#"<Package InstallerVersion="200" Compressed="yes" />"#;
+        assert!(regex.captures(content).is_none());
+    }
+
+    #[test]
+    fn test_reset_revision() {
+        let content = r#"<Product Version="1.2.3.45">"#.to_string();
+        let unchanged = WixParser::update_extra_fields(content.clone(), &WalkOptions::default()).unwrap();
+        assert_eq!(unchanged, content);
+
+        let options = WalkOptions { reset_revision: true, ..Default::default() };
+        let reset = WixParser::update_extra_fields(content, &options).unwrap();
+        assert_eq!(reset, r#"<Product Version="1.2.3.0">"#);
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = WixParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/installer/Product.wxs"));
+        assert!(regex.is_match("\\installer\\Product.wxs"));
+        assert!(!regex.is_match("/installer/Product.wxi"));
+    }
+}
@@ -0,0 +1,48 @@
+use crate::parsers::Parser;
+use regex::Regex;
+use semver::Version;
+
+/// Parser for Zig `build.zig.zon` manifests: the `.version` field.
+pub struct ZigZonParser;
+
+impl Parser for ZigZonParser {
+    fn version_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"(?m)^(\s*\.version\s*=\s*")(\d+\.\d+\.\d+[^"]*)""#)?)
+    }
+
+    fn filename_match_regex() -> anyhow::Result<Regex> {
+        Ok(Regex::new(r#"[/\\]build\.zig\.zon$"#)?)
+    }
+
+    fn version_line_format(version: &Version) -> anyhow::Result<String> {
+        Ok(format!("${{1}}{version}\""))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_regex_matches_version_field() {
+        let regex = ZigZonParser::version_match_regex().unwrap();
+        let content = ".{\n    .name = .my_pkg,\n    .version = \"0.3.1\",\n}\n";
+        let captures = regex.captures(content).unwrap();
+        assert_eq!(captures.get(2).unwrap().as_str(), "0.3.1");
+    }
+
+    #[test]
+    fn test_version_regex_ignores_minimum_zig_version() {
+        let regex = ZigZonParser::version_match_regex().unwrap();
+        let content = ".{\n    .minimum_zig_version = \"0.14.0\",\n}\n";
+        assert!(regex.captures(content).is_none());
+    }
+
+    #[test]
+    fn test_filename_regex() {
+        let regex = ZigZonParser::filename_match_regex().unwrap();
+        assert!(regex.is_match("/project/build.zig.zon"));
+        assert!(regex.is_match("\\project\\build.zig.zon"));
+        assert!(!regex.is_match("/project/build.zig"));
+    }
+}
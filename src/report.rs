@@ -0,0 +1,69 @@
+use serde::Serialize;
+use serde_json::{Value, json};
+
+/// What a run did to one `--path` root, printed as one JSON object per root by `--output json`.
+/// A root whose files already held the target version reports `changed: false` with no files,
+/// commit or tag.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RunReport {
+    /// The root as given to `--path`
+    pub path: String,
+    /// Whether the files were bumped (or, with `--tag-existing-commit`, HEAD was tagged)
+    pub changed: bool,
+    /// The version the files held before the run, if one was found
+    pub previous: Option<String>,
+    /// The version the files hold after the run, or `None` if the run stopped before picking one
+    pub version: Option<String>,
+    /// Absolute paths of the files that were updated
+    pub files: Vec<String>,
+    /// Id of the release commit, if the git step created or tagged one
+    pub commit: Option<String>,
+    /// Name of the release tag, if the git step created one
+    pub tag: Option<String>,
+}
+
+/// A JSON Schema (draft 2020-12) for one `RunReport` object, printed by `--print-report-schema`
+pub fn report_schema() -> Value {
+    let nullable_string = |description: &str| json!({ "type": ["string", "null"], "description": description });
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "RunReport",
+        "description": "What an update-version run did to one --path root. --output json prints one per line.",
+        "type": "object",
+        "properties": {
+            "path": { "type": "string", "description": "The root as given to --path" },
+            "changed": {
+                "type": "boolean",
+                "description": "Whether the files were bumped; false when they already held the target version"
+            },
+            "previous": nullable_string("The version the files held before the run, if one was found"),
+            "version": nullable_string("The version the files hold after the run"),
+            "files": {
+                "type": "array",
+                "items": { "type": "string" },
+                "description": "Absolute paths of the files that were updated"
+            },
+            "commit": nullable_string("Id of the release commit, if the git step created or tagged one"),
+            "tag": nullable_string("Name of the release tag, if the git step created one"),
+        },
+        "required": ["path", "changed", "previous", "version", "files", "commit", "tag"],
+        "additionalProperties": false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_properties_match_report_fields() {
+        let report = serde_json::to_value(RunReport::default()).unwrap();
+        let mut fields: Vec<&String> = report.as_object().unwrap().keys().collect();
+        let schema = report_schema();
+        let mut properties: Vec<&String> = schema["properties"].as_object().unwrap().keys().collect();
+        fields.sort();
+        properties.sort();
+        assert_eq!(fields, properties);
+        assert_eq!(schema["required"].as_array().unwrap().len(), fields.len());
+    }
+}
@@ -0,0 +1,520 @@
+use crate::arguments::SupportedTypes;
+use crate::parsers::{
+    Parser, ParsingError, WalkOptions, increment_semver, ansible_galaxy_parser::AnsibleGalaxyParser,
+    assembly_info_parser::AssemblyInfoParser, bukkit_plugin_parser::BukkitPluginParser,
+    cabal_parser::CabalParser, cmake_parser::CMakeParser, conan_parser::ConanParser,
+    dockerfile_parser::DockerfileParser, dotnet_project_parser::DotnetProjectParser,
+    dune_parser::DuneParser, env_file_parser::EnvFileParser, generic_json_parser::GenericJsonParser,
+    generic_toml_parser::GenericTomlParser, godot_parser::GodotParser,
+    gradle_properties_parser::GradlePropertiesParser, julia_project_parser::JuliaProjectParser,
+    lerna_parser::LernaParser, maven_parser::MavenParser, nimble_parser::NimbleParser,
+    package_json_parser::PackageJsonParser, plist_parser::PlistParser,
+    pubspec_parser::PubspecParser, r_description_parser::RDescriptionParser,
+    readme_parser::ReadmeParser, ros_package_parser::RosPackageParser,
+    ruby_gem_parser::RubyGemParser, setup_cfg_parser::SetupCfgParser,
+    snapcraft_parser::SnapcraftParser, swift_parser::SwiftParser,
+    tauri_config_parser::TauriConfigParser, toml_parser::TomlParser, unity_parser::UnityParser,
+    vcpkg_parser::VcpkgParser, wix_parser::WixParser, zig_zon_parser::ZigZonParser,
+};
+use anyhow::Result;
+use log::debug;
+use regex::Regex;
+use semver::{BuildMetadata, Prerelease, Version};
+use std::path::{Path, PathBuf};
+
+/// Which component of a version to bump.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum BumpKind {
+    /// `1.2.3` → `2.0.0`
+    Major,
+    /// `1.2.3` → `1.3.0`
+    Minor,
+    /// `1.2.3` → `1.2.4`, or `1.0.0-alpha.0` → `1.0.0-alpha.1` (see `increment_semver`)
+    Patch,
+    /// `1.0.0-rc.1` → `1.0.0-rc.2`, or from a stable version `1.2.0` → `1.2.1-rc.0` to begin a
+    /// new prerelease cycle
+    Prerelease,
+}
+
+/// Label used when a prerelease bump starts a new cycle from a stable version
+pub const DEFAULT_PRERELEASE_LABEL: &str = "rc";
+
+/// Bumps `version` by `kind`. Major and minor bumps reset the lower components and clear
+/// any prerelease; patch bumps follow `increment_semver`. A prerelease bump of a stable
+/// version starts a `rc` cycle; see `bump_version_with_label`.
+pub fn bump_version(version: &Version, kind: BumpKind) -> Result<Version> {
+    bump_version_with_label(version, kind, DEFAULT_PRERELEASE_LABEL)
+}
+
+/// Like `bump_version`, but a prerelease bump of a stable version begins a cycle labelled
+/// `label` on the next patch: `1.2.0` → `1.2.1-{label}.0`. Existing prereleases keep their label.
+pub fn bump_version_with_label(version: &Version, kind: BumpKind, label: &str) -> Result<Version> {
+    let mut next = version.clone();
+    next.build = BuildMetadata::EMPTY;
+    match kind {
+        BumpKind::Major => {
+            next.major += 1;
+            next.minor = 0;
+            next.patch = 0;
+            next.pre = Prerelease::EMPTY;
+        }
+        BumpKind::Minor => {
+            next.minor += 1;
+            next.patch = 0;
+            next.pre = Prerelease::EMPTY;
+        }
+        BumpKind::Patch => return increment_semver(version),
+        BumpKind::Prerelease => {
+            if version.pre.is_empty() {
+                next.patch += 1;
+                next.pre = Prerelease::new(&format!("{}.0", label))
+                    .map_err(|e| anyhow::anyhow!("Invalid prerelease label '{}': {}", label, e))?;
+                return Ok(next);
+            }
+            let pre = version.pre.as_str();
+            let new_pre = match pre.rsplit_once('.').map(|(label, n)| (label, n.parse::<u64>())) {
+                Some((label, Ok(n))) => format!("{}.{}", label, n + 1),
+                _ => match pre.parse::<u64>() {
+                    Ok(n) => (n + 1).to_string(),
+                    Err(_) => format!("{}.0", pre),
+                },
+            };
+            next.pre = Prerelease::new(&new_pre)?;
+        }
+    }
+    Ok(next)
+}
+
+/// Carries `current`'s prerelease over to `next` after a major or minor bump, which otherwise
+/// clears it: `1.2.0-rc.1` bumped minor becomes `1.3.0-rc.1` instead of `1.3.0`. Patch and
+/// prerelease bumps already keep the label, so for those `next` is returned unchanged.
+pub fn keep_prerelease(current: &Version, mut next: Version, kind: BumpKind) -> Version {
+    if matches!(kind, BumpKind::Major | BumpKind::Minor) {
+        next.pre = current.pre.clone();
+    }
+    next
+}
+
+/// Computes the version that a bump of `kind` would produce for the project at `path`,
+/// without writing anything. The current version is read the same way as a real run.
+pub fn next_version(
+    path: impl AsRef<Path>,
+    supported_types: SupportedTypes,
+    bump: BumpKind,
+    options: &WalkOptions,
+) -> Result<Version> {
+    let current = current_version_for(supported_types, path.as_ref(), options)?;
+    bump_version(&current, bump)
+}
+
+/// Picks the bump implied by Conventional Commits `messages`: a breaking change (`feat!:` or a
+/// `BREAKING CHANGE:` footer) bumps major, `feat` bumps minor and anything else bumps patch.
+/// While `current` is `0.x` each level shifts down one, since anything may change before 1.0:
+/// breaking → minor and feat → patch. `strict_semver` disables that shift.
+pub fn conventional_bump(messages: &[String], current: &Version, strict_semver: bool) -> Result<BumpKind> {
+    let header = Regex::new(r"^(\w+)(?:\([^)]*\))?(!)?:")?;
+    let footer = Regex::new(r"(?m)^BREAKING[ -]CHANGE:")?;
+
+    let mut kind = BumpKind::Patch;
+    for message in messages {
+        let captures = header.captures(message);
+        if captures.as_ref().is_some_and(|c| c.get(2).is_some()) || footer.is_match(message) {
+            kind = BumpKind::Major;
+            break;
+        }
+        if captures.is_some_and(|c| &c[1] == "feat") {
+            kind = BumpKind::Minor;
+        }
+    }
+
+    if current.major == 0 && !strict_semver {
+        kind = match kind {
+            BumpKind::Major => BumpKind::Minor,
+            _ => BumpKind::Patch,
+        };
+    }
+    Ok(kind)
+}
+
+/// Narrows `options` to `supported_type` by filling in its configured extra filename patterns
+fn scoped_options(supported_type: SupportedTypes, options: &WalkOptions) -> WalkOptions {
+    WalkOptions { extra_files: options.config.extra_files(supported_type).to_vec(), ..options.clone() }
+}
+
+fn apply_version<P: Parser>(
+    path: &Path,
+    version: &Version,
+    options: &WalkOptions,
+) -> Result<Vec<PathBuf>> {
+    P::update_version(path, version, options)
+}
+
+/// Whether `error` is a parser reporting that it matched no files at all
+fn is_no_matching_files(error: &anyhow::Error) -> bool {
+    matches!(error.downcast_ref::<ParsingError>(), Some(ParsingError::NoMatchingFilesError(_)))
+}
+
+/// Applies `version` using the parser(s) for the given type, returning the matched files sorted
+/// by path so the output doesn't depend on walk order
+pub fn update_version_for(
+    supported_type: SupportedTypes,
+    path: &Path,
+    version: &Version,
+    options: &WalkOptions,
+) -> Result<Vec<PathBuf>> {
+    let options = &scoped_options(supported_type, options);
+    let mut files = match supported_type {
+        SupportedTypes::All => {
+            let options = &WalkOptions { fanout_covered: true, ..options.clone() };
+            let mut modified_files = Vec::new();
+            for supported_type in SupportedTypes::All.expand() {
+                modified_files.extend(update_version_for(supported_type, path, version, options)?);
+            }
+            Ok(modified_files)
+        }
+        SupportedTypes::TOML => TomlParser::update_workspace(path, version, options),
+        SupportedTypes::PackageJSON => apply_version::<PackageJsonParser>(path, version, options),
+        SupportedTypes::TauriConfig => apply_version::<TauriConfigParser>(path, version, options),
+        SupportedTypes::Ruby => apply_version::<RubyGemParser>(path, version, options),
+        SupportedTypes::RDescription => apply_version::<RDescriptionParser>(path, version, options),
+        SupportedTypes::Julia => apply_version::<JuliaProjectParser>(path, version, options),
+        SupportedTypes::Swift => apply_version::<SwiftParser>(path, version, options),
+        SupportedTypes::AssemblyInfo => apply_version::<AssemblyInfoParser>(path, version, options),
+        SupportedTypes::Plist => apply_version::<PlistParser>(path, version, options),
+        SupportedTypes::Snapcraft => apply_version::<SnapcraftParser>(path, version, options),
+        SupportedTypes::AnsibleGalaxy => apply_version::<AnsibleGalaxyParser>(path, version, options),
+        SupportedTypes::Zig => apply_version::<ZigZonParser>(path, version, options),
+        SupportedTypes::Vcpkg => apply_version::<VcpkgParser>(path, version, options),
+        SupportedTypes::Conan => apply_version::<ConanParser>(path, version, options),
+        SupportedTypes::Wix => apply_version::<WixParser>(path, version, options),
+        SupportedTypes::Maven => apply_version::<MavenParser>(path, version, options),
+        SupportedTypes::GradleProperties => apply_version::<GradlePropertiesParser>(path, version, options),
+        SupportedTypes::Nimble => apply_version::<NimbleParser>(path, version, options),
+        SupportedTypes::Dockerfile => apply_version::<DockerfileParser>(path, version, options),
+        SupportedTypes::Godot => apply_version::<GodotParser>(path, version, options),
+        SupportedTypes::Cabal => apply_version::<CabalParser>(path, version, options),
+        SupportedTypes::Dune => apply_version::<DuneParser>(path, version, options),
+        SupportedTypes::Ros => apply_version::<RosPackageParser>(path, version, options),
+        SupportedTypes::Lerna => LernaParser::update_workspace(path, version, options),
+        SupportedTypes::EnvFile => apply_version::<EnvFileParser>(path, version, options),
+        SupportedTypes::SetupCfg => apply_version::<SetupCfgParser>(path, version, options),
+        SupportedTypes::Json => apply_version::<GenericJsonParser>(path, version, options),
+        SupportedTypes::GenericToml => apply_version::<GenericTomlParser>(path, version, options),
+        SupportedTypes::Pubspec => apply_version::<PubspecParser>(path, version, options),
+        SupportedTypes::Readme => apply_version::<ReadmeParser>(path, version, options),
+        SupportedTypes::Unity => apply_version::<UnityParser>(path, version, options),
+        SupportedTypes::BukkitPlugin => apply_version::<BukkitPluginParser>(path, version, options),
+        SupportedTypes::Dotnet => apply_version::<DotnetProjectParser>(path, version, options),
+        SupportedTypes::CMake => apply_version::<CMakeParser>(path, version, options),
+    }?;
+    files.sort();
+    Ok(files)
+}
+
+/// Summarizes per-type update results as e.g. `5 files (4 toml, 1 package-json)`.
+/// Types that matched no files are left out.
+pub fn update_summary(updates: &[(SupportedTypes, Vec<PathBuf>)]) -> String {
+    let counts: Vec<String> = updates
+        .iter()
+        .filter(|(_, files)| !files.is_empty())
+        .map(|(supported_type, files)| format!("{} {}", files.len(), supported_type.name()))
+        .collect();
+    let total: usize = updates.iter().map(|(_, files)| files.len()).sum();
+    if total == 0 {
+        return "no files".to_string();
+    }
+    format!("{} file{} ({})", total, if total == 1 { "" } else { "s" }, counts.join(", "))
+}
+
+/// Gets the current version using the parser(s) for the given type.
+/// For `All`, the first type that yields a version wins.
+pub fn current_version_for(
+    supported_type: SupportedTypes,
+    path: &Path,
+    options: &WalkOptions,
+) -> Result<Version> {
+    let options = &scoped_options(supported_type, options);
+    match supported_type {
+        SupportedTypes::All => {
+            // Report the most telling failure: a type with no matching files says the least
+            let mut last_error: Option<anyhow::Error> = None;
+            for supported_type in SupportedTypes::All.expand() {
+                match current_version_for(supported_type, path, options) {
+                    Ok(version) => return Ok(version),
+                    Err(e) if last_error.is_some() && is_no_matching_files(&e) => {}
+                    Err(e) => {
+                        // e.g. a Tauri `src-tauri/Cargo.toml` inheriting its version falls back to package.json
+                        debug!("No {:?} version to start from ({}), trying the next type", supported_type, e);
+                        last_error = Some(e);
+                    }
+                }
+            }
+            Err(last_error.unwrap_or_else(|| {
+                ParsingError::NoMatchingFilesError(path.to_string_lossy().to_string()).into()
+            }))
+        }
+        SupportedTypes::TOML => TomlParser::get_current_version(path, options),
+        SupportedTypes::PackageJSON => PackageJsonParser::get_current_version(path, options),
+        SupportedTypes::TauriConfig => TauriConfigParser::get_current_version(path, options),
+        SupportedTypes::Ruby => RubyGemParser::get_current_version(path, options),
+        SupportedTypes::RDescription => RDescriptionParser::get_current_version(path, options),
+        SupportedTypes::Julia => JuliaProjectParser::get_current_version(path, options),
+        SupportedTypes::Swift => SwiftParser::get_current_version(path, options),
+        SupportedTypes::AssemblyInfo => AssemblyInfoParser::get_current_version(path, options),
+        SupportedTypes::Plist => PlistParser::get_current_version(path, options),
+        SupportedTypes::Snapcraft => SnapcraftParser::get_current_version(path, options),
+        SupportedTypes::AnsibleGalaxy => AnsibleGalaxyParser::get_current_version(path, options),
+        SupportedTypes::Zig => ZigZonParser::get_current_version(path, options),
+        SupportedTypes::Vcpkg => VcpkgParser::get_current_version(path, options),
+        SupportedTypes::Conan => ConanParser::get_current_version(path, options),
+        SupportedTypes::Wix => WixParser::get_current_version(path, options),
+        SupportedTypes::Maven => MavenParser::get_current_version(path, options),
+        SupportedTypes::GradleProperties => GradlePropertiesParser::get_current_version(path, options),
+        SupportedTypes::Nimble => NimbleParser::get_current_version(path, options),
+        SupportedTypes::Dockerfile => DockerfileParser::get_current_version(path, options),
+        SupportedTypes::Godot => GodotParser::get_current_version(path, options),
+        SupportedTypes::Cabal => CabalParser::get_current_version(path, options),
+        SupportedTypes::Dune => DuneParser::get_current_version(path, options),
+        SupportedTypes::Ros => RosPackageParser::get_current_version(path, options),
+        SupportedTypes::Lerna => LernaParser::get_current_version(path, options),
+        SupportedTypes::EnvFile => EnvFileParser::get_current_version(path, options),
+        SupportedTypes::SetupCfg => SetupCfgParser::get_current_version(path, options),
+        SupportedTypes::Json => GenericJsonParser::get_current_version(path, options),
+        SupportedTypes::GenericToml => GenericTomlParser::get_current_version(path, options),
+        SupportedTypes::Pubspec => PubspecParser::get_current_version(path, options),
+        SupportedTypes::Readme => ReadmeParser::get_current_version(path, options),
+        SupportedTypes::Unity => UnityParser::get_current_version(path, options),
+        SupportedTypes::BukkitPlugin => BukkitPluginParser::get_current_version(path, options),
+        SupportedTypes::Dotnet => DotnetProjectParser::get_current_version(path, options),
+        SupportedTypes::CMake => CMakeParser::get_current_version(path, options),
+    }
+}
+
+/// Re-reads the files for the given type after a write and returns one description per file
+/// whose version doesn't match `version`
+pub fn verify_version_for(
+    supported_type: SupportedTypes,
+    path: &Path,
+    version: &Version,
+    options: &WalkOptions,
+) -> Result<Vec<String>> {
+    let options = &scoped_options(supported_type, options);
+    match supported_type {
+        SupportedTypes::All => {
+            let mut mismatches = Vec::new();
+            for supported_type in SupportedTypes::All.expand() {
+                mismatches.extend(verify_version_for(supported_type, path, version, options)?);
+            }
+            Ok(mismatches)
+        }
+        SupportedTypes::TOML => TomlParser::verify_version(path, version, options),
+        SupportedTypes::PackageJSON => PackageJsonParser::verify_version(path, version, options),
+        SupportedTypes::TauriConfig => TauriConfigParser::verify_version(path, version, options),
+        SupportedTypes::Ruby => RubyGemParser::verify_version(path, version, options),
+        SupportedTypes::RDescription => RDescriptionParser::verify_version(path, version, options),
+        SupportedTypes::Julia => JuliaProjectParser::verify_version(path, version, options),
+        SupportedTypes::Swift => SwiftParser::verify_version(path, version, options),
+        SupportedTypes::AssemblyInfo => AssemblyInfoParser::verify_version(path, version, options),
+        SupportedTypes::Plist => PlistParser::verify_version(path, version, options),
+        SupportedTypes::Snapcraft => SnapcraftParser::verify_version(path, version, options),
+        SupportedTypes::AnsibleGalaxy => AnsibleGalaxyParser::verify_version(path, version, options),
+        SupportedTypes::Zig => ZigZonParser::verify_version(path, version, options),
+        SupportedTypes::Vcpkg => VcpkgParser::verify_version(path, version, options),
+        SupportedTypes::Conan => ConanParser::verify_version(path, version, options),
+        SupportedTypes::Wix => WixParser::verify_version(path, version, options),
+        SupportedTypes::Maven => MavenParser::verify_version(path, version, options),
+        SupportedTypes::GradleProperties => GradlePropertiesParser::verify_version(path, version, options),
+        SupportedTypes::Nimble => NimbleParser::verify_version(path, version, options),
+        SupportedTypes::Dockerfile => DockerfileParser::verify_version(path, version, options),
+        SupportedTypes::Godot => GodotParser::verify_version(path, version, options),
+        SupportedTypes::Cabal => CabalParser::verify_version(path, version, options),
+        SupportedTypes::Dune => DuneParser::verify_version(path, version, options),
+        SupportedTypes::Ros => RosPackageParser::verify_version(path, version, options),
+        SupportedTypes::Lerna => LernaParser::verify_version(path, version, options),
+        SupportedTypes::EnvFile => EnvFileParser::verify_version(path, version, options),
+        SupportedTypes::SetupCfg => SetupCfgParser::verify_version(path, version, options),
+        SupportedTypes::Json => GenericJsonParser::verify_version(path, version, options),
+        SupportedTypes::GenericToml => GenericTomlParser::verify_version(path, version, options),
+        SupportedTypes::Pubspec => PubspecParser::verify_version(path, version, options),
+        SupportedTypes::Readme => ReadmeParser::verify_version(path, version, options),
+        SupportedTypes::Unity => UnityParser::verify_version(path, version, options),
+        SupportedTypes::BukkitPlugin => BukkitPluginParser::verify_version(path, version, options),
+        SupportedTypes::Dotnet => DotnetProjectParser::verify_version(path, version, options),
+        SupportedTypes::CMake => CMakeParser::verify_version(path, version, options),
+    }
+}
+
+/// Gets the files the parser(s) for the given type would consider
+pub fn matching_files_for(
+    supported_type: SupportedTypes,
+    path: &Path,
+    options: &WalkOptions,
+) -> Result<Vec<PathBuf>> {
+    let options = &scoped_options(supported_type, options);
+    match supported_type {
+        SupportedTypes::All => {
+            let mut files = Vec::new();
+            for supported_type in SupportedTypes::All.expand() {
+                files.extend(matching_files_for(supported_type, path, options)?);
+            }
+            Ok(files)
+        }
+        SupportedTypes::TOML => TomlParser::get_matching_files(path, options),
+        SupportedTypes::PackageJSON => PackageJsonParser::get_matching_files(path, options),
+        SupportedTypes::TauriConfig => TauriConfigParser::get_matching_files(path, options),
+        SupportedTypes::Ruby => RubyGemParser::get_matching_files(path, options),
+        SupportedTypes::RDescription => RDescriptionParser::get_matching_files(path, options),
+        SupportedTypes::Julia => JuliaProjectParser::get_matching_files(path, options),
+        SupportedTypes::Swift => SwiftParser::get_matching_files(path, options),
+        SupportedTypes::AssemblyInfo => AssemblyInfoParser::get_matching_files(path, options),
+        SupportedTypes::Plist => PlistParser::get_matching_files(path, options),
+        SupportedTypes::Snapcraft => SnapcraftParser::get_matching_files(path, options),
+        SupportedTypes::AnsibleGalaxy => AnsibleGalaxyParser::get_matching_files(path, options),
+        SupportedTypes::Zig => ZigZonParser::get_matching_files(path, options),
+        SupportedTypes::Vcpkg => VcpkgParser::get_matching_files(path, options),
+        SupportedTypes::Conan => ConanParser::get_matching_files(path, options),
+        SupportedTypes::Wix => WixParser::get_matching_files(path, options),
+        SupportedTypes::Maven => MavenParser::get_matching_files(path, options),
+        SupportedTypes::GradleProperties => GradlePropertiesParser::get_matching_files(path, options),
+        SupportedTypes::Nimble => NimbleParser::get_matching_files(path, options),
+        SupportedTypes::Dockerfile => DockerfileParser::get_matching_files(path, options),
+        SupportedTypes::Godot => GodotParser::get_matching_files(path, options),
+        SupportedTypes::Cabal => CabalParser::get_matching_files(path, options),
+        SupportedTypes::Dune => DuneParser::get_matching_files(path, options),
+        SupportedTypes::Ros => RosPackageParser::get_matching_files(path, options),
+        SupportedTypes::Lerna => LernaParser::get_matching_files(path, options),
+        SupportedTypes::EnvFile => EnvFileParser::get_matching_files(path, options),
+        SupportedTypes::SetupCfg => SetupCfgParser::get_matching_files(path, options),
+        SupportedTypes::Json => GenericJsonParser::get_matching_files(path, options),
+        SupportedTypes::GenericToml => GenericTomlParser::get_matching_files(path, options),
+        SupportedTypes::Pubspec => PubspecParser::get_matching_files(path, options),
+        SupportedTypes::Readme => ReadmeParser::get_matching_files(path, options),
+        SupportedTypes::Unity => UnityParser::get_matching_files(path, options),
+        SupportedTypes::BukkitPlugin => BukkitPluginParser::get_matching_files(path, options),
+        SupportedTypes::Dotnet => DotnetProjectParser::get_matching_files(path, options),
+        SupportedTypes::CMake => CMakeParser::get_matching_files(path, options),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn v(s: &str) -> Version {
+        Version::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_bump_major() {
+        assert_eq!(bump_version(&v("1.2.3"), BumpKind::Major).unwrap(), v("2.0.0"));
+        assert_eq!(bump_version(&v("1.2.3-rc.1+build"), BumpKind::Major).unwrap(), v("2.0.0"));
+    }
+
+    #[test]
+    fn test_bump_minor() {
+        assert_eq!(bump_version(&v("1.2.3"), BumpKind::Minor).unwrap(), v("1.3.0"));
+        assert_eq!(bump_version(&v("0.9.9-beta"), BumpKind::Minor).unwrap(), v("0.10.0"));
+    }
+
+    #[test]
+    fn test_keep_prerelease() {
+        let current = v("1.2.0-rc.1");
+        let minor = bump_version(&current, BumpKind::Minor).unwrap();
+        assert_eq!(minor, v("1.3.0"));
+        assert_eq!(keep_prerelease(&current, minor, BumpKind::Minor), v("1.3.0-rc.1"));
+        let major = bump_version(&current, BumpKind::Major).unwrap();
+        assert_eq!(keep_prerelease(&current, major, BumpKind::Major), v("2.0.0-rc.1"));
+        let patch = bump_version(&current, BumpKind::Patch).unwrap();
+        assert_eq!(keep_prerelease(&current, patch, BumpKind::Patch), v("1.2.0-rc.2"));
+    }
+
+    #[test]
+    fn test_bump_patch() {
+        assert_eq!(bump_version(&v("1.2.3"), BumpKind::Patch).unwrap(), v("1.2.4"));
+        assert_eq!(bump_version(&v("1.0.0-alpha.0"), BumpKind::Patch).unwrap(), v("1.0.0-alpha.1"));
+    }
+
+    #[test]
+    fn test_bump_prerelease() {
+        assert_eq!(bump_version(&v("1.2.0-rc.1"), BumpKind::Prerelease).unwrap(), v("1.2.0-rc.2"));
+        assert_eq!(bump_version(&v("1.2.0-3"), BumpKind::Prerelease).unwrap(), v("1.2.0-4"));
+        assert_eq!(bump_version(&v("1.2.0-beta"), BumpKind::Prerelease).unwrap(), v("1.2.0-beta.0"));
+    }
+
+    #[test]
+    fn test_bump_prerelease_begins_cycle_from_stable() {
+        assert_eq!(bump_version(&v("1.2.0"), BumpKind::Prerelease).unwrap(), v("1.2.1-rc.0"));
+        assert_eq!(bump_version_with_label(&v("1.2.0+7"), BumpKind::Prerelease, "beta").unwrap(), v("1.2.1-beta.0"));
+        // An existing prerelease keeps its own label
+        assert_eq!(bump_version_with_label(&v("1.2.0-rc.1"), BumpKind::Prerelease, "beta").unwrap(), v("1.2.0-rc.2"));
+        assert!(bump_version_with_label(&v("1.2.0"), BumpKind::Prerelease, "not valid").is_err());
+    }
+
+    fn messages(list: &[&str]) -> Vec<String> {
+        list.iter().map(|m| m.to_string()).collect()
+    }
+
+    #[test]
+    fn test_conventional_bump_stable() {
+        let current = v("1.4.2");
+        let fix = messages(&["fix: crash", "chore: deps"]);
+        let feat = messages(&["fix: crash", "feat(cli): new flag"]);
+        let breaking = messages(&["feat!: drop old flag", "fix: crash"]);
+        let footer = messages(&["refactor: rework\n\nBREAKING CHANGE: config moved"]);
+        assert_eq!(conventional_bump(&fix, &current, false).unwrap(), BumpKind::Patch);
+        assert_eq!(conventional_bump(&feat, &current, false).unwrap(), BumpKind::Minor);
+        assert_eq!(conventional_bump(&breaking, &current, false).unwrap(), BumpKind::Major);
+        assert_eq!(conventional_bump(&footer, &current, false).unwrap(), BumpKind::Major);
+    }
+
+    #[test]
+    fn test_conventional_bump_pre_1_0_shifts_down() {
+        let current = v("0.7.1");
+        let fix = messages(&["fix: crash"]);
+        let feat = messages(&["feat: new flag"]);
+        let breaking = messages(&["feat(api)!: rename"]);
+        assert_eq!(conventional_bump(&fix, &current, false).unwrap(), BumpKind::Patch);
+        assert_eq!(conventional_bump(&feat, &current, false).unwrap(), BumpKind::Patch);
+        assert_eq!(conventional_bump(&breaking, &current, false).unwrap(), BumpKind::Minor);
+    }
+
+    #[test]
+    fn test_conventional_bump_pre_1_0_strict_semver() {
+        let current = v("0.7.1");
+        let feat = messages(&["feat: new flag"]);
+        let breaking = messages(&["feat(api)!: rename"]);
+        assert_eq!(conventional_bump(&feat, &current, true).unwrap(), BumpKind::Minor);
+        assert_eq!(conventional_bump(&breaking, &current, true).unwrap(), BumpKind::Major);
+    }
+
+    #[test]
+    fn test_update_summary_counts_per_type() {
+        let updates = vec![
+            (SupportedTypes::TOML, vec![PathBuf::from("a/Cargo.toml"), PathBuf::from("b/Cargo.toml")]),
+            (SupportedTypes::PackageJSON, vec![PathBuf::from("package.json")]),
+            (SupportedTypes::TauriConfig, vec![]),
+        ];
+        assert_eq!(update_summary(&updates), "3 files (2 toml, 1 package-json)");
+        assert_eq!(update_summary(&updates[1..2]), "1 file (1 package-json)");
+        assert_eq!(update_summary(&[]), "no files");
+    }
+
+    #[test]
+    fn test_next_version_does_not_write() {
+        let temp_dir = TempDir::new().unwrap();
+        let cargo_toml = temp_dir.path().join("Cargo.toml");
+        let original = "[package]\nname = \"a\"\nversion = \"1.4.2\"\n";
+        std::fs::write(&cargo_toml, original).unwrap();
+
+        let next = next_version(
+            temp_dir.path(),
+            SupportedTypes::TOML,
+            BumpKind::Minor,
+            &WalkOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(next, v("1.5.0"));
+        assert_eq!(std::fs::read_to_string(&cargo_toml).unwrap(), original);
+    }
+}
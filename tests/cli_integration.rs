@@ -0,0 +1,902 @@
+//! Integration tests for the `uv` binary
+
+use std::fs;
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+use tempfile::TempDir;
+
+/// Helper to run the `uv` binary with the given arguments
+fn run_uv(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_uv"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+/// Helper to create a temporary project with a single Cargo.toml
+fn create_cargo_project(version: &str) -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("Cargo.toml"),
+        format!(
+            r#"[package]
+name = "test-crate"
+version = "{version}"
+"#
+        ),
+    )
+    .unwrap();
+    temp_dir
+}
+
+#[test]
+fn test_write_version_to_file() {
+    let temp_dir = create_cargo_project("1.0.0");
+    let target = temp_dir.path().join("dist").join("VERSION");
+
+    let output = run_uv(&[
+        "-p",
+        temp_dir.path().to_str().unwrap(),
+        "--write-version-to",
+        target.to_str().unwrap(),
+        "2.3.4",
+    ]);
+    assert!(output.status.success());
+
+    assert_eq!(fs::read_to_string(&target).unwrap(), "2.3.4");
+}
+
+#[test]
+fn test_write_version_to_file_dry_run() {
+    let temp_dir = create_cargo_project("1.0.0");
+    let target = temp_dir.path().join("VERSION");
+
+    let output = run_uv(&[
+        "-p",
+        temp_dir.path().to_str().unwrap(),
+        "--dry-run",
+        "--write-version-to",
+        target.to_str().unwrap(),
+        "2.3.4",
+    ]);
+    assert!(output.status.success());
+
+    assert!(!target.exists());
+    let content = fs::read_to_string(temp_dir.path().join("Cargo.toml")).unwrap();
+    assert!(content.contains(r#"version = "1.0.0""#));
+}
+
+#[test]
+fn test_dry_run_verbose_logs_diff() {
+    let temp_dir = create_cargo_project("1.0.0");
+
+    let output = run_uv(&[
+        "-p",
+        temp_dir.path().to_str().unwrap(),
+        "--dry-run",
+        "--verbose",
+        "--no-color",
+        "2.0.0",
+    ]);
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains(r#"-version = "1.0.0""#), "stderr: {stderr}");
+    assert!(stderr.contains(r#"+version = "2.0.0""#), "stderr: {stderr}");
+
+    let content = fs::read_to_string(temp_dir.path().join("Cargo.toml")).unwrap();
+    assert!(content.contains(r#"version = "1.0.0""#));
+}
+
+#[test]
+fn test_interactive_with_yes_proceeds() {
+    let temp_dir = create_cargo_project("1.0.0");
+
+    let output = run_uv(&["-p", temp_dir.path().to_str().unwrap(), "-i", "--yes", "1.1.0"]);
+    assert!(output.status.success());
+
+    let content = fs::read_to_string(temp_dir.path().join("Cargo.toml")).unwrap();
+    assert!(content.contains(r#"version = "1.1.0""#));
+}
+
+#[test]
+fn test_interactive_without_terminal_aborts() {
+    let temp_dir = create_cargo_project("1.0.0");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_uv"))
+        .args(["-p", temp_dir.path().to_str().unwrap(), "-i", "1.1.0"])
+        .stdin(Stdio::null())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let content = fs::read_to_string(temp_dir.path().join("Cargo.toml")).unwrap();
+    assert!(content.contains(r#"version = "1.0.0""#));
+}
+
+#[test]
+fn test_list_files_includes_nested_manifests() {
+    let temp_dir = create_cargo_project("1.0.0");
+    let nested = temp_dir.path().join("crates").join("sub");
+    fs::create_dir_all(&nested).unwrap();
+    // No version field: list-files still reports it
+    fs::write(nested.join("Cargo.toml"), "[package]\nname = \"sub\"\n").unwrap();
+
+    let output = run_uv(&["-p", temp_dir.path().to_str().unwrap(), "-t", "toml", "--list-files"]);
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let root = temp_dir.path().join("Cargo.toml");
+    let sub = nested.join("Cargo.toml");
+    assert!(stdout.starts_with("toml:\n"), "stdout: {stdout}");
+    assert!(stdout.contains(root.to_str().unwrap()), "stdout: {stdout}");
+    assert!(stdout.contains(sub.to_str().unwrap()), "stdout: {stdout}");
+
+    let content = fs::read_to_string(&root).unwrap();
+    assert!(content.contains(r#"version = "1.0.0""#));
+}
+
+/// Helper to run the `uv` binary with the given arguments and stdin contents
+fn run_uv_with_stdin(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_uv"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn test_version_from_stdin() {
+    let temp_dir = create_cargo_project("1.0.0");
+
+    let output = run_uv_with_stdin(&["-p", temp_dir.path().to_str().unwrap(), "-"], "1.4.0\n");
+    assert!(output.status.success());
+
+    let content = fs::read_to_string(temp_dir.path().join("Cargo.toml")).unwrap();
+    assert!(content.contains(r#"version = "1.4.0""#));
+}
+
+#[test]
+fn test_version_from_stdin_rejects_empty_and_invalid() {
+    let temp_dir = create_cargo_project("1.0.0");
+
+    let output = run_uv_with_stdin(&["-p", temp_dir.path().to_str().unwrap(), "-"], "  \n");
+    assert!(!output.status.success());
+
+    let output = run_uv_with_stdin(&["-p", temp_dir.path().to_str().unwrap(), "-"], "not-a-version");
+    assert!(!output.status.success());
+
+    let content = fs::read_to_string(temp_dir.path().join("Cargo.toml")).unwrap();
+    assert!(content.contains(r#"version = "1.0.0""#));
+}
+
+#[test]
+fn test_failing_pre_hook_prevents_changes() {
+    let temp_dir = create_cargo_project("1.0.0");
+    let target = temp_dir.path().join("VERSION");
+
+    let output = run_uv(&[
+        "-p",
+        temp_dir.path().to_str().unwrap(),
+        "--pre-hook",
+        "exit 3",
+        "--write-version-to",
+        target.to_str().unwrap(),
+        "2.0.0",
+    ]);
+    assert!(!output.status.success());
+
+    let content = fs::read_to_string(temp_dir.path().join("Cargo.toml")).unwrap();
+    assert!(content.contains(r#"version = "1.0.0""#));
+    assert!(!target.exists());
+}
+
+#[test]
+fn test_pre_hook_runs_in_project_path() {
+    let temp_dir = create_cargo_project("1.0.0");
+
+    let output = run_uv(&[
+        "-p",
+        temp_dir.path().to_str().unwrap(),
+        "--pre-hook",
+        "echo ran > hook-marker",
+        "2.0.0",
+    ]);
+    assert!(output.status.success());
+
+    assert!(temp_dir.path().join("hook-marker").exists());
+    let content = fs::read_to_string(temp_dir.path().join("Cargo.toml")).unwrap();
+    assert!(content.contains(r#"version = "2.0.0""#));
+}
+
+#[test]
+fn test_initial_version_inserts_into_files_without_version() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("Cargo.toml"),
+        "[package]\nname = \"fresh\"\nedition = \"2024\"\n",
+    )
+    .unwrap();
+    fs::write(temp_dir.path().join("package.json"), "{\n  \"name\": \"fresh\"\n}\n").unwrap();
+
+    let output = run_uv(&["-p", temp_dir.path().to_str().unwrap(), "--initial-version", "0.1.0"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    let cargo = fs::read_to_string(temp_dir.path().join("Cargo.toml")).unwrap();
+    assert_eq!(cargo, "[package]\nname = \"fresh\"\nversion = \"0.1.0\"\nedition = \"2024\"\n");
+    let package = fs::read_to_string(temp_dir.path().join("package.json")).unwrap();
+    assert_eq!(package, "{\n  \"version\": \"0.1.0\",\n  \"name\": \"fresh\"\n}\n");
+}
+
+#[test]
+fn test_progress_flag_without_terminal_runs_normally() {
+    let project = create_cargo_project("1.0.0");
+    let path = project.path().to_str().unwrap();
+
+    let output = run_uv(&["-p", path, "--progress", "1.1.0"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(output.stdout.is_empty());
+
+    let content = fs::read_to_string(project.path().join("Cargo.toml")).unwrap();
+    assert!(content.contains("version = \"1.1.0\""));
+}
+
+#[test]
+fn test_verify_passes_on_normal_update() {
+    let project = create_cargo_project("1.0.0");
+    fs::write(project.path().join("package.json"), "{\n  \"version\": \"1.0.0\"\n}\n").unwrap();
+
+    let output = run_uv(&["-p", project.path().to_str().unwrap(), "--verify", "1.2.0-rc.1"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Verified version 1.2.0-rc.1"));
+}
+
+#[test]
+fn test_update_summary_counts_per_type() {
+    let project = create_cargo_project("1.0.0");
+    let member = project.path().join("member");
+    fs::create_dir_all(&member).unwrap();
+    fs::write(member.join("Cargo.toml"), "[package]\nname = \"member\"\nversion = \"1.0.0\"\n").unwrap();
+    fs::write(project.path().join("package.json"), "{\n  \"version\": \"1.0.0\"\n}\n").unwrap();
+
+    let output = run_uv(&["-p", project.path().to_str().unwrap(), "1.1.0"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Updated 3 files (2 toml, 1 package-json) to 1.1.0"), "stderr: {stderr}");
+}
+
+/// Helper to turn `dir` into a git repository with everything committed
+fn commit_all(dir: &std::path::Path) -> git2::Repository {
+    let repo = git2::Repository::init(dir).unwrap();
+    let mut config = repo.config().unwrap();
+    config.set_str("user.name", "Test User").unwrap();
+    config.set_str("user.email", "test@example.com").unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_all(["*"].iter(), git2::IndexAddOption::DEFAULT, None).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = repo.signature().unwrap();
+    repo.commit(Some("HEAD"), &sig, &sig, "Initial commit", &tree, &[]).unwrap();
+    drop(tree);
+    repo
+}
+
+#[test]
+fn test_noop_skips_git_operations() {
+    let project = create_cargo_project("1.2.0");
+    let repo = commit_all(project.path());
+
+    let output = run_uv(&["-p", project.path().to_str().unwrap(), "-g", "commit-tag", "1.2.0"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("nothing to do"));
+
+    let head = repo.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(head.message(), Some("Initial commit"));
+    assert!(repo.tag_names(None).unwrap().is_empty());
+}
+
+#[test]
+fn test_error_on_noop_exits_nonzero() {
+    let project = create_cargo_project("1.2.0");
+
+    let output = run_uv(&["-p", project.path().to_str().unwrap(), "--error-on-noop", "1.2.0"]);
+    assert_eq!(output.status.code(), Some(3));
+
+    let output = run_uv(&["-p", project.path().to_str().unwrap(), "--error-on-noop", "1.3.0"]);
+    assert!(output.status.success());
+}
+
+#[test]
+fn test_multiple_paths_updated_in_one_call() {
+    let repo_a = create_cargo_project("1.0.0");
+    let repo_b = create_cargo_project("3.4.5");
+
+    let output = run_uv(&[
+        "--path",
+        repo_a.path().to_str().unwrap(),
+        "--path",
+        repo_b.path().to_str().unwrap(),
+        "2.0.0",
+    ]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    for repo in [&repo_a, &repo_b] {
+        let content = fs::read_to_string(repo.path().join("Cargo.toml")).unwrap();
+        assert!(content.contains("version = \"2.0.0\""), "content: {content}");
+    }
+}
+
+#[test]
+fn test_failing_path_does_not_stop_other_paths() {
+    let missing = TempDir::new().unwrap();
+    let repo = create_cargo_project("1.0.0");
+
+    // The empty root has no version to increment from, so it fails
+    let output = run_uv(&["-p", missing.path().to_str().unwrap(), "-p", repo.path().to_str().unwrap()]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Failed to update 1 of 2 paths"), "stderr: {stderr}");
+
+    let content = fs::read_to_string(repo.path().join("Cargo.toml")).unwrap();
+    assert!(content.contains("version = \"1.0.1\""));
+}
+
+#[test]
+fn test_print_tag_uses_tag_format() {
+    let project = create_cargo_project("1.0.0");
+    let path = project.path().to_str().unwrap();
+
+    let output = run_uv(&["-p", path, "--print-tag", "--tag-format", "release-{version}", "2.0.0"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "release-2.0.0\n");
+
+    let output = run_uv(&["-p", path, "--print-tag"]);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "v1.0.1\n");
+
+    // Nothing was written
+    let content = fs::read_to_string(project.path().join("Cargo.toml")).unwrap();
+    assert!(content.contains("version = \"1.0.0\""));
+}
+
+#[test]
+fn test_backup_then_restore() {
+    let project = create_cargo_project("1.0.0");
+    let path = project.path().to_str().unwrap();
+    let cargo_toml = project.path().join("Cargo.toml");
+    let backup = project.path().join("Cargo.toml.bak");
+    let original = fs::read_to_string(&cargo_toml).unwrap();
+
+    let output = run_uv(&["-p", path, "--backup", "2.0.0"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(fs::read_to_string(&backup).unwrap(), original);
+    assert!(fs::read_to_string(&cargo_toml).unwrap().contains("version = \"2.0.0\""));
+
+    let output = run_uv(&["-p", path, "--restore"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(fs::read_to_string(&cargo_toml).unwrap(), original);
+    assert!(!backup.exists());
+}
+
+#[test]
+fn test_restore_includes_hidden_files() {
+    let project = TempDir::new().unwrap();
+    let path = project.path().to_str().unwrap();
+    let env_file = project.path().join(".env");
+    fs::write(&env_file, "VERSION=1.0.0\n").unwrap();
+
+    let output = run_uv(&["-p", path, "--types", "env-file", "--backup", "2.0.0"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(fs::read_to_string(&env_file).unwrap(), "VERSION=2.0.0\n");
+
+    let output = run_uv(&["-p", path, "--restore"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(fs::read_to_string(&env_file).unwrap(), "VERSION=1.0.0\n");
+    assert!(!project.path().join(".env.bak").exists());
+}
+
+#[test]
+fn test_restore_leaves_unrelated_backups() {
+    let project = create_cargo_project("1.0.0");
+    let path = project.path().to_str().unwrap();
+    fs::write(project.path().join("data"), "current").unwrap();
+    fs::write(project.path().join("data.bak"), "old").unwrap();
+
+    let output = run_uv(&["-p", path, "--restore"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(fs::read_to_string(project.path().join("data")).unwrap(), "current");
+    assert_eq!(fs::read_to_string(project.path().join("data.bak")).unwrap(), "old");
+}
+
+#[test]
+fn test_keep_prerelease_on_minor_bump() {
+    let temp_dir = create_cargo_project("1.2.0-rc.1");
+    let path = temp_dir.path().to_str().unwrap();
+
+    let output = run_uv(&["-p", path, "--bump", "minor", "--keep-prerelease"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    let content = fs::read_to_string(temp_dir.path().join("Cargo.toml")).unwrap();
+    assert!(content.contains(r#"version = "1.3.0-rc.1""#));
+
+    let output = run_uv(&["-p", path, "--bump", "minor"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    let content = fs::read_to_string(temp_dir.path().join("Cargo.toml")).unwrap();
+    assert!(content.contains(r#"version = "1.4.0""#));
+}
+
+#[test]
+fn test_patch_bump_advances_prerelease_regardless_of_keep_prerelease() {
+    for keep in [false, true] {
+        let temp_dir = create_cargo_project("1.2.3-beta.1");
+        let path = temp_dir.path().to_str().unwrap();
+        let mut args = vec!["-p", path, "--bump", "patch"];
+        if keep {
+            args.push("--keep-prerelease");
+        }
+
+        let output = run_uv(&args);
+        assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+        let content = fs::read_to_string(temp_dir.path().join("Cargo.toml")).unwrap();
+        assert!(content.contains(r#"version = "1.2.3-beta.2""#), "keep: {keep}, content: {content}");
+    }
+}
+
+#[test]
+fn test_bump_prerelease_from_stable_uses_label() {
+    let temp_dir = create_cargo_project("1.2.0");
+    let path = temp_dir.path().to_str().unwrap();
+
+    let output = run_uv(&["-p", path, "--bump", "prerelease", "--prerelease-label", "beta"]);
+    assert!(output.status.success());
+    let content = fs::read_to_string(temp_dir.path().join("Cargo.toml")).unwrap();
+    assert!(content.contains(r#"version = "1.2.1-beta.0""#));
+
+    let output = run_uv(&["-p", path, "--bump", "prerelease"]);
+    assert!(output.status.success());
+    let content = fs::read_to_string(temp_dir.path().join("Cargo.toml")).unwrap();
+    assert!(content.contains(r#"version = "1.2.1-beta.1""#));
+}
+
+#[test]
+fn test_exit_code_no_matching_files() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let output = run_uv(&["-p", temp_dir.path().to_str().unwrap(), "-t", "toml"]);
+    assert_eq!(output.status.code(), Some(4));
+
+    // An explicit version with nothing to write to fails the same way
+    let output = run_uv(&["-p", temp_dir.path().to_str().unwrap(), "-t", "toml", "1.0.0"]);
+    assert_eq!(output.status.code(), Some(4));
+}
+
+#[test]
+fn test_exit_code_no_version_field() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("Cargo.toml"), "[package]\nname = \"test-crate\"\n").unwrap();
+
+    let output = run_uv(&["-p", temp_dir.path().to_str().unwrap(), "-t", "toml"]);
+    assert_eq!(output.status.code(), Some(5));
+}
+
+#[test]
+fn test_exit_code_invalid_version() {
+    let temp_dir = create_cargo_project("1.0.0");
+
+    let output = run_uv(&["-p", temp_dir.path().to_str().unwrap(), "not-a-version"]);
+    assert_eq!(output.status.code(), Some(7));
+
+    let partial = TempDir::new().unwrap();
+    fs::write(partial.path().join("package.json"), "{\n  \"version\": \"1.2\"\n}\n").unwrap();
+    let output = run_uv(&["-p", partial.path().to_str().unwrap(), "-t", "package-json"]);
+    assert_eq!(output.status.code(), Some(7));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid version '1.2'"));
+}
+
+#[test]
+fn test_abort_on_mismatch_with_latest_tag() {
+    let project = create_cargo_project("1.1.0");
+    let repo = commit_all(project.path());
+    let head = repo.head().unwrap().peel_to_commit().unwrap();
+    repo.tag_lightweight("v1.0.0", head.as_object(), false).unwrap();
+    let path = project.path().to_str().unwrap();
+
+    let output = run_uv(&["-p", path, "--abort-on-mismatch", "1.2.0"]);
+    assert_eq!(output.status.code(), Some(7));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("found 1.1.0, expected 1.0.0"));
+    let content = fs::read_to_string(project.path().join("Cargo.toml")).unwrap();
+    assert!(content.contains(r#"version = "1.1.0""#));
+
+    repo.tag_lightweight("v1.1.0", head.as_object(), false).unwrap();
+    let output = run_uv(&["-p", path, "--abort-on-mismatch", "1.2.0"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+}
+
+#[test]
+fn test_git_root_commits_subdirectory_changes_to_parent_repo() {
+    let parent = TempDir::new().unwrap();
+    let app = parent.path().join("app");
+    fs::create_dir(&app).unwrap();
+    fs::write(app.join("Cargo.toml"), "[package]\nname = \"app\"\nversion = \"1.0.0\"\n").unwrap();
+    let repo = commit_all(parent.path());
+
+    let output = run_uv(&[
+        "-p",
+        app.to_str().unwrap(),
+        "--git-root",
+        parent.path().to_str().unwrap(),
+        "-g",
+        "commit",
+        "1.1.0",
+    ]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    let head = repo.head().unwrap().peel_to_commit().unwrap();
+    assert_ne!(head.message(), Some("Initial commit"));
+    let blob = head.tree().unwrap().get_path(std::path::Path::new("app/Cargo.toml")).unwrap();
+    let content = repo.find_blob(blob.id()).unwrap().content().to_vec();
+    assert!(String::from_utf8(content).unwrap().contains(r#"version = "1.1.0""#));
+}
+
+#[test]
+fn test_verbose_logs_ignore_source() {
+    let project = create_cargo_project("1.0.0");
+    fs::create_dir_all(project.path().join("vendor").join("dep")).unwrap();
+    fs::write(
+        project.path().join("vendor").join("dep").join("Cargo.toml"),
+        "[package]\nname = \"dep\"\nversion = \"0.1.0\"\n",
+    )
+    .unwrap();
+    fs::write(project.path().join(".uvignore"), "vendor/\n").unwrap();
+
+    let output = run_uv(&["-p", project.path().to_str().unwrap(), "--verbose", "--no-color", "-t", "toml", "2.0.0"]);
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Including '"), "stderr: {}", stderr);
+    let excluded = stderr.lines().find(|line| line.contains("Excluding '")).unwrap();
+    assert!(excluded.contains("dep"), "line: {}", excluded);
+    assert!(excluded.contains("ignored by a rule in .uvignore"), "line: {}", excluded);
+}
+
+#[test]
+fn test_tauri_base_version_falls_back_to_package_json() {
+    let project = TempDir::new().unwrap();
+    fs::write(project.path().join("package.json"), "{\n  \"name\": \"app\",\n  \"version\": \"1.4.0\"\n}\n").unwrap();
+    let src_tauri = project.path().join("src-tauri");
+    fs::create_dir(&src_tauri).unwrap();
+    let cargo_toml = "[package]\nname = \"app\"\nversion.workspace = true\n";
+    fs::write(src_tauri.join("Cargo.toml"), cargo_toml).unwrap();
+
+    let output = run_uv(&["-p", project.path().to_str().unwrap()]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    let package_json = fs::read_to_string(project.path().join("package.json")).unwrap();
+    assert!(package_json.contains("\"version\": \"1.4.1\""));
+    assert_eq!(fs::read_to_string(src_tauri.join("Cargo.toml")).unwrap(), cargo_toml);
+}
+
+#[test]
+fn test_quiet_git_keeps_file_logs() {
+    let project = create_cargo_project("1.0.0");
+    commit_all(project.path());
+
+    let output = run_uv(&["-p", project.path().to_str().unwrap(), "--no-color", "--quiet-git", "-g", "commit", "1.1.0"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Updated"), "stderr: {}", stderr);
+    assert!(!stderr.contains("commit"), "stderr: {}", stderr);
+
+    let output = run_uv(&["-p", project.path().to_str().unwrap(), "--quiet-git", "--verbose", "-g", "commit", "1.2.0"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Created commit"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_no_tag_prefix_tags_bare_version() {
+    let project = create_cargo_project("1.0.0");
+    let repo = commit_all(project.path());
+    let path = project.path().to_str().unwrap();
+
+    let output = run_uv(&["-p", path, "--print-tag", "--no-tag-prefix", "1.1.0"]);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "1.1.0\n");
+
+    let output = run_uv(&["-p", path, "--no-tag-prefix", "-g", "commit-tag", "1.1.0"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(repo.find_reference("refs/tags/1.1.0").is_ok());
+    assert!(repo.find_reference("refs/tags/v1.1.0").is_err());
+}
+
+#[test]
+fn test_tag_existing_commit_tags_head_with_file_version() {
+    let project = create_cargo_project("1.4.0");
+    let repo = commit_all(project.path());
+    let head = repo.head().unwrap().target().unwrap();
+
+    let output = run_uv(&["-p", project.path().to_str().unwrap(), "--tag-existing-commit", "-g", "commit-tag"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(repo.head().unwrap().target(), Some(head));
+    let tagged = repo.revparse_single("refs/tags/v1.4.0").unwrap().peel_to_commit().unwrap();
+    assert_eq!(tagged.id(), head);
+
+    let output = run_uv(&["-p", project.path().to_str().unwrap(), "--tag-existing-commit", "-g", "commit"]);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_dry_run_patch_applies_with_git() {
+    if !Command::new("git").arg("--version").output().is_ok_and(|o| o.status.success()) {
+        return;
+    }
+    let project = create_cargo_project("1.0.0");
+    fs::create_dir(project.path().join("web")).unwrap();
+    let package_json = "{\n  \"name\": \"web\",\n  \"version\": \"1.0.0\"\n}";
+    fs::write(project.path().join("web").join("package.json"), package_json).unwrap();
+
+    let output = run_uv(&["-p", project.path().to_str().unwrap(), "--dry-run", "--output", "patch", "1.1.0"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    let patch = String::from_utf8(output.stdout).unwrap();
+    assert!(patch.contains("--- a/Cargo.toml\n+++ b/Cargo.toml\n@@"), "patch: {}", patch);
+    assert!(patch.contains("--- a/web/package.json\n+++ b/web/package.json\n@@"), "patch: {}", patch);
+    assert!(fs::read_to_string(project.path().join("Cargo.toml")).unwrap().contains(r#"version = "1.0.0""#));
+
+    let mut apply = Command::new("git")
+        .args(["apply", "-"])
+        .current_dir(project.path())
+        .stdin(Stdio::piped())
+        .spawn()
+        .unwrap();
+    apply.stdin.take().unwrap().write_all(patch.as_bytes()).unwrap();
+    assert!(apply.wait().unwrap().success());
+
+    assert!(fs::read_to_string(project.path().join("Cargo.toml")).unwrap().contains(r#"version = "1.1.0""#));
+    assert_eq!(
+        fs::read_to_string(project.path().join("web").join("package.json")).unwrap(),
+        package_json.replace("1.0.0", "1.1.0")
+    );
+}
+
+#[test]
+fn test_changelog_unreleased_section_is_released() {
+    let project = create_cargo_project("1.0.0");
+    let changelog = project.path().join("CHANGELOG.md");
+    fs::write(
+        &changelog,
+        "# Changelog\n\n## [Unreleased]\n### Added\n- Shiny feature\n\n## [1.0.0] - 2023-01-01\n### Added\n- First release\n",
+    )
+    .unwrap();
+
+    let output = run_uv(&[
+        "-p",
+        project.path().to_str().unwrap(),
+        "--changelog-unreleased",
+        "--commit-date",
+        "1700000000",
+        "1.1.0",
+    ]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    assert_eq!(
+        fs::read_to_string(&changelog).unwrap(),
+        "# Changelog\n\n## [Unreleased]\n\n## [1.1.0] - 2023-11-14\n### Added\n- Shiny feature\n\n## [1.0.0] - 2023-01-01\n### Added\n- First release\n"
+    );
+}
+
+#[test]
+fn test_changelog_unreleased_requires_heading() {
+    let project = create_cargo_project("1.0.0");
+    fs::write(project.path().join("CHANGELOG.md"), "# Changelog\n\n## [1.0.0] - 2023-01-01\n").unwrap();
+
+    let output = run_uv(&["-p", project.path().to_str().unwrap(), "--changelog-unreleased", "1.1.0"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Unreleased"));
+    let content = fs::read_to_string(project.path().join("Cargo.toml")).unwrap();
+    assert!(content.contains(r#"version = "1.0.0""#));
+}
+
+#[test]
+fn test_per_type_version_overrides() {
+    let project = create_cargo_project("1.0.0");
+    let package_json = project.path().join("package.json");
+    fs::write(&package_json, "{\n  \"name\": \"web\",\n  \"version\": \"1.0.0\"\n}\n").unwrap();
+    let tauri_conf = project.path().join("tauri.conf.json");
+    fs::write(&tauri_conf, "{\n  \"version\": \"1.0.0\"\n}\n").unwrap();
+
+    let output = run_uv(&[
+        "-p",
+        project.path().to_str().unwrap(),
+        "--toml-version",
+        "1.5.0",
+        "--package-json-version",
+        "2.0.0",
+        "--verify",
+        "3.0.0",
+    ]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    let cargo_toml = fs::read_to_string(project.path().join("Cargo.toml")).unwrap();
+    assert!(cargo_toml.contains(r#"version = "1.5.0""#));
+    assert!(fs::read_to_string(&package_json).unwrap().contains(r#""version": "2.0.0""#));
+    assert!(fs::read_to_string(&tauri_conf).unwrap().contains(r#""version": "3.0.0""#));
+}
+
+#[test]
+fn test_print_files_json_lists_updated_files() {
+    let project = create_cargo_project("1.0.0");
+    fs::create_dir(project.path().join("web")).unwrap();
+    fs::write(project.path().join("web").join("package.json"), "{\n  \"version\": \"1.0.0\"\n}\n").unwrap();
+    fs::write(project.path().join("notes.txt"), "version 1.0.0\n").unwrap();
+
+    let output = run_uv(&["-p", project.path().to_str().unwrap(), "--print-files-json", "1.1.0"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    let files: Vec<String> = serde_json::from_slice(&output.stdout).unwrap();
+    let mut files: Vec<std::path::PathBuf> =
+        files.iter().map(|f| fs::canonicalize(f).unwrap()).collect();
+    files.sort();
+    let root = fs::canonicalize(project.path()).unwrap();
+    assert_eq!(files, vec![root.join("Cargo.toml"), root.join("web").join("package.json")]);
+}
+
+#[test]
+fn test_output_json_distinguishes_noop_from_bump() {
+    let project = create_cargo_project("1.0.0");
+    let repo = commit_all(project.path());
+    let path = project.path().to_str().unwrap();
+
+    let output = run_uv(&["-p", path, "--output", "json", "-g", "commit-tag", "1.1.0"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["changed"], true);
+    assert_eq!(report["previous"], "1.0.0");
+    assert_eq!(report["version"], "1.1.0");
+    assert_eq!(report["files"].as_array().unwrap().len(), 1);
+    assert_eq!(report["tag"], "v1.1.0");
+    let head = repo.head().unwrap().target().unwrap().to_string();
+    assert_eq!(report["commit"], head.as_str());
+
+    let output = run_uv(&["-p", path, "--output", "json", "-g", "commit-tag", "1.1.0"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["changed"], false);
+    assert_eq!(report["previous"], "1.1.0");
+    assert_eq!(report["version"], "1.1.0");
+    assert_eq!(report["files"], serde_json::json!([]));
+    assert!(report["commit"].is_null());
+    assert!(report["tag"].is_null());
+}
+
+#[test]
+fn test_print_report_schema() {
+    let output = run_uv(&["--print-report-schema"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    let schema: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(schema["type"], "object");
+    for property in ["path", "changed", "previous", "version", "files", "commit", "tag"] {
+        assert!(schema["properties"].get(property).is_some(), "missing property {property}");
+    }
+    assert_eq!(schema["properties"]["changed"]["type"], "boolean");
+
+    // The `--output json` report has exactly the schema's properties
+    let project = create_cargo_project("1.0.0");
+    let output = run_uv(&["-p", project.path().to_str().unwrap(), "--output", "json", "1.1.0"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let mut keys: Vec<&String> = report.as_object().unwrap().keys().collect();
+    let mut properties: Vec<&String> = schema["properties"].as_object().unwrap().keys().collect();
+    keys.sort();
+    properties.sort();
+    assert_eq!(keys, properties);
+}
+
+#[test]
+fn test_assert_consistent_exits_before_updating() {
+    let project = create_cargo_project("1.2.0");
+    fs::create_dir(project.path().join("core")).unwrap();
+    let member = "[package]\nname = \"core\"\nversion = \"1.1.0\"\n";
+    fs::write(project.path().join("core").join("Cargo.toml"), member).unwrap();
+
+    let output = run_uv(&["-p", project.path().to_str().unwrap(), "--assert-consistent", "1.3.0"]);
+    assert_eq!(output.status.code(), Some(7));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("different versions"));
+    assert_eq!(fs::read_to_string(project.path().join("core").join("Cargo.toml")).unwrap(), member);
+}
+
+#[test]
+fn test_flutter_bump_only_touches_pubspec() {
+    let project = create_cargo_project("1.2.3");
+    let path = project.path().to_str().unwrap();
+    let pubspec = project.path().join("pubspec.yaml");
+    fs::write(&pubspec, "name: app\nversion: 1.2.3+45\n").unwrap();
+
+    // Under `all` the current version would come from Cargo.toml, losing the build number
+    let output = run_uv(&["-p", path, "--flutter-bump"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--types pubspec"));
+
+    let output = run_uv(&["-p", path, "--types", "pubspec", "--flutter-bump", "--bump", "prerelease", "--prerelease-label", "beta"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(fs::read_to_string(&pubspec).unwrap(), "name: app\nversion: 1.2.4-beta.0+46\n");
+    assert!(fs::read_to_string(project.path().join("Cargo.toml")).unwrap().contains("version = \"1.2.3\""));
+}
+
+#[test]
+fn test_noop_still_inserts_missing_versions() {
+    let project = create_cargo_project("1.2.3");
+    let path = project.path().to_str().unwrap();
+    let package_json = project.path().join("package.json");
+    fs::write(&package_json, "{\n  \"name\": \"web\"\n}\n").unwrap();
+
+    let output = run_uv(&["-p", path, "--insert-missing", "1.2.3"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("nothing to do"));
+    assert!(fs::read_to_string(&package_json).unwrap().contains("\"version\": \"1.2.3\""));
+
+    let output = run_uv(&["-p", path, "--insert-missing", "1.2.3"]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("nothing to do"));
+}
+
+#[test]
+fn test_noop_still_releases_changelog() {
+    let project = create_cargo_project("1.2.3");
+    let path = project.path().to_str().unwrap();
+    let changelog = project.path().join("CHANGELOG.md");
+    fs::write(&changelog, "# Changelog\n\n## [Unreleased]\n### Added\n- Shiny feature\n").unwrap();
+
+    let output = run_uv(&["-p", path, "--changelog-unreleased", "--commit-date", "1700000000", "1.2.3"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    let released = "# Changelog\n\n## [Unreleased]\n\n## [1.2.3] - 2023-11-14\n### Added\n- Shiny feature\n";
+    assert_eq!(fs::read_to_string(&changelog).unwrap(), released);
+
+    // Once 1.2.3 has a heading, a rerun has nothing left to release
+    let output = run_uv(&["-p", path, "--changelog-unreleased", "1.2.3"]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("nothing to do"));
+    assert_eq!(fs::read_to_string(&changelog).unwrap(), released);
+}
+
+#[test]
+fn test_noop_still_updates_internal_deps() {
+    let project = TempDir::new().unwrap();
+    let path = project.path().to_str().unwrap();
+    let app = "[package]\nname = \"app\"\nversion = \"1.2.3\"\n\n[dependencies]\ncore = { path = \"core\", version = \"1.0.0\" }\n";
+    fs::write(project.path().join("Cargo.toml"), app).unwrap();
+    fs::create_dir(project.path().join("core")).unwrap();
+    fs::write(project.path().join("core").join("Cargo.toml"), "[package]\nname = \"core\"\nversion = \"1.2.3\"\n").unwrap();
+
+    let output = run_uv(&["-p", path, "--update-internal-deps", "1.2.3"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(fs::read_to_string(project.path().join("Cargo.toml")).unwrap().contains("version = \"1.2.3\" }"));
+
+    let output = run_uv(&["-p", path, "--update-internal-deps", "1.2.3"]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("nothing to do"));
+}
+
+#[test]
+fn test_noop_still_writes_version_file() {
+    let project = create_cargo_project("1.2.3");
+    let path = project.path().to_str().unwrap();
+    let target = project.path().join("VERSION");
+
+    let output = run_uv(&["-p", path, "--write-version-to", target.to_str().unwrap(), "1.2.3"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(fs::read_to_string(&target).unwrap(), "1.2.3");
+
+    let output = run_uv(&["-p", path, "--write-version-to", target.to_str().unwrap(), "1.2.3"]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("nothing to do"));
+}
+
+#[test]
+fn test_default_types_leave_opt_in_files_alone() {
+    let project = create_cargo_project("1.2.3");
+    let vendored = project.path().join("vendor").join("zlib");
+    fs::create_dir_all(&vendored).unwrap();
+    let cmake = "cmake_minimum_required(VERSION 3.10)\nproject(zlib VERSION 1.3.1)\n";
+    fs::write(vendored.join("CMakeLists.txt"), cmake).unwrap();
+
+    let output = run_uv(&["-p", project.path().to_str().unwrap()]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(fs::read_to_string(project.path().join("Cargo.toml")).unwrap().contains("version = \"1.2.4\""));
+    assert_eq!(fs::read_to_string(vendored.join("CMakeLists.txt")).unwrap(), cmake);
+}
@@ -2,7 +2,11 @@
 
 use std::fs;
 use tempfile::TempDir;
-use update_version::{arguments::GitMode, git::GitTracker};
+use update_version::{
+    arguments::{GitMode, PrereleaseFallback, SupportedTypes},
+    git::{GitOptions, GitTracker, VersionCheck, branch_refspec, parse_author},
+    parsers::WalkOptions,
+};
 
 /// Helper to create a temporary git repository
 fn create_test_repo() -> TempDir {
@@ -211,6 +215,21 @@ fn test_execute_git_mode_commit_tag() {
     assert!(tags.contains(&"v2.0.0".to_string()));
 }
 
+#[test]
+fn test_tag_existing_commit_tags_head_without_committing() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    tracker.options = GitOptions { tag_existing_commit: true, ..Default::default() };
+    let head = tracker.repository.head().unwrap().target().unwrap();
+
+    let release = tracker.execute_git_mode(GitMode::CommitTag, "2.0.0", &[]).unwrap();
+    assert_eq!(tracker.repository.head().unwrap().target(), Some(head));
+    assert_eq!(release.commit, Some(head.to_string()));
+    assert_eq!(release.tag.as_deref(), Some("v2.0.0"));
+    let tagged = tracker.repository.revparse_single("refs/tags/v2.0.0").unwrap().peel_to_commit().unwrap();
+    assert_eq!(tagged.id(), head);
+}
+
 #[test]
 fn test_execute_git_mode_no_changes() {
     let temp_dir = create_test_repo();
@@ -251,3 +270,827 @@ fn test_duplicate_tag_fails() {
     let result = tracker.create_tag("v1.0.0", commit_id2);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_get_version_tags_sorted_by_semver() {
+    let temp_dir = create_test_repo();
+    let tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+
+    let head = tracker.repository.head().unwrap().target().unwrap();
+    for tag in ["v1.0.0", "v2.0.0", "v1.10.0", "v1.2.0", "not-a-version"] {
+        tracker.create_tag(tag, head).unwrap();
+    }
+
+    let tags = tracker.get_version_tags("v").unwrap();
+    let names: Vec<&str> = tags.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["v1.0.0", "v1.2.0", "v1.10.0", "v2.0.0"]);
+    assert_eq!(tags[2].1, semver::Version::parse("1.10.0").unwrap());
+}
+
+/// Helper to detach HEAD at the current commit
+fn detach_head(tracker: &GitTracker) {
+    let head = tracker.repository.head().unwrap().target().unwrap();
+    tracker.repository.set_head_detached(head).unwrap();
+}
+
+#[test]
+fn test_detached_head_push_requires_branch() {
+    let temp_dir = create_test_repo();
+    let tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    detach_head(&tracker);
+    assert!(tracker.is_head_detached().unwrap());
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+
+    let err = tracker
+        .execute_git_mode(GitMode::CommitPush, "1.0.0", &[file])
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("detached"), "error: {err}");
+    assert!(err.contains("--branch"), "error: {err}");
+
+    // Nothing was committed
+    let commit = tracker.repository.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(commit.message(), Some("Initial commit"));
+}
+
+#[test]
+fn test_detached_head_commit_still_works() {
+    let temp_dir = create_test_repo();
+    let tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    detach_head(&tracker);
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+
+    tracker.execute_git_mode(GitMode::CommitTag, "1.0.0", &[file]).unwrap();
+
+    let commit = tracker.repository.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(commit.message(), Some("chore: bump version to 1.0.0"));
+    assert!(tracker.get_tags().unwrap().contains(&"v1.0.0".to_string()));
+}
+
+/// Helper to create a bare repository and register it as `name` on `tracker`
+fn add_bare_remote(tracker: &GitTracker, name: &str) -> TempDir {
+    let remote_dir = TempDir::new().unwrap();
+    git2::Repository::init_bare(remote_dir.path()).unwrap();
+    tracker.repository.remote(name, remote_dir.path().to_str().unwrap()).unwrap();
+    remote_dir
+}
+
+#[test]
+fn test_push_to_multiple_remotes() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    let github = add_bare_remote(&tracker, "github");
+    let gitlab = add_bare_remote(&tracker, "gitlab");
+    tracker.options = GitOptions {
+        remotes: vec!["github".to_string(), "gitlab".to_string()],
+        ..Default::default()
+    };
+    let branch = tracker.current_branch().unwrap();
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    tracker.execute_git_mode(GitMode::CommitPushTag, "1.0.0", &[file]).unwrap();
+
+    let head = tracker.repository.head().unwrap().target().unwrap();
+    for remote_dir in [&github, &gitlab] {
+        let remote = git2::Repository::open_bare(remote_dir.path()).unwrap();
+        let pushed = remote.find_reference(&format!("refs/heads/{}", branch)).unwrap();
+        assert_eq!(pushed.target(), Some(head));
+        assert!(remote.find_reference("refs/tags/v1.0.0").is_ok());
+    }
+}
+
+#[test]
+fn test_branch_refspec_maps_local_to_remote_branch() {
+    assert_eq!(branch_refspec("main", "main"), "refs/heads/main:refs/heads/main");
+    assert_eq!(branch_refspec("develop", "release"), "refs/heads/develop:refs/heads/release");
+}
+
+#[test]
+fn test_push_branch_pushes_to_differently_named_remote_branch() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    let remote_dir = add_bare_remote(&tracker, "origin");
+    tracker.options = GitOptions { push_branch: Some("release".to_string()), ..Default::default() };
+    let branch = tracker.current_branch().unwrap();
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    tracker.execute_git_mode(GitMode::CommitPush, "1.0.0", &[file]).unwrap();
+
+    let head = tracker.repository.head().unwrap().target().unwrap();
+    let remote = git2::Repository::open_bare(remote_dir.path()).unwrap();
+    let pushed = remote.find_reference("refs/heads/release").unwrap();
+    assert_eq!(pushed.target(), Some(head));
+    assert!(remote.find_reference(&format!("refs/heads/{}", branch)).is_err());
+}
+
+#[test]
+fn test_branch_pushes_head_from_attached_branch() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    let remote_dir = add_bare_remote(&tracker, "origin");
+    tracker.options = GitOptions { branch: Some("release".to_string()), ..Default::default() };
+    assert!(!tracker.is_head_detached().unwrap());
+
+    // There is no local `release` branch; the new commit on the current branch is what gets pushed
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    tracker.execute_git_mode(GitMode::CommitPush, "1.0.0", &[file]).unwrap();
+
+    let head = tracker.repository.head().unwrap().target().unwrap();
+    let remote = git2::Repository::open_bare(remote_dir.path()).unwrap();
+    assert_eq!(remote.find_reference("refs/heads/release").unwrap().target(), Some(head));
+}
+
+#[test]
+fn test_push_failure_on_one_remote_still_pushes_others() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    let missing = temp_dir.path().join("does-not-exist");
+    tracker.repository.remote("broken", missing.to_str().unwrap()).unwrap();
+    let mirror = add_bare_remote(&tracker, "mirror");
+    tracker.options = GitOptions {
+        remotes: vec!["broken".to_string(), "mirror".to_string()],
+        ..Default::default()
+    };
+    let branch = tracker.current_branch().unwrap();
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    let err = tracker
+        .execute_git_mode(GitMode::CommitPush, "1.0.0", &[file])
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("broken"), "error: {err}");
+    assert!(!err.contains("mirror"), "error: {err}");
+
+    let remote = git2::Repository::open_bare(mirror.path()).unwrap();
+    assert!(remote.find_reference(&format!("refs/heads/{}", branch)).is_ok());
+}
+
+#[test]
+fn test_existing_tag_aborts_before_commit() {
+    let temp_dir = create_test_repo();
+    let tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    let initial = tracker.repository.head().unwrap().target().unwrap();
+    tracker.create_tag("v1.0.0", initial).unwrap();
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+
+    let err = tracker
+        .execute_git_mode(GitMode::CommitTag, "1.0.0", &[file])
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("v1.0.0"), "error: {err}");
+    assert!(err.contains("already exists"), "error: {err}");
+
+    // No commit was created and nothing was staged
+    assert_eq!(tracker.repository.head().unwrap().target(), Some(initial));
+    let head_tree = tracker.repository.head().unwrap().peel_to_tree().unwrap();
+    let diff = tracker
+        .repository
+        .diff_tree_to_index(Some(&head_tree), None, None)
+        .unwrap();
+    assert_eq!(diff.deltas().len(), 0);
+}
+
+#[test]
+fn test_force_tag_moves_existing_tag() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    let initial = tracker.repository.head().unwrap().target().unwrap();
+    tracker.create_tag("v1.0.0", initial).unwrap();
+    tracker.options = GitOptions { force_tag: true, ..Default::default() };
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    tracker.execute_git_mode(GitMode::CommitTag, "1.0.0", &[file]).unwrap();
+
+    let head = tracker.repository.head().unwrap().target().unwrap();
+    assert_ne!(head, initial);
+    let tagged = tracker
+        .repository
+        .revparse_single("v1.0.0^{commit}")
+        .unwrap()
+        .id();
+    assert_eq!(tagged, head);
+}
+
+#[test]
+fn test_author_override_without_git_config() {
+    let temp_dir = TempDir::new().unwrap();
+    git2::Repository::init(temp_dir.path()).unwrap();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    tracker.options = GitOptions {
+        author: Some("Release Bot <release@example.com>".to_string()),
+        ..Default::default()
+    };
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    tracker.execute_git_mode(GitMode::CommitTag, "1.0.0", &[file]).unwrap();
+
+    let commit = tracker.repository.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(commit.author().name(), Some("Release Bot"));
+    assert_eq!(commit.author().email(), Some("release@example.com"));
+    assert_eq!(commit.committer().name(), Some("Release Bot"));
+
+    let tag = tracker
+        .repository
+        .revparse_single("v1.0.0")
+        .unwrap()
+        .peel_to_tag()
+        .unwrap();
+    assert_eq!(tag.tagger().unwrap().name(), Some("Release Bot"));
+}
+
+#[test]
+fn test_parse_author() {
+    assert_eq!(
+        parse_author("Jane Doe <jane@example.com>").unwrap(),
+        ("Jane Doe".to_string(), "jane@example.com".to_string())
+    );
+    assert!(parse_author("Jane Doe").is_err());
+    assert!(parse_author("<jane@example.com>").is_err());
+}
+
+/// Helper to clone `remote_dir` and push one extra commit to `branch` from the clone
+fn push_commit_from_other_clone(remote_dir: &TempDir, branch: &str) -> git2::Oid {
+    let clone_dir = TempDir::new().unwrap();
+    let clone = git2::Repository::clone(remote_dir.path().to_str().unwrap(), clone_dir.path()).unwrap();
+    let mut config = clone.config().unwrap();
+    config.set_str("user.name", "Other User").unwrap();
+    config.set_str("user.email", "other@example.com").unwrap();
+
+    let tracker = GitTracker::open(clone_dir.path(), false).unwrap();
+    fs::write(clone_dir.path().join("other.txt"), "other").unwrap();
+    tracker.stage_all().unwrap();
+    let commit_id = tracker.create_commit("other release work").unwrap();
+    tracker.push_commits("origin", branch, branch).unwrap();
+    commit_id
+}
+
+#[test]
+fn test_pull_before_push_fast_forwards() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    let remote_dir = add_bare_remote(&tracker, "origin");
+    let branch = tracker.current_branch().unwrap();
+    tracker.push_commits("origin", &branch, &branch).unwrap();
+    let other_commit = push_commit_from_other_clone(&remote_dir, &branch);
+
+    tracker.options = GitOptions { pull_before_push: true, ..Default::default() };
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    tracker.execute_git_mode(GitMode::CommitPush, "1.0.0", &[file]).unwrap();
+
+    let head = tracker.repository.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(head.message(), Some("chore: bump version to 1.0.0"));
+    assert_eq!(head.parent_id(0).unwrap(), other_commit);
+    assert!(temp_dir.path().join("other.txt").exists());
+
+    let remote = git2::Repository::open_bare(remote_dir.path()).unwrap();
+    let pushed = remote.find_reference(&format!("refs/heads/{}", branch)).unwrap();
+    assert_eq!(pushed.target(), Some(head.id()));
+}
+
+#[test]
+fn test_pull_before_push_fails_when_diverged() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    let remote_dir = add_bare_remote(&tracker, "origin");
+    let branch = tracker.current_branch().unwrap();
+    tracker.push_commits("origin", &branch, &branch).unwrap();
+    push_commit_from_other_clone(&remote_dir, &branch);
+
+    // A local commit the remote doesn't have
+    fs::write(temp_dir.path().join("local.txt"), "local").unwrap();
+    tracker.stage_all().unwrap();
+    let local_commit = tracker.create_commit("local work").unwrap();
+
+    tracker.options = GitOptions { pull_before_push: true, ..Default::default() };
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    let err = format!(
+        "{:#}",
+        tracker.execute_git_mode(GitMode::CommitPush, "1.0.0", &[file]).unwrap_err()
+    );
+    assert!(err.contains("diverged"), "error: {err}");
+    assert_eq!(tracker.repository.head().unwrap().target(), Some(local_commit));
+}
+
+#[test]
+fn test_detailed_commit_lists_changed_files() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    tracker.options = GitOptions { detailed_commit: true, ..Default::default() };
+
+    let nested = temp_dir.path().join("crates").join("core");
+    fs::create_dir_all(&nested).unwrap();
+    let files = vec![temp_dir.path().join("version.txt"), nested.join("version.txt")];
+    for file in &files {
+        fs::write(file, "1.0.0").unwrap();
+    }
+    tracker.execute_git_mode(GitMode::Commit, "1.0.0", &files).unwrap();
+
+    let commit = tracker.repository.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(commit.summary(), Some("chore: bump version to 1.0.0"));
+    let body = commit.body().unwrap();
+    assert!(body.contains("- version.txt"), "body: {body}");
+    assert!(body.contains("- crates/core/version.txt"), "body: {body}");
+}
+
+#[test]
+fn test_get_tags_with_prefix_filters_and_strips() {
+    let temp_dir = create_test_repo();
+    let tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+
+    let head = tracker.repository.head().unwrap().target().unwrap();
+    for tag in ["v1.0.0", "release/1.1.0", "release/2.0.0", "nightly-2024"] {
+        tracker.create_tag(tag, head).unwrap();
+    }
+
+    let mut tags = tracker.get_tags_with_prefix("release/").unwrap();
+    tags.sort();
+    assert_eq!(tags, ["1.1.0", "2.0.0"]);
+    assert_eq!(tracker.get_tags_with_prefix("v").unwrap(), ["1.0.0"]);
+}
+
+#[test]
+fn test_tag_format_applied_to_created_tag() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    tracker.options = GitOptions { tag_format: Some("release/{version}".to_string()), ..Default::default() };
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    tracker.execute_git_mode(GitMode::CommitTag, "1.0.0", &[file]).unwrap();
+
+    assert_eq!(tracker.get_tags().unwrap(), ["release/1.0.0"]);
+}
+
+#[test]
+fn test_dry_run_creates_no_commit_or_tag() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    let _remote = add_bare_remote(&tracker, "origin");
+    tracker.options = GitOptions { dry_run: true, ..Default::default() };
+    let head_before = tracker.repository.head().unwrap().target().unwrap();
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    tracker.execute_git_mode(GitMode::CommitPushTag, "1.0.0", &[file]).unwrap();
+
+    assert_eq!(tracker.repository.head().unwrap().target().unwrap(), head_before);
+    assert!(tracker.get_tags().unwrap().is_empty());
+    // Nothing is staged either
+    let status = tracker.repository.status_file(std::path::Path::new("version.txt")).unwrap();
+    assert!(!status.is_index_new());
+}
+
+#[test]
+fn test_rollback_on_push_failure_reverts_commit_and_tag() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    // A remote that doesn't exist makes every push fail
+    let missing = temp_dir.path().join("no-such-remote");
+    tracker.repository.remote("origin", missing.to_str().unwrap()).unwrap();
+    tracker.options = GitOptions { rollback_on_push_failure: true, ..Default::default() };
+    let head_before = tracker.repository.head().unwrap().target().unwrap();
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    let result = tracker.execute_git_mode(GitMode::CommitPushTag, "1.0.0", std::slice::from_ref(&file));
+
+    assert!(result.is_err());
+    assert_eq!(tracker.repository.head().unwrap().target().unwrap(), head_before);
+    assert!(tracker.get_tags().unwrap().is_empty());
+    // The version change is still in the working tree, ready for a retry
+    assert_eq!(fs::read_to_string(&file).unwrap(), "1.0.0");
+}
+
+#[test]
+fn test_rollback_skipped_when_another_remote_accepted_the_push() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    let mirror = add_bare_remote(&tracker, "mirror");
+    let missing = temp_dir.path().join("no-such-remote");
+    tracker.repository.remote("broken", missing.to_str().unwrap()).unwrap();
+    tracker.options = GitOptions {
+        remotes: vec!["mirror".to_string(), "broken".to_string()],
+        rollback_on_push_failure: true,
+        ..Default::default()
+    };
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    let err = tracker.execute_git_mode(GitMode::CommitPushTag, "1.0.0", &[file]).unwrap_err();
+    assert!(err.to_string().contains("broken"), "error: {err}");
+
+    // The local release stays in step with the mirror that took it
+    let head = tracker.repository.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(head.message(), Some("chore: bump version to 1.0.0"));
+    assert_eq!(tracker.get_tags().unwrap(), ["v1.0.0"]);
+    let remote = git2::Repository::open_bare(mirror.path()).unwrap();
+    assert!(remote.find_reference("refs/tags/v1.0.0").is_ok());
+}
+
+#[test]
+fn test_push_failure_without_rollback_keeps_commit_and_tag() {
+    let temp_dir = create_test_repo();
+    let tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    let missing = temp_dir.path().join("no-such-remote");
+    tracker.repository.remote("origin", missing.to_str().unwrap()).unwrap();
+    let head_before = tracker.repository.head().unwrap().target().unwrap();
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    assert!(tracker.execute_git_mode(GitMode::CommitPushTag, "1.0.0", &[file]).is_err());
+
+    assert_ne!(tracker.repository.head().unwrap().target().unwrap(), head_before);
+    assert_eq!(tracker.get_tags().unwrap(), ["v1.0.0"]);
+}
+
+#[test]
+fn test_amend_folds_changes_into_initial_commit() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    tracker.options = GitOptions { amend: true, ..Default::default() };
+    let original = tracker.repository.head().unwrap().peel_to_commit().unwrap().id();
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    tracker.execute_git_mode(GitMode::CommitTag, "1.0.0", &[file]).unwrap();
+
+    let head = tracker.repository.head().unwrap().peel_to_commit().unwrap();
+    assert_ne!(head.id(), original);
+    assert_eq!(head.message(), Some("Initial commit"));
+    assert_eq!(head.parent_count(), 0);
+    assert!(head.tree().unwrap().get_name("version.txt").is_some());
+    assert!(head.tree().unwrap().get_name("README.md").is_some());
+
+    let tag = tracker.repository.revparse_single("refs/tags/v1.0.0").unwrap().peel_to_commit().unwrap();
+    assert_eq!(tag.id(), head.id());
+}
+
+#[test]
+fn test_amend_refused_when_head_is_pushed() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    let _remote = add_bare_remote(&tracker, "origin");
+    let branch = tracker.current_branch().unwrap();
+    tracker.push_commits("origin", &branch, &branch).unwrap();
+    tracker.options = GitOptions { amend: true, ..Default::default() };
+    let original = tracker.repository.head().unwrap().target().unwrap();
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    let err = tracker.execute_git_mode(GitMode::Commit, "1.0.0", &[file]).unwrap_err();
+
+    assert!(err.to_string().contains("already been pushed"));
+    assert_eq!(tracker.repository.head().unwrap().target().unwrap(), original);
+}
+
+/// Commits a change to `name` with `message` and returns the commit id
+fn commit_file(tracker: &GitTracker, dir: &TempDir, name: &str, message: &str) -> git2::Oid {
+    let file = dir.path().join(name);
+    fs::write(&file, message).unwrap();
+    tracker.stage_files(&[file]).unwrap();
+    tracker.create_commit(message).unwrap()
+}
+
+#[test]
+fn test_commit_range_respects_since_tag() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    let head = tracker.repository.head().unwrap().target().unwrap();
+    tracker.create_tag("v1.0.0", head).unwrap();
+    let second = commit_file(&tracker, &temp_dir, "a.txt", "feat: second");
+    tracker.create_tag("v1.1.0", second).unwrap();
+    commit_file(&tracker, &temp_dir, "b.txt", "fix: third");
+
+    // Defaults to the latest version tag
+    assert_eq!(tracker.latest_version_tag().unwrap().as_deref(), Some("v1.1.0"));
+    assert_eq!(tracker.commit_messages_since_tag().unwrap(), ["fix: third"]);
+
+    tracker.options = GitOptions { since_tag: Some("v1.0.0".to_string()), ..Default::default() };
+    assert_eq!(tracker.commit_messages_since_tag().unwrap(), ["fix: third", "feat: second"]);
+
+    tracker.options = GitOptions { since_tag: Some("v0.9.0".to_string()), ..Default::default() };
+    let err = tracker.commit_messages_since_tag().unwrap_err();
+    assert!(err.to_string().contains("'v0.9.0'"));
+}
+
+#[test]
+fn test_release_branch_guard_skips_tag_and_push() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    let remote_dir = add_bare_remote(&tracker, "origin");
+    let head = tracker.repository.head().unwrap().peel_to_commit().unwrap();
+    tracker.repository.branch("feature", &head, false).unwrap();
+    tracker.repository.set_head("refs/heads/feature").unwrap();
+    tracker.options = GitOptions { release_branch: Some("main".to_string()), ..Default::default() };
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    tracker.execute_git_mode(GitMode::CommitPushTag, "1.0.0", &[file]).unwrap();
+
+    // The commit is still made locally
+    assert_ne!(tracker.repository.head().unwrap().target().unwrap(), head.id());
+    assert!(tracker.get_tags().unwrap().is_empty());
+    let remote = git2::Repository::open_bare(remote_dir.path()).unwrap();
+    assert!(remote.references().unwrap().next().is_none());
+}
+
+/// Whether a `git` executable is available for the `--use-git-cli` tests
+fn git_cli_available() -> bool {
+    std::process::Command::new("git").arg("--version").output().is_ok_and(|o| o.status.success())
+}
+
+/// Installs a pre-commit hook that leaves a marker file behind when it runs
+#[cfg(unix)]
+fn install_marker_hook(dir: &TempDir) -> std::path::PathBuf {
+    use std::os::unix::fs::PermissionsExt;
+    let marker = dir.path().join("hook-ran");
+    let hook = dir.path().join(".git").join("hooks").join("pre-commit");
+    fs::create_dir_all(hook.parent().unwrap()).unwrap();
+    fs::write(&hook, format!("#!/bin/sh\ntouch '{}'\n", marker.display())).unwrap();
+    fs::set_permissions(&hook, fs::Permissions::from_mode(0o755)).unwrap();
+    marker
+}
+
+#[cfg(unix)]
+#[test]
+fn test_git_cli_commit_runs_hooks() {
+    if !git_cli_available() {
+        return;
+    }
+    let temp_dir = create_test_repo();
+    let marker = install_marker_hook(&temp_dir);
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    tracker.options = GitOptions { use_git_cli: true, ..Default::default() };
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    tracker.execute_git_mode(GitMode::CommitTag, "1.0.0", &[file]).unwrap();
+
+    assert!(marker.exists());
+    let head = tracker.repository.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(head.message().map(str::trim), Some("chore: bump version to 1.0.0"));
+    let tag = tracker.repository.revparse_single("refs/tags/v1.0.0").unwrap().peel_to_commit().unwrap();
+    assert_eq!(tag.id(), head.id());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_git_cli_no_verify_skips_hooks() {
+    if !git_cli_available() {
+        return;
+    }
+    let temp_dir = create_test_repo();
+    let marker = install_marker_hook(&temp_dir);
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    tracker.options = GitOptions { use_git_cli: true, no_verify: true, ..Default::default() };
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    tracker.execute_git_mode(GitMode::Commit, "1.0.0", &[file]).unwrap();
+
+    assert!(!marker.exists());
+    let head = tracker.repository.head().unwrap().peel_to_commit().unwrap();
+    assert!(head.tree().unwrap().get_name("version.txt").is_some());
+}
+
+#[test]
+fn test_tag_aborts_when_files_hold_another_version() {
+    let temp_dir = create_test_repo();
+    let cargo_toml = temp_dir.path().join("Cargo.toml");
+    fs::write(&cargo_toml, "[package]\nname = \"app\"\nversion = \"1.0.0\"\n").unwrap();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    tracker.options = GitOptions {
+        version_check: Some(VersionCheck {
+            path: temp_dir.path().to_path_buf(),
+            types: SupportedTypes::TOML,
+            options: WalkOptions::default(),
+        }),
+        ..Default::default()
+    };
+    let head_before = tracker.repository.head().unwrap().target().unwrap();
+
+    let err = tracker.execute_git_mode(GitMode::CommitTag, "2.0.0", std::slice::from_ref(&cargo_toml)).unwrap_err();
+
+    assert!(err.to_string().contains("found 1.0.0, expected 2.0.0"), "{err}");
+    assert_eq!(tracker.repository.head().unwrap().target().unwrap(), head_before);
+    assert!(tracker.get_tags().unwrap().is_empty());
+
+    // Once the files match, the tag goes ahead
+    tracker.execute_git_mode(GitMode::CommitTag, "1.0.0", &[cargo_toml]).unwrap();
+    assert_eq!(tracker.get_tags().unwrap(), ["v1.0.0"]);
+}
+
+#[test]
+fn test_tag_check_allows_prerelease_dropped_by_parser() {
+    let temp_dir = create_test_repo();
+    let cmake = temp_dir.path().join("CMakeLists.txt");
+    fs::write(&cmake, "project(app VERSION 1.3.0)\n").unwrap();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    tracker.options = GitOptions {
+        version_check: Some(VersionCheck {
+            path: temp_dir.path().to_path_buf(),
+            types: SupportedTypes::CMake,
+            options: WalkOptions::default(),
+        }),
+        ..Default::default()
+    };
+
+    tracker.execute_git_mode(GitMode::CommitTag, "1.3.0-rc.1", &[cmake]).unwrap();
+    assert_eq!(tracker.get_tags().unwrap(), ["v1.3.0-rc.1"]);
+}
+
+#[test]
+fn test_sign_without_key_aborts_before_commit() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    if tracker.repository.config().unwrap().get_string("user.signingkey").is_ok() {
+        // A globally configured key would make the preflight pass
+        return;
+    }
+    tracker.options = GitOptions { sign: true, ..Default::default() };
+    let head_before = tracker.repository.head().unwrap().target().unwrap();
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    let err = tracker.execute_git_mode(GitMode::CommitTag, "1.0.0", &[file]).unwrap_err();
+
+    assert!(err.to_string().contains("user.signingkey"));
+    assert_eq!(tracker.repository.head().unwrap().target().unwrap(), head_before);
+    assert!(tracker.get_tags().unwrap().is_empty());
+}
+
+#[test]
+fn test_commit_range_first_parent_skips_merged_branch() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    let repo = &tracker.repository;
+    let base = repo.head().unwrap().peel_to_commit().unwrap();
+    tracker.create_tag("v1.0.0", base.id()).unwrap();
+
+    // A side branch off the tag, committed without moving HEAD
+    let sig = repo.signature().unwrap();
+    let mut builder = repo.treebuilder(Some(&base.tree().unwrap())).unwrap();
+    builder.insert("side.txt", repo.blob(b"side").unwrap(), 0o100644).unwrap();
+    let side_tree = repo.find_tree(builder.write().unwrap()).unwrap();
+    let side = repo.commit(None, &sig, &sig, "feat: side", &side_tree, &[&base]).unwrap();
+
+    let mainline = commit_file(&tracker, &temp_dir, "main.txt", "fix: mainline");
+    let repo = &tracker.repository;
+    let mainline = repo.find_commit(mainline).unwrap();
+    let mut builder = repo.treebuilder(Some(&mainline.tree().unwrap())).unwrap();
+    builder.insert("side.txt", repo.blob(b"side").unwrap(), 0o100644).unwrap();
+    let merge_tree = repo.find_tree(builder.write().unwrap()).unwrap();
+    let side = repo.find_commit(side).unwrap();
+    repo.commit(Some("HEAD"), &sig, &sig, "Merge branch 'side'", &merge_tree, &[&mainline, &side]).unwrap();
+
+    let mut messages = tracker.commit_messages_since_tag().unwrap();
+    messages.sort();
+    assert_eq!(messages, ["Merge branch 'side'", "feat: side", "fix: mainline"]);
+
+    tracker.options = GitOptions { first_parent: true, ..Default::default() };
+    assert_eq!(tracker.commit_messages_since_tag().unwrap(), ["Merge branch 'side'", "fix: mainline"]);
+}
+
+#[test]
+fn test_latest_version_tag_excludes_prereleases() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    let head = tracker.repository.head().unwrap().target().unwrap();
+    tracker.create_tag("v1.2.0", head).unwrap();
+    let second = commit_file(&tracker, &temp_dir, "a.txt", "feat: second");
+    tracker.create_tag("v1.3.0-rc.1", second).unwrap();
+    let third = commit_file(&tracker, &temp_dir, "b.txt", "fix: third");
+    tracker.create_tag("v1.3.0-rc.2", third).unwrap();
+
+    assert_eq!(tracker.latest_version_tag().unwrap().as_deref(), Some("v1.3.0-rc.2"));
+
+    tracker.options = GitOptions { exclude_prerelease: true, ..Default::default() };
+    assert_eq!(tracker.latest_version_tag().unwrap().as_deref(), Some("v1.2.0"));
+    assert_eq!(tracker.commit_messages_since_tag().unwrap(), ["fix: third", "feat: second"]);
+}
+
+#[test]
+fn test_latest_version_tag_strips_tag_format_suffix() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    let head = tracker.repository.head().unwrap().target().unwrap();
+    tracker.create_tag("v1.2.0-release", head).unwrap();
+    let second = commit_file(&tracker, &temp_dir, "a.txt", "feat: second");
+    tracker.create_tag("v1.3.0-rc.1-release", second).unwrap();
+    tracker.create_tag("v9.0.0", second).unwrap();
+
+    // `-release` is the format's suffix, not a prerelease label
+    tracker.options = GitOptions {
+        tag_format: Some("v{version}-release".to_string()),
+        exclude_prerelease: true,
+        ..Default::default()
+    };
+    assert_eq!(tracker.latest_version_tag().unwrap().as_deref(), Some("v1.2.0-release"));
+    assert_eq!(tracker.latest_tagged_version().unwrap(), Some(semver::Version::new(1, 2, 0)));
+
+    tracker.options = GitOptions { tag_format: Some("{version}@app".to_string()), ..Default::default() };
+    assert_eq!(tracker.latest_version_tag().unwrap(), None);
+    tracker.create_tag("2.0.0@app", second).unwrap();
+    assert_eq!(tracker.latest_version_tag().unwrap().as_deref(), Some("2.0.0@app"));
+}
+
+#[test]
+fn test_exclude_prerelease_fallback_when_all_tags_are_prereleases() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    let head = tracker.repository.head().unwrap().target().unwrap();
+    tracker.create_tag("v1.0.0-rc.1", head).unwrap();
+    tracker.create_tag("v1.0.0-rc.2", head).unwrap();
+
+    tracker.options = GitOptions { exclude_prerelease: true, ..Default::default() };
+    assert_eq!(tracker.latest_version_tag().unwrap().as_deref(), Some("v1.0.0-rc.2"));
+
+    tracker.options = GitOptions {
+        exclude_prerelease: true,
+        prerelease_fallback: PrereleaseFallback::Error,
+        ..Default::default()
+    };
+    assert!(tracker.latest_version_tag().unwrap_err().to_string().contains("prerelease"));
+}
+
+#[test]
+fn test_commit_date_sets_commit_and_tag_time() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    tracker.options = GitOptions { commit_date: Some(1_700_000_000), ..Default::default() };
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    tracker.execute_git_mode(GitMode::CommitTag, "1.0.0", &[file]).unwrap();
+
+    let head = tracker.repository.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(head.author().when().seconds(), 1_700_000_000);
+    assert_eq!(head.committer().when().seconds(), 1_700_000_000);
+    assert_eq!(head.author().name(), Some("Test User"));
+    let tag = tracker.repository.revparse_single("refs/tags/v1.0.0").unwrap().peel_to_tag().unwrap();
+    assert_eq!(tag.tagger().unwrap().when().seconds(), 1_700_000_000);
+}
+
+#[test]
+fn test_commit_date_with_git_cli() {
+    if !git_cli_available() {
+        return;
+    }
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    tracker.options = GitOptions { commit_date: Some(1_700_000_000), use_git_cli: true, ..Default::default() };
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    tracker.execute_git_mode(GitMode::Commit, "1.0.0", &[file]).unwrap();
+
+    let head = tracker.repository.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(head.author().when().seconds(), 1_700_000_000);
+    assert_eq!(head.committer().when().seconds(), 1_700_000_000);
+}
+
+#[test]
+fn test_tag_annotation_template() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    tracker.options = GitOptions {
+        tag_annotation: Some("Release {version} ({tag}) on {date}".to_string()),
+        commit_date: Some(1_700_000_000),
+        ..Default::default()
+    };
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    tracker.execute_git_mode(GitMode::CommitTag, "1.0.0", &[file]).unwrap();
+
+    let tag = tracker.repository.revparse_single("refs/tags/v1.0.0").unwrap().peel_to_tag().unwrap();
+    assert_eq!(tag.message(), Some("Release 1.0.0 (v1.0.0) on 2023-11-14"));
+}
+
+#[test]
+fn test_lightweight_tag_has_no_annotation() {
+    let temp_dir = create_test_repo();
+    let mut tracker = GitTracker::open(temp_dir.path(), false).unwrap();
+    tracker.options = GitOptions { lightweight_tag: true, ..Default::default() };
+
+    let file = temp_dir.path().join("version.txt");
+    fs::write(&file, "1.0.0").unwrap();
+    tracker.execute_git_mode(GitMode::CommitTag, "1.0.0", &[file]).unwrap();
+
+    let target = tracker.repository.revparse_single("refs/tags/v1.0.0").unwrap();
+    assert_eq!(target.kind(), Some(git2::ObjectType::Commit));
+}
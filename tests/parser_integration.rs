@@ -3,9 +3,23 @@
 use semver::Version;
 use std::fs;
 use tempfile::TempDir;
+use update_version::BumpKind;
 use update_version::parsers::{
-    WalkOptions, package_json_parser::PackageJsonParser, tauri_config_parser::TauriConfigParser,
-    toml_parser::TomlParser, Parser,
+    ParsingError, STREAMING_THRESHOLD, WalkOptions, ansible_galaxy_parser::AnsibleGalaxyParser,
+    assembly_info_parser::AssemblyInfoParser, bukkit_plugin_parser::BukkitPluginParser,
+    cabal_parser::CabalParser, cmake_parser::CMakeParser, conan_parser::ConanParser,
+    dockerfile_parser::DockerfileParser, dotnet_project_parser::DotnetProjectParser,
+    dune_parser::DuneParser, env_file_parser::EnvFileParser, generic_json_parser::GenericJsonParser,
+    generic_toml_parser::GenericTomlParser, godot_parser::GodotParser,
+    gradle_properties_parser::GradlePropertiesParser, julia_project_parser::JuliaProjectParser,
+    lerna_parser::LernaParser, maven_parser::MavenParser, nimble_parser::NimbleParser,
+    package_json_parser::PackageJsonParser, plist_parser::PlistParser,
+    pubspec_parser::PubspecParser, r_description_parser::RDescriptionParser,
+    readme_parser::ReadmeParser, ros_package_parser::RosPackageParser,
+    ruby_gem_parser::RubyGemParser, setup_cfg_parser::SetupCfgParser,
+    snapcraft_parser::SnapcraftParser, swift_parser::SwiftParser,
+    tauri_config_parser::TauriConfigParser, toml_parser::TomlParser, unity_parser::UnityParser,
+    vcpkg_parser::VcpkgParser, wix_parser::WixParser, zig_zon_parser::ZigZonParser, Parser,
 };
 
 // ============================================================================
@@ -120,6 +134,32 @@ version = "1.0.0"
     assert!(sub_content.contains(r#"version = "2.0.0""#));
 }
 
+#[test]
+fn test_toml_preserves_single_quotes() {
+    let temp_dir = TempDir::new().unwrap();
+    let cargo_toml = temp_dir.path().join("Cargo.toml");
+
+    fs::write(
+        &cargo_toml,
+        r#"[package]
+name = 'test-crate'
+version = '1.0.0'
+"#,
+    )
+    .unwrap();
+
+    let new_version = Version::parse("2.0.0").unwrap();
+    TomlParser::update_version(temp_dir.path(), &new_version, &WalkOptions::default()).unwrap();
+
+    let content = fs::read_to_string(&cargo_toml).unwrap();
+    assert!(content.contains("version = '2.0.0'"));
+    assert!(!content.contains(r#"version = "2.0.0""#));
+
+    let version =
+        TomlParser::get_current_version(temp_dir.path(), &WalkOptions::default()).unwrap();
+    assert_eq!(version, Version::parse("2.0.0").unwrap());
+}
+
 // ============================================================================
 // Package.json Parser Integration Tests
 // ============================================================================
@@ -189,6 +229,41 @@ fn test_package_json_get_current_version() {
     assert_eq!(version, Version::parse("5.4.3").unwrap());
 }
 
+#[test]
+fn test_package_json_large_file_is_streamed() {
+    let temp_dir = TempDir::new().unwrap();
+    let package_json = temp_dir.path().join("package.json");
+
+    let mut original = String::from("{\r\n  \"name\": \"big\",\r\n  \"version\": \"1.0.0\",\r\n  \"files\": {\r\n");
+    for i in 0..60_000 {
+        original.push_str(&format!("    \"asset-{i:05}\": {{ \"version\": \"0.0.{i}\" }},\r\n"));
+    }
+    original.push_str("    \"last\": {}\r\n  }\r\n}");
+    // A stray non-UTF-8 byte past the version line fails a whole-file read, so the reads and
+    // writes below only succeed if they stream
+    let mut original = original.into_bytes();
+    original.extend_from_slice(b"\r\n\xff");
+    fs::write(&package_json, &original).unwrap();
+    assert!(original.len() as u64 > STREAMING_THRESHOLD * 2);
+
+    let version = PackageJsonParser::get_current_version(temp_dir.path(), &WalkOptions::default()).unwrap();
+    assert_eq!(version, Version::new(1, 0, 0));
+
+    let options = WalkOptions { dry_run: true, ..Default::default() };
+    PackageJsonParser::update_version(temp_dir.path(), &Version::new(2, 0, 0), &options).unwrap();
+    assert_eq!(fs::read(&package_json).unwrap(), original);
+
+    let options = WalkOptions { backup: true, ..Default::default() };
+    PackageJsonParser::update_version(temp_dir.path(), &Version::new(2, 0, 0), &options).unwrap();
+
+    let version_line = original.windows(18).position(|w| w == b"\"version\": \"1.0.0\"").unwrap();
+    let mut expected = original.clone();
+    expected[version_line + 12..version_line + 17].copy_from_slice(b"2.0.0");
+    assert_eq!(fs::read(&package_json).unwrap(), expected);
+    assert_eq!(fs::read(temp_dir.path().join("package.json.bak")).unwrap(), original);
+    assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 2);
+}
+
 // ============================================================================
 // Tauri Config Parser Integration Tests
 // ============================================================================
@@ -274,6 +349,70 @@ edition = "2021"
     assert!(result.is_err());
 }
 
+#[test]
+fn test_partial_version_strict_names_file_and_value() {
+    let temp_dir = TempDir::new().unwrap();
+    let package_json = temp_dir.path().join("package.json");
+
+    fs::write(&package_json, "{\n  \"version\": \"1.2\"\n}\n").unwrap();
+
+    let err = PackageJsonParser::get_current_version(temp_dir.path(), &WalkOptions::default())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("'1.2'"), "error: {err}");
+    assert!(err.contains(package_json.to_str().unwrap()), "error: {err}");
+}
+
+#[test]
+fn test_partial_version_coerced() {
+    let temp_dir = TempDir::new().unwrap();
+    let package_json = temp_dir.path().join("package.json");
+
+    fs::write(&package_json, "{\n  \"version\": \"1.2\"\n}\n").unwrap();
+
+    let options = WalkOptions { coerce: true, ..Default::default() };
+    let version = PackageJsonParser::get_current_version(temp_dir.path(), &options).unwrap();
+    assert_eq!(version, Version::parse("1.2.0").unwrap());
+
+    PackageJsonParser::increment_version(temp_dir.path(), &options).unwrap();
+    let content = fs::read_to_string(&package_json).unwrap();
+    assert!(content.contains(r#""version": "1.2.1""#));
+}
+
+#[test]
+fn test_insert_missing_adds_version_to_toml() {
+    let temp_dir = TempDir::new().unwrap();
+    let cargo_toml = temp_dir.path().join("Cargo.toml");
+
+    fs::write(&cargo_toml, "[package]\nname = \"test-crate\"\n\n[dependencies]\nserde = \"1.0\"\n")
+        .unwrap();
+
+    let options = WalkOptions { insert_missing: true, ..Default::default() };
+    let new_version = Version::parse("0.1.0").unwrap();
+    TomlParser::update_version(temp_dir.path(), &new_version, &options).unwrap();
+
+    let content = fs::read_to_string(&cargo_toml).unwrap();
+    assert_eq!(
+        content,
+        "[package]\nname = \"test-crate\"\nversion = \"0.1.0\"\n\n[dependencies]\nserde = \"1.0\"\n"
+    );
+}
+
+#[test]
+fn test_missing_version_untouched_without_insert() {
+    let temp_dir = TempDir::new().unwrap();
+    let package_json = temp_dir.path().join("package.json");
+
+    fs::write(&package_json, "{\n  \"name\": \"pkg\"\n}\n").unwrap();
+
+    let new_version = Version::parse("0.1.0").unwrap();
+    PackageJsonParser::update_version(temp_dir.path(), &new_version, &WalkOptions::default())
+        .unwrap();
+
+    let content = fs::read_to_string(&package_json).unwrap();
+    assert_eq!(content, "{\n  \"name\": \"pkg\"\n}\n");
+}
+
 // ============================================================================
 // Edge Cases
 // ============================================================================
@@ -329,6 +468,38 @@ version = "1.0.0-alpha.1"
     assert!(!version.pre.is_empty());
 }
 
+#[test]
+fn test_preserves_trailing_newline() {
+    let temp_dir = TempDir::new().unwrap();
+    let cargo_toml = temp_dir.path().join("Cargo.toml");
+    fs::write(&cargo_toml, "[package]\nname = \"test\"\nversion = \"1.0.0\"\n").unwrap();
+
+    let new_version = Version::parse("1.1.0").unwrap();
+    TomlParser::update_version(temp_dir.path(), &new_version, &WalkOptions::default()).unwrap();
+
+    let content = fs::read_to_string(&cargo_toml).unwrap();
+    assert_eq!(content, "[package]\nname = \"test\"\nversion = \"1.1.0\"\n");
+}
+
+#[test]
+fn test_preserves_missing_trailing_newline() {
+    let temp_dir = TempDir::new().unwrap();
+    let cargo_toml = temp_dir.path().join("Cargo.toml");
+    let package_json = temp_dir.path().join("package.json");
+    fs::write(&cargo_toml, "[package]\nname = \"test\"\nversion = \"1.0.0\"").unwrap();
+    fs::write(&package_json, "{\n  \"name\": \"test\"\n}").unwrap();
+
+    let new_version = Version::parse("1.1.0").unwrap();
+    let options = WalkOptions { insert_missing: true, ..Default::default() };
+    TomlParser::update_version(temp_dir.path(), &new_version, &options).unwrap();
+    PackageJsonParser::update_version(temp_dir.path(), &new_version, &options).unwrap();
+
+    let content = fs::read_to_string(&cargo_toml).unwrap();
+    assert_eq!(content, "[package]\nname = \"test\"\nversion = \"1.1.0\"");
+    let content = fs::read_to_string(&package_json).unwrap();
+    assert_eq!(content, "{\n  \"version\": \"1.1.0\",\n  \"name\": \"test\"\n}");
+}
+
 // ============================================================================
 // Regression: No Double Quotes
 // ============================================================================
@@ -392,6 +563,46 @@ fn test_package_json_no_double_quote_after_version() {
     );
 }
 
+#[test]
+fn test_package_json_with_bom_round_trips() {
+    let temp_dir = TempDir::new().unwrap();
+    let package_json = temp_dir.path().join("package.json");
+
+    fs::write(
+        &package_json,
+        "\u{FEFF}{\n  \"version\": \"1.0.0\",\n  \"name\": \"bom-package\"\n}\n",
+    )
+    .unwrap();
+
+    let version =
+        PackageJsonParser::get_current_version(temp_dir.path(), &WalkOptions::default()).unwrap();
+    assert_eq!(version, Version::parse("1.0.0").unwrap());
+
+    let new_version = Version::parse("2.0.0").unwrap();
+    PackageJsonParser::update_version(temp_dir.path(), &new_version, &WalkOptions::default())
+        .unwrap();
+
+    let content = fs::read_to_string(&package_json).unwrap();
+    assert_eq!(
+        content,
+        "\u{FEFF}{\n  \"version\": \"2.0.0\",\n  \"name\": \"bom-package\"\n}\n"
+    );
+}
+
+#[test]
+fn test_bom_does_not_hide_version_on_first_line() {
+    let temp_dir = TempDir::new().unwrap();
+    let cargo_toml = temp_dir.path().join("Cargo.toml");
+
+    fs::write(&cargo_toml, "\u{FEFF}version = \"1.0.0\"\nname = \"bom\"\n").unwrap();
+
+    let new_version = Version::parse("1.1.0").unwrap();
+    TomlParser::update_version(temp_dir.path(), &new_version, &WalkOptions::default()).unwrap();
+
+    let content = fs::read_to_string(&cargo_toml).unwrap();
+    assert_eq!(content, "\u{FEFF}version = \"1.1.0\"\nname = \"bom\"\n");
+}
+
 // ============================================================================
 // Ignore File Tests
 // ============================================================================
@@ -439,6 +650,35 @@ version = "1.0.0"
     assert!(vendor_content.contains(r#"version = "1.0.0""#));
 }
 
+#[test]
+fn test_git_info_exclude_excludes_directory() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let root_toml = temp_dir.path().join("Cargo.toml");
+    fs::write(&root_toml, "[package]\nname = \"root\"\nversion = \"1.0.0\"\n").unwrap();
+
+    // Exclude "scratch/" through the repository-local exclude file rather than .gitignore
+    let info_dir = temp_dir.path().join(".git").join("info");
+    fs::create_dir_all(&info_dir).unwrap();
+    fs::write(info_dir.join("exclude"), "scratch/\n").unwrap();
+
+    let scratch_dir = temp_dir.path().join("scratch");
+    fs::create_dir_all(&scratch_dir).unwrap();
+    fs::write(
+        scratch_dir.join("Cargo.toml"),
+        "[package]\nname = \"scratch\"\nversion = \"1.0.0\"\n",
+    )
+    .unwrap();
+
+    let files = TomlParser::get_matching_files(temp_dir.path(), &WalkOptions::default()).unwrap();
+    assert_eq!(files, vec![root_toml.clone()]);
+
+    // --no-ignore disables the exclude file too
+    let options = WalkOptions { no_ignore: true, ..Default::default() };
+    let files = TomlParser::get_matching_files(temp_dir.path(), &options).unwrap();
+    assert_eq!(files.len(), 2);
+}
+
 #[test]
 fn test_gitignore_excludes_directory() {
     let temp_dir = TempDir::new().unwrap();
@@ -508,7 +748,7 @@ version = "1.0.0"
     )
     .unwrap();
 
-    let options = WalkOptions { no_ignore: true };
+    let options = WalkOptions { no_ignore: true, ..Default::default() };
     let new_version = Version::parse("2.0.0").unwrap();
     let updated = TomlParser::update_version(temp_dir.path(), &new_version, &options).unwrap();
 
@@ -594,3 +834,1606 @@ version = "1.0.0-beta.5"
         content
     );
 }
+
+// ============================================================================
+// Ruby Gem Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_ruby_gemspec_update_version() {
+    let temp_dir = TempDir::new().unwrap();
+    let gemspec = temp_dir.path().join("my_gem.gemspec");
+
+    fs::write(
+        &gemspec,
+        r#"Gem::Specification.new do |spec|
+  spec.name    = "my_gem"
+  spec.version = '1.0.0'
+  spec.add_dependency "rake", "~> 13.0"
+end
+"#,
+    )
+    .unwrap();
+
+    let new_version = Version::parse("2.0.0").unwrap();
+    let updated =
+        RubyGemParser::update_version(temp_dir.path(), &new_version, &WalkOptions::default())
+            .unwrap();
+
+    assert_eq!(updated.len(), 1);
+
+    let content = fs::read_to_string(&gemspec).unwrap();
+    assert!(content.contains("spec.version = '2.0.0'"));
+    assert!(content.contains(r#""rake", "~> 13.0""#));
+}
+
+#[test]
+fn test_ruby_version_rb_update_version() {
+    let temp_dir = TempDir::new().unwrap();
+    let lib_dir = temp_dir.path().join("lib").join("my_gem");
+    fs::create_dir_all(&lib_dir).unwrap();
+    let version_rb = lib_dir.join("version.rb");
+
+    fs::write(
+        &version_rb,
+        r#"module MyGem
+  VERSION = "1.4.2".freeze
+end
+"#,
+    )
+    .unwrap();
+
+    let new_version = Version::parse("1.5.0").unwrap();
+    RubyGemParser::update_version(temp_dir.path(), &new_version, &WalkOptions::default())
+        .unwrap();
+
+    let content = fs::read_to_string(&version_rb).unwrap();
+    assert!(content.contains(r#"VERSION = "1.5.0".freeze"#));
+}
+
+#[test]
+fn test_ruby_prefers_version_rb_for_current_version() {
+    let temp_dir = TempDir::new().unwrap();
+    let lib_dir = temp_dir.path().join("lib").join("my_gem");
+    fs::create_dir_all(&lib_dir).unwrap();
+
+    fs::write(
+        temp_dir.path().join("my_gem.gemspec"),
+        "Gem::Specification.new do |spec|\n  spec.version = \"0.9.0\"\nend\n",
+    )
+    .unwrap();
+    fs::write(
+        lib_dir.join("version.rb"),
+        "module MyGem\n  VERSION = \"1.0.0\"\nend\n",
+    )
+    .unwrap();
+
+    let version =
+        RubyGemParser::get_current_version(temp_dir.path(), &WalkOptions::default()).unwrap();
+    assert_eq!(version, Version::parse("1.0.0").unwrap());
+}
+
+// ============================================================================
+// R DESCRIPTION Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_r_description_update_dotted_version() {
+    let temp_dir = TempDir::new().unwrap();
+    let description = temp_dir.path().join("DESCRIPTION");
+
+    fs::write(
+        &description,
+        "Package: mypkg\nTitle: My Package\nVersion: 1.2.3\nDepends: R (>= 3.5.0)\n",
+    )
+    .unwrap();
+
+    let version =
+        RDescriptionParser::get_current_version(temp_dir.path(), &WalkOptions::default()).unwrap();
+    assert_eq!(version, Version::parse("1.2.3").unwrap());
+
+    let new_version = Version::parse("1.3.0").unwrap();
+    RDescriptionParser::update_version(temp_dir.path(), &new_version, &WalkOptions::default())
+        .unwrap();
+
+    let content = fs::read_to_string(&description).unwrap();
+    assert_eq!(
+        content,
+        "Package: mypkg\nTitle: My Package\nVersion: 1.3.0\nDepends: R (>= 3.5.0)\n"
+    );
+}
+
+#[test]
+fn test_r_description_dash_version() {
+    let temp_dir = TempDir::new().unwrap();
+    let description = temp_dir.path().join("DESCRIPTION");
+
+    fs::write(&description, "Package: mypkg\nVersion: 0.4-2\n").unwrap();
+
+    let version =
+        RDescriptionParser::get_current_version(temp_dir.path(), &WalkOptions::default()).unwrap();
+    assert_eq!(version, Version::parse("0.4.2").unwrap());
+
+    RDescriptionParser::increment_version(temp_dir.path(), &WalkOptions::default()).unwrap();
+
+    let content = fs::read_to_string(&description).unwrap();
+    assert!(content.contains("Version: 0.4.3"));
+}
+
+#[test]
+fn test_r_description_only_matches_root() {
+    let temp_dir = TempDir::new().unwrap();
+    let nested = temp_dir.path().join("inst").join("extdata");
+    fs::create_dir_all(&nested).unwrap();
+    fs::write(nested.join("DESCRIPTION"), "Version: 9.9.9\n").unwrap();
+
+    let files =
+        RDescriptionParser::get_matching_files(temp_dir.path(), &WalkOptions::default()).unwrap();
+    assert!(files.is_empty());
+}
+
+// ============================================================================
+// Julia Project Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_julia_project_updates_only_top_level_version() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_toml = temp_dir.path().join("Project.toml");
+
+    fs::write(
+        &project_toml,
+        r#"name = "MyPkg"
+uuid = "7876af07-990d-54b4-ab0e-23690620f79a"
+version = "1.0.0"
+
+[deps]
+JSON = "682c06a0-de6a-54ab-a142-c8b1cf79cde6"
+
+[compat]
+JSON = "1.0.0"
+julia = "1.6"
+"#,
+    )
+    .unwrap();
+
+    let version =
+        JuliaProjectParser::get_current_version(temp_dir.path(), &WalkOptions::default()).unwrap();
+    assert_eq!(version, Version::parse("1.0.0").unwrap());
+
+    let new_version = Version::parse("2.0.0").unwrap();
+    JuliaProjectParser::update_version(temp_dir.path(), &new_version, &WalkOptions::default())
+        .unwrap();
+
+    let content = fs::read_to_string(&project_toml).unwrap();
+    assert!(content.contains("version = \"2.0.0\"\n\n[deps]"));
+    assert!(content.contains(r#"JSON = "1.0.0""#));
+    assert!(content.contains(r#"julia = "1.6""#));
+}
+
+// ============================================================================
+// Swift Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_swift_updates_version_constant_only_in_declaring_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let sources = temp_dir.path().join("Sources").join("App");
+    fs::create_dir_all(&sources).unwrap();
+    let version_swift = sources.join("Version.swift");
+    let other_swift = sources.join("Client.swift");
+
+    fs::write(&version_swift, "public enum AppInfo {\n    public static let version = \"1.0.0\"\n}\n")
+        .unwrap();
+    fs::write(&other_swift, "let userAgent = \"App/1.0.0\"\n").unwrap();
+
+    let files = SwiftParser::get_matching_files(temp_dir.path(), &WalkOptions::default()).unwrap();
+    assert_eq!(files, vec![version_swift.clone()]);
+
+    let new_version = Version::parse("1.1.0").unwrap();
+    SwiftParser::update_version(temp_dir.path(), &new_version, &WalkOptions::default()).unwrap();
+
+    let content = fs::read_to_string(&version_swift).unwrap();
+    assert!(content.contains("public static let version = \"1.1.0\""));
+    let other = fs::read_to_string(&other_swift).unwrap();
+    assert_eq!(other, "let userAgent = \"App/1.0.0\"\n");
+}
+
+#[test]
+fn test_swift_let_with_custom_identifier() {
+    let temp_dir = TempDir::new().unwrap();
+    let app_version = temp_dir.path().join("AppVersion.swift");
+
+    fs::write(&app_version, "let marketingVersion = \"2.3.4\"\n").unwrap();
+
+    let options =
+        WalkOptions { swift_identifier: Some("marketingVersion".to_string()), ..Default::default() };
+    let version = SwiftParser::get_current_version(temp_dir.path(), &options).unwrap();
+    assert_eq!(version, Version::parse("2.3.4").unwrap());
+
+    SwiftParser::increment_version(temp_dir.path(), &options).unwrap();
+    let content = fs::read_to_string(&app_version).unwrap();
+    assert_eq!(content, "let marketingVersion = \"2.3.5\"\n");
+}
+
+// ============================================================================
+// AssemblyInfo Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_assembly_info_updates_both_attributes() {
+    let temp_dir = TempDir::new().unwrap();
+    let properties = temp_dir.path().join("MyApp").join("Properties");
+    fs::create_dir_all(&properties).unwrap();
+    let assembly_info = properties.join("AssemblyInfo.cs");
+
+    fs::write(
+        &assembly_info,
+        r#"using System.Reflection;
+
+[assembly: AssemblyTitle("MyApp")]
+[assembly: AssemblyVersion("1.2.3.0")]
+[assembly: AssemblyFileVersion("1.2.3.17")]
+"#,
+    )
+    .unwrap();
+
+    let version = AssemblyInfoParser::get_current_version(temp_dir.path(), &WalkOptions::default()).unwrap();
+    assert_eq!(version, Version::parse("1.2.3").unwrap());
+
+    let new_version = Version::parse("1.3.0").unwrap();
+    AssemblyInfoParser::update_version(temp_dir.path(), &new_version, &WalkOptions::default()).unwrap();
+
+    let content = fs::read_to_string(&assembly_info).unwrap();
+    assert!(content.contains(r#"[assembly: AssemblyVersion("1.3.0.0")]"#));
+    assert!(content.contains(r#"[assembly: AssemblyFileVersion("1.3.0.17")]"#));
+    assert!(content.contains(r#"[assembly: AssemblyTitle("MyApp")]"#));
+}
+
+// ============================================================================
+// Plist Parser Integration Tests
+// ============================================================================
+
+const INFO_PLIST: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+	<key>CFBundleName</key>
+	<string>MyApp</string>
+	<key>CFBundleShortVersionString</key>
+	<string>1.4.2</string>
+	<key>CFBundleVersion</key>
+	<string>108</string>
+</dict>
+</plist>
+"#;
+
+#[test]
+fn test_plist_updates_short_version_string() {
+    let temp_dir = TempDir::new().unwrap();
+    let plist = temp_dir.path().join("Info.plist");
+    fs::write(&plist, INFO_PLIST).unwrap();
+
+    let version = PlistParser::get_current_version(temp_dir.path(), &WalkOptions::default()).unwrap();
+    assert_eq!(version, Version::parse("1.4.2").unwrap());
+
+    let new_version = Version::parse("1.5.0").unwrap();
+    PlistParser::update_version(temp_dir.path(), &new_version, &WalkOptions::default()).unwrap();
+
+    let content = fs::read_to_string(&plist).unwrap();
+    assert!(content.contains("<key>CFBundleShortVersionString</key>\n\t<string>1.5.0</string>"));
+    assert!(content.contains("<key>CFBundleVersion</key>\n\t<string>108</string>"));
+}
+
+#[test]
+fn test_plist_two_part_short_version() {
+    let temp_dir = TempDir::new().unwrap();
+    let plist = temp_dir.path().join("Info.plist");
+    fs::write(&plist, INFO_PLIST.replace("1.4.2", "1.4")).unwrap();
+
+    let version = PlistParser::get_current_version(temp_dir.path(), &WalkOptions::default()).unwrap();
+    assert_eq!(version, Version::new(1, 4, 0));
+
+    PlistParser::update_version(temp_dir.path(), &Version::new(1, 5, 0), &WalkOptions::default()).unwrap();
+    assert_eq!(fs::read_to_string(&plist).unwrap(), INFO_PLIST.replace("1.4.2", "1.5"));
+    assert!(PlistParser::verify_version(temp_dir.path(), &Version::new(1, 5, 0), &WalkOptions::default())
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_plist_bumps_build_number_when_requested() {
+    let temp_dir = TempDir::new().unwrap();
+    let plist = temp_dir.path().join("Info.plist");
+    fs::write(&plist, INFO_PLIST).unwrap();
+
+    let options = WalkOptions { bump_build_number: true, ..Default::default() };
+    let new_version = Version::parse("1.5.0").unwrap();
+    PlistParser::update_version(temp_dir.path(), &new_version, &options).unwrap();
+
+    let content = fs::read_to_string(&plist).unwrap();
+    assert!(content.contains("<key>CFBundleShortVersionString</key>\n\t<string>1.5.0</string>"));
+    assert!(content.contains("<key>CFBundleVersion</key>\n\t<string>109</string>"));
+}
+
+// ============================================================================
+// Snapcraft Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_snapcraft_updates_quoted_version() {
+    let temp_dir = TempDir::new().unwrap();
+    let snap_dir = temp_dir.path().join("snap");
+    fs::create_dir_all(&snap_dir).unwrap();
+    let snapcraft = snap_dir.join("snapcraft.yaml");
+
+    fs::write(&snapcraft, "name: my-snap\nversion: '1.2.3'\nbase: core22\n").unwrap();
+
+    let version = SnapcraftParser::get_current_version(temp_dir.path(), &WalkOptions::default()).unwrap();
+    assert_eq!(version, Version::parse("1.2.3").unwrap());
+
+    SnapcraftParser::increment_version(temp_dir.path(), &WalkOptions::default()).unwrap();
+
+    let content = fs::read_to_string(&snapcraft).unwrap();
+    assert_eq!(content, "name: my-snap\nversion: '1.2.4'\nbase: core22\n");
+}
+
+#[test]
+fn test_snapcraft_git_version_skipped_on_read_but_replaced_on_set() {
+    let temp_dir = TempDir::new().unwrap();
+    let snapcraft = temp_dir.path().join("snapcraft.yaml");
+
+    fs::write(&snapcraft, "name: my-snap\nversion: git\nbase: core22\n").unwrap();
+
+    let result = SnapcraftParser::get_current_version(temp_dir.path(), &WalkOptions::default());
+    assert!(result.is_err());
+
+    let new_version = Version::parse("2.0.0").unwrap();
+    SnapcraftParser::update_version(temp_dir.path(), &new_version, &WalkOptions::default()).unwrap();
+
+    let content = fs::read_to_string(&snapcraft).unwrap();
+    assert_eq!(content, "name: my-snap\nversion: 2.0.0\nbase: core22\n");
+}
+
+// ============================================================================
+// Ansible Galaxy Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_ansible_galaxy_updates_quoted_version() {
+    let temp_dir = TempDir::new().unwrap();
+    let galaxy = temp_dir.path().join("galaxy.yml");
+
+    fs::write(
+        &galaxy,
+        "namespace: acme\nname: tools\nversion: '1.0.0-rc.1'\ndependencies:\n  community.general: '>=5.0.0'\n",
+    )
+    .unwrap();
+
+    let version = AnsibleGalaxyParser::get_current_version(temp_dir.path(), &WalkOptions::default()).unwrap();
+    assert_eq!(version, Version::parse("1.0.0-rc.1").unwrap());
+
+    let new_version = Version::parse("1.0.0-rc.2").unwrap();
+    AnsibleGalaxyParser::update_version(temp_dir.path(), &new_version, &WalkOptions::default()).unwrap();
+
+    let content = fs::read_to_string(&galaxy).unwrap();
+    assert_eq!(
+        content,
+        "namespace: acme\nname: tools\nversion: '1.0.0-rc.2'\ndependencies:\n  community.general: '>=5.0.0'\n"
+    );
+}
+
+// ============================================================================
+// Zig Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_zig_updates_only_package_version() {
+    let temp_dir = TempDir::new().unwrap();
+    let zon = temp_dir.path().join("build.zig.zon");
+
+    fs::write(
+        &zon,
+        r#".{
+    .name = .my_pkg,
+    .version = "0.3.1",
+    .minimum_zig_version = "0.14.0",
+    .dependencies = .{
+        .zlib = .{
+            .url = "https://example.com/zlib-1.3.1.tar.gz",
+            .hash = "1220fed0b8a8e7bd0f8d5d5d5d5d5d5d5d5d5d5d5d5d5d5d5d5d5d5d5d5d5d5d5d",
+        },
+    },
+}
+"#,
+    )
+    .unwrap();
+
+    let version = ZigZonParser::get_current_version(temp_dir.path(), &WalkOptions::default()).unwrap();
+    assert_eq!(version, Version::parse("0.3.1").unwrap());
+
+    let new_version = Version::parse("0.4.0").unwrap();
+    ZigZonParser::update_version(temp_dir.path(), &new_version, &WalkOptions::default()).unwrap();
+
+    let content = fs::read_to_string(&zon).unwrap();
+    assert!(content.contains(".version = \"0.4.0\","));
+    assert!(content.contains(".minimum_zig_version = \"0.14.0\","));
+    assert!(content.contains("zlib-1.3.1.tar.gz"));
+}
+
+// ============================================================================
+// Vcpkg Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_vcpkg_updates_top_level_version_only() {
+    let temp_dir = TempDir::new().unwrap();
+    let manifest = temp_dir.path().join("vcpkg.json");
+
+    fs::write(
+        &manifest,
+        r#"{
+  "name": "my-app",
+  "version-semver": "1.0.0",
+  "dependencies": [
+    "zlib",
+    { "name": "fmt", "version>=": "10.0.0" }
+  ],
+  "overrides": [
+    {
+      "name": "zlib",
+      "version": "1.3.1"
+    }
+  ]
+}
+"#,
+    )
+    .unwrap();
+
+    let version = VcpkgParser::get_current_version(temp_dir.path(), &WalkOptions::default()).unwrap();
+    assert_eq!(version, Version::parse("1.0.0").unwrap());
+
+    let new_version = Version::parse("1.1.0").unwrap();
+    VcpkgParser::update_version(temp_dir.path(), &new_version, &WalkOptions::default()).unwrap();
+
+    let content = fs::read_to_string(&manifest).unwrap();
+    assert!(content.contains(r#""version-semver": "1.1.0","#));
+    assert!(content.contains(r#""version>=": "10.0.0""#));
+    assert!(content.contains(r#""version": "1.3.1""#));
+}
+
+// ============================================================================
+// Verification Tests
+// ============================================================================
+
+#[test]
+fn test_verify_version_reports_mismatched_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().join("Cargo.toml");
+    let member_dir = temp_dir.path().join("member");
+    fs::create_dir_all(&member_dir).unwrap();
+    fs::write(&root, "[package]\nname = \"root\"\nversion = \"2.0.0\"\n").unwrap();
+    fs::write(member_dir.join("Cargo.toml"), "[package]\nname = \"member\"\nversion = \"1.0.0\"\n").unwrap();
+
+    let expected = Version::parse("2.0.0").unwrap();
+    let mismatches = TomlParser::verify_version(temp_dir.path(), &expected, &WalkOptions::default()).unwrap();
+    assert_eq!(mismatches.len(), 1);
+    assert!(mismatches[0].contains("member"), "mismatch: {}", mismatches[0]);
+    assert!(mismatches[0].contains("found 1.0.0"), "mismatch: {}", mismatches[0]);
+}
+
+// ============================================================================
+// Conan Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_conan_updates_only_recipe_version() {
+    let temp_dir = TempDir::new().unwrap();
+    let conanfile = temp_dir.path().join("conanfile.py");
+
+    fs::write(
+        &conanfile,
+        r#"from conan import ConanFile
+
+
+class MyLibRecipe(ConanFile):
+    name = "mylib"
+    version = "0.4.0"
+    requires = "zlib/1.2.11"
+
+    def requirements(self):
+        self.requires("fmt/10.2.1")
+"#,
+    )
+    .unwrap();
+
+    let version = ConanParser::get_current_version(temp_dir.path(), &WalkOptions::default()).unwrap();
+    assert_eq!(version, Version::parse("0.4.0").unwrap());
+
+    let new_version = Version::parse("0.5.0").unwrap();
+    ConanParser::update_version(temp_dir.path(), &new_version, &WalkOptions::default()).unwrap();
+
+    let content = fs::read_to_string(&conanfile).unwrap();
+    assert!(content.contains("    version = \"0.5.0\"\n"));
+    assert!(content.contains("requires = \"zlib/1.2.11\""));
+    assert!(content.contains("self.requires(\"fmt/10.2.1\")"));
+}
+
+// ============================================================================
+// Multiple Occurrence Tests
+// ============================================================================
+
+const TWO_VERSION_CONSTANTS: &str = r#"public enum App {
+    public static let version = "1.0.0"
+}
+
+public enum Cli {
+    public static let version = "1.0.0"
+}
+"#;
+
+#[test]
+fn test_only_first_occurrence_updated_by_default() {
+    let temp_dir = TempDir::new().unwrap();
+    let file = temp_dir.path().join("Version.swift");
+    fs::write(&file, TWO_VERSION_CONSTANTS).unwrap();
+
+    let new_version = Version::parse("1.1.0").unwrap();
+    SwiftParser::update_version(temp_dir.path(), &new_version, &WalkOptions::default()).unwrap();
+
+    let content = fs::read_to_string(&file).unwrap();
+    assert_eq!(content.matches("\"1.1.0\"").count(), 1);
+    assert_eq!(content.matches("\"1.0.0\"").count(), 1);
+}
+
+#[test]
+fn test_all_occurrences_updates_every_version_line() {
+    let temp_dir = TempDir::new().unwrap();
+    let file = temp_dir.path().join("Version.swift");
+    fs::write(&file, TWO_VERSION_CONSTANTS.replacen("1.0.0", "0.9.0", 1)).unwrap();
+
+    let options = WalkOptions { all_occurrences: true, ..Default::default() };
+    let new_version = Version::parse("1.1.0").unwrap();
+    SwiftParser::update_version(temp_dir.path(), &new_version, &options).unwrap();
+
+    let content = fs::read_to_string(&file).unwrap();
+    assert_eq!(content, TWO_VERSION_CONSTANTS.replace("1.0.0", "1.1.0"));
+}
+
+// ============================================================================
+// WiX Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_wix_updates_product_version_attribute() {
+    let temp_dir = TempDir::new().unwrap();
+    let wxs = temp_dir.path().join("Product.wxs");
+
+    fs::write(
+        &wxs,
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<Wix xmlns="http://schemas.microsoft.com/wix/2006/wi">
+  <Product Id="*" Name="App" Language="1033" Version="1.2.3.17" Manufacturer="Acme" UpgradeCode="PUT-GUID-HERE">
+    <Package InstallerVersion="200" Compressed="yes" InstallScope="perMachine" />
+  </Product>
+</Wix>
+"#,
+    )
+    .unwrap();
+
+    let version = WixParser::get_current_version(temp_dir.path(), &WalkOptions::default()).unwrap();
+    assert_eq!(version, Version::parse("1.2.3").unwrap());
+
+    let new_version = Version::parse("1.3.0").unwrap();
+    WixParser::update_version(temp_dir.path(), &new_version, &WalkOptions::default()).unwrap();
+    let content = fs::read_to_string(&wxs).unwrap();
+    assert!(content.contains(r#"Version="1.3.0.17""#));
+    assert!(content.contains(r#"InstallerVersion="200""#));
+    assert!(content.contains(r#"<?xml version="1.0""#));
+
+    let options = WalkOptions { reset_revision: true, ..Default::default() };
+    WixParser::update_version(temp_dir.path(), &new_version, &options).unwrap();
+    let content = fs::read_to_string(&wxs).unwrap();
+    assert!(content.contains(r#"Version="1.3.0.0""#));
+}
+
+// ============================================================================
+// Maven Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_maven_updates_revision_property_instead_of_placeholder() {
+    let temp_dir = TempDir::new().unwrap();
+    let pom = temp_dir.path().join("pom.xml");
+
+    let original = r#"<?xml version="1.0" encoding="UTF-8"?>
+<project xmlns="http://maven.apache.org/POM/4.0.0">
+  <modelVersion>4.0.0</modelVersion>
+  <groupId>org.acme</groupId>
+  <artifactId>app</artifactId>
+  <version>${revision}</version>
+  <properties>
+    <revision>1.4.0</revision>
+  </properties>
+  <dependencies>
+    <dependency>
+      <groupId>junit</groupId>
+      <artifactId>junit</artifactId>
+      <version>4.13.2</version>
+    </dependency>
+  </dependencies>
+</project>
+"#;
+    fs::write(&pom, original).unwrap();
+
+    let version = MavenParser::get_current_version(temp_dir.path(), &WalkOptions::default()).unwrap();
+    assert_eq!(version, Version::parse("1.4.0").unwrap());
+
+    let new_version = Version::parse("1.5.0").unwrap();
+    MavenParser::update_version(temp_dir.path(), &new_version, &WalkOptions::default()).unwrap();
+
+    let content = fs::read_to_string(&pom).unwrap();
+    assert_eq!(
+        content,
+        original.replace("<revision>1.4.0</revision>", "<revision>1.5.0</revision>")
+    );
+}
+
+#[test]
+fn test_maven_updates_direct_project_version() {
+    let temp_dir = TempDir::new().unwrap();
+    let pom = temp_dir.path().join("pom.xml");
+
+    fs::write(
+        &pom,
+        r#"<project>
+  <modelVersion>4.0.0</modelVersion>
+  <parent>
+    <groupId>org.acme</groupId>
+    <artifactId>acme-parent</artifactId>
+    <version>3.0.0</version>
+  </parent>
+  <artifactId>app</artifactId>
+  <version>1.0.0</version>
+</project>
+"#,
+    )
+    .unwrap();
+
+    let new_version = Version::parse("1.0.1").unwrap();
+    MavenParser::update_version(temp_dir.path(), &new_version, &WalkOptions::default()).unwrap();
+
+    let content = fs::read_to_string(&pom).unwrap();
+    assert!(content.contains("<version>3.0.0</version>"));
+    assert!(content.contains("<artifactId>app</artifactId>\n  <version>1.0.1</version>"));
+}
+
+// ============================================================================
+// Gradle Properties Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_gradle_properties_increments_version_code() {
+    let temp_dir = TempDir::new().unwrap();
+    let properties = temp_dir.path().join("gradle.properties");
+
+    fs::write(
+        &properties,
+        "android.useAndroidX=true\nversionName=1.2.3\nversionCode=12\n",
+    )
+    .unwrap();
+
+    let options = WalkOptions { bump_build_number: true, ..Default::default() };
+    let new_version = Version::parse("1.3.0").unwrap();
+    GradlePropertiesParser::update_version(temp_dir.path(), &new_version, &options).unwrap();
+
+    let content = fs::read_to_string(&properties).unwrap();
+    assert_eq!(content, "android.useAndroidX=true\nversionName=1.3.0\nversionCode=13\n");
+}
+
+// ============================================================================
+// Workspace Root Only Tests
+// ============================================================================
+
+#[test]
+fn test_workspace_root_only_updates_workspace_package() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().join("Cargo.toml");
+    fs::write(
+        &root,
+        r#"[workspace]
+members = ["crates/a", "crates/b"]
+
+[workspace.package]
+edition = "2024"
+authors = ["Someone"]
+version = "0.3.0"
+"#,
+    )
+    .unwrap();
+
+    let member_a = temp_dir.path().join("crates").join("a");
+    let member_b = temp_dir.path().join("crates").join("b");
+    fs::create_dir_all(&member_a).unwrap();
+    fs::create_dir_all(&member_b).unwrap();
+    fs::write(member_a.join("Cargo.toml"), "[package]\nname = \"a\"\nversion.workspace = true\n").unwrap();
+    // A member that pins its own version must not be touched in this mode
+    let pinned = "[package]\nname = \"b\"\nversion = \"9.0.0\"\n";
+    fs::write(member_b.join("Cargo.toml"), pinned).unwrap();
+
+    let options = WalkOptions { workspace_root_only: true, ..Default::default() };
+
+    // Discovery works from inside a member too
+    let files = TomlParser::get_matching_files(&member_a, &options).unwrap();
+    assert_eq!(files, vec![root.canonicalize().unwrap()]);
+
+    let version = TomlParser::get_current_version(temp_dir.path(), &options).unwrap();
+    assert_eq!(version, Version::parse("0.3.0").unwrap());
+
+    TomlParser::increment_version(temp_dir.path(), &options).unwrap();
+
+    let content = fs::read_to_string(&root).unwrap();
+    assert!(content.contains("version = \"0.3.1\""));
+    assert_eq!(fs::read_to_string(member_b.join("Cargo.toml")).unwrap(), pinned);
+}
+
+// ============================================================================
+// uv.toml Extra Files Tests
+// ============================================================================
+
+#[test]
+fn test_config_extra_files_pattern() {
+    use update_version::{arguments::SupportedTypes, config::Config, version::update_version_for};
+
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("uv.toml"), "[toml]\nextra_files = [\"version-config\\\\.toml\"]\n").unwrap();
+    fs::write(temp_dir.path().join("Cargo.toml"), "[package]\nname = \"app\"\nversion = \"1.0.0\"\n").unwrap();
+    let extra = temp_dir.path().join("version-config.toml");
+    fs::write(&extra, "version = \"1.0.0\"\n").unwrap();
+    // Only the configured name is added, not every .toml file
+    let other = temp_dir.path().join("other.toml");
+    fs::write(&other, "version = \"1.0.0\"\n").unwrap();
+
+    let options = WalkOptions { config: Config::load(temp_dir.path()).unwrap(), ..Default::default() };
+    let files = update_version_for(SupportedTypes::TOML, temp_dir.path(), &Version::new(2, 0, 0), &options).unwrap();
+
+    assert_eq!(files.len(), 2);
+    assert_eq!(fs::read_to_string(&extra).unwrap(), "version = \"2.0.0\"\n");
+    assert_eq!(fs::read_to_string(&other).unwrap(), "version = \"1.0.0\"\n");
+    // Without the config the parser only sees Cargo.toml
+    assert_eq!(TomlParser::get_matching_files(temp_dir.path(), &WalkOptions::default()).unwrap().len(), 1);
+}
+
+// ============================================================================
+// Nimble Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_nimble_update_leaves_requires_alone() {
+    let temp_dir = TempDir::new().unwrap();
+    let nimble = temp_dir.path().join("mylib.nimble");
+    fs::write(
+        &nimble,
+        r#"# Package
+
+version       = "0.4.1"
+author        = "someone"
+description   = "A library"
+license       = "MIT"
+
+# Dependencies
+
+requires "nim >= 2.0.0"
+requires "jester >= 0.6.0"
+"#,
+    )
+    .unwrap();
+
+    let options = WalkOptions::default();
+    assert_eq!(NimbleParser::get_current_version(temp_dir.path(), &options).unwrap(), Version::new(0, 4, 1));
+
+    NimbleParser::update_version(temp_dir.path(), &Version::new(1, 0, 0), &options).unwrap();
+
+    let content = fs::read_to_string(&nimble).unwrap();
+    assert!(content.contains("version       = \"1.0.0\""));
+    assert!(content.contains("requires \"nim >= 2.0.0\""));
+    assert!(content.contains("requires \"jester >= 0.6.0\""));
+}
+
+// ============================================================================
+// Dockerfile Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_dockerfile_update_existing_label() {
+    let temp_dir = TempDir::new().unwrap();
+    let dockerfile = temp_dir.path().join("Dockerfile");
+    fs::write(&dockerfile, "FROM alpine:3.19\nLABEL org.opencontainers.image.version=\"1.0.0\"\n").unwrap();
+
+    DockerfileParser::update_version(temp_dir.path(), &Version::new(1, 1, 0), &WalkOptions::default()).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(&dockerfile).unwrap(),
+        "FROM alpine:3.19\nLABEL org.opencontainers.image.version=\"1.1.0\"\n"
+    );
+}
+
+#[test]
+fn test_dockerfile_insert_missing_label() {
+    let temp_dir = TempDir::new().unwrap();
+    let dockerfile = temp_dir.path().join("Dockerfile");
+    fs::write(&dockerfile, "FROM alpine:3.19\n").unwrap();
+
+    // Left alone unless insertion is requested
+    DockerfileParser::update_version(temp_dir.path(), &Version::new(1, 0, 0), &WalkOptions::default()).unwrap();
+    assert_eq!(fs::read_to_string(&dockerfile).unwrap(), "FROM alpine:3.19\n");
+
+    let options = WalkOptions { insert_missing: true, ..Default::default() };
+    DockerfileParser::update_version(temp_dir.path(), &Version::new(1, 0, 0), &options).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(&dockerfile).unwrap(),
+        "FROM alpine:3.19\nLABEL org.opencontainers.image.version=\"1.0.0\"\n"
+    );
+    assert_eq!(DockerfileParser::get_current_version(temp_dir.path(), &options).unwrap(), Version::new(1, 0, 0));
+}
+
+// ============================================================================
+// Godot Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_godot_update_only_config_version() {
+    let temp_dir = TempDir::new().unwrap();
+    let project = temp_dir.path().join("project.godot");
+    let original = r#"; Engine configuration file.
+
+config_version=5
+
+[application]
+
+config/name="My Game"
+config/version="0.3.0"
+config/features=PackedStringArray("4.2", "Forward Plus")
+run/main_scene="res://main.tscn"
+
+[rendering]
+
+renderer/rendering_method="mobile"
+"#;
+    fs::write(&project, original).unwrap();
+
+    let options = WalkOptions::default();
+    assert_eq!(GodotParser::get_current_version(temp_dir.path(), &options).unwrap(), Version::new(0, 3, 0));
+
+    GodotParser::update_version(temp_dir.path(), &Version::new(0, 4, 0), &options).unwrap();
+
+    let content = fs::read_to_string(&project).unwrap();
+    assert_eq!(content, original.replace("config/version=\"0.3.0\"", "config/version=\"0.4.0\""));
+}
+
+// ============================================================================
+// Cabal Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_cabal_update_preserves_fourth_component_and_depends() {
+    let temp_dir = TempDir::new().unwrap();
+    let cabal = temp_dir.path().join("mylib.cabal");
+    let original = r#"cabal-version:      3.0
+name:               mylib
+version:            0.1.2.0
+synopsis:           A library
+
+library
+    exposed-modules:  MyLib
+    build-depends:
+        base >=4.14.0.0 && <5,
+        text ^>=2.0.1
+    default-language: Haskell2010
+"#;
+    fs::write(&cabal, original).unwrap();
+
+    let options = WalkOptions::default();
+    assert_eq!(CabalParser::get_current_version(temp_dir.path(), &options).unwrap(), Version::new(0, 1, 2));
+
+    CabalParser::update_version(temp_dir.path(), &Version::new(0, 2, 0), &options).unwrap();
+
+    let content = fs::read_to_string(&cabal).unwrap();
+    assert_eq!(content, original.replace("0.1.2.0", "0.2.0.0"));
+}
+
+// ============================================================================
+// Dune Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_dune_update_version_stanza() {
+    let temp_dir = TempDir::new().unwrap();
+    let dune_project = temp_dir.path().join("dune-project");
+    let original = r#"(lang dune 3.11)
+
+(name mylib)
+
+(version 1.2.3)
+
+(generate_opam_files true)
+
+(package
+ (name mylib)
+ (depends
+  (ocaml (>= 4.14.0))
+  (dune (>= 3.11))))
+"#;
+    fs::write(&dune_project, original).unwrap();
+
+    DuneParser::update_version(temp_dir.path(), &Version::new(2, 0, 0), &WalkOptions::default()).unwrap();
+
+    let content = fs::read_to_string(&dune_project).unwrap();
+    assert_eq!(content, original.replace("(version 1.2.3)", "(version 2.0.0)"));
+}
+
+// ============================================================================
+// ROS Package Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_ros_package_update_version() {
+    let temp_dir = TempDir::new().unwrap();
+    let package_dir = temp_dir.path().join("src").join("my_robot");
+    fs::create_dir_all(&package_dir).unwrap();
+    let package_xml = package_dir.join("package.xml");
+    let original = r#"<?xml version="1.0"?>
+<?xml-model href="http://download.ros.org/schema/package_format3.xsd" schematypens="http://www.w3.org/2001/XMLSchema"?>
+<package format="3">
+  <name>my_robot</name>
+  <version>0.2.1</version>
+  <description>Robot bringup</description>
+  <maintainer email="dev@example.com">Dev</maintainer>
+  <license>Apache-2.0</license>
+
+  <buildtool_depend>ament_cmake</buildtool_depend>
+  <depend version_gte="1.0.0">rclcpp</depend>
+</package>
+"#;
+    fs::write(&package_xml, original).unwrap();
+
+    let options = WalkOptions::default();
+    assert_eq!(RosPackageParser::get_current_version(temp_dir.path(), &options).unwrap(), Version::new(0, 2, 1));
+
+    RosPackageParser::update_version(temp_dir.path(), &Version::new(0, 3, 0), &options).unwrap();
+
+    let content = fs::read_to_string(&package_xml).unwrap();
+    assert_eq!(content, original.replace("<version>0.2.1</version>", "<version>0.3.0</version>"));
+}
+
+// ============================================================================
+// Lerna Parser Integration Tests
+// ============================================================================
+
+/// Creates a Lerna repo with `lerna_version` in lerna.json and two packages at 1.0.0
+fn create_lerna_repo(lerna_version: &str) -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("lerna.json"),
+        format!("{{\n  \"$schema\": \"node_modules/lerna/schemas/lerna-schema.json\",\n  \"version\": \"{lerna_version}\"\n}}\n"),
+    )
+    .unwrap();
+    for name in ["a", "b"] {
+        let dir = temp_dir.path().join("packages").join(name);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("package.json"), format!("{{\n  \"name\": \"{name}\",\n  \"version\": \"1.0.0\"\n}}\n")).unwrap();
+    }
+    temp_dir
+}
+
+#[test]
+fn test_lerna_fixed_mode() {
+    let temp_dir = create_lerna_repo("1.0.0");
+    let options = WalkOptions::default();
+    assert_eq!(LernaParser::get_current_version(temp_dir.path(), &options).unwrap(), Version::new(1, 0, 0));
+
+    let files = LernaParser::update_workspace(temp_dir.path(), &Version::new(1, 1, 0), &options).unwrap();
+
+    assert_eq!(files.len(), 1);
+    assert!(fs::read_to_string(temp_dir.path().join("lerna.json")).unwrap().contains("\"version\": \"1.1.0\""));
+    let package = temp_dir.path().join("packages").join("a").join("package.json");
+    assert!(fs::read_to_string(package).unwrap().contains("\"version\": \"1.0.0\""));
+}
+
+#[test]
+fn test_lerna_fixed_mode_fanout() {
+    let temp_dir = create_lerna_repo("1.0.0");
+    let options = WalkOptions { fanout: true, ..Default::default() };
+
+    let files = LernaParser::update_workspace(temp_dir.path(), &Version::new(2, 0, 0), &options).unwrap();
+
+    assert_eq!(files.len(), 3);
+    for name in ["a", "b"] {
+        let package = temp_dir.path().join("packages").join(name).join("package.json");
+        assert!(fs::read_to_string(package).unwrap().contains("\"version\": \"2.0.0\""));
+    }
+}
+
+#[test]
+fn test_lerna_independent_mode() {
+    let temp_dir = create_lerna_repo("independent");
+    let options = WalkOptions { lerna_strict: true, ..Default::default() };
+
+    // No single version to read, and setting one is refused without --fanout
+    assert!(LernaParser::get_current_version(temp_dir.path(), &options).is_err());
+    let err = LernaParser::update_workspace(temp_dir.path(), &Version::new(2, 0, 0), &options).unwrap_err();
+    assert!(err.to_string().contains("--fanout"));
+
+    // Reached without being targeted, the config is skipped rather than failing the run
+    let files = LernaParser::update_workspace(temp_dir.path(), &Version::new(2, 0, 0), &WalkOptions::default()).unwrap();
+    assert!(files.is_empty());
+
+    let options = WalkOptions { fanout: true, ..Default::default() };
+    LernaParser::update_workspace(temp_dir.path(), &Version::new(2, 0, 0), &options).unwrap();
+
+    assert!(fs::read_to_string(temp_dir.path().join("lerna.json")).unwrap().contains("\"version\": \"independent\""));
+    let package = temp_dir.path().join("packages").join("b").join("package.json");
+    assert!(fs::read_to_string(package).unwrap().contains("\"version\": \"2.0.0\""));
+}
+
+#[test]
+fn test_lerna_independent_skip_keeps_fixed_configs() {
+    let temp_dir = create_lerna_repo("1.0.0");
+    let nested = temp_dir.path().join("tools");
+    fs::create_dir_all(&nested).unwrap();
+    let independent = "{\n  \"version\": \"independent\"\n}\n";
+    fs::write(nested.join("lerna.json"), independent).unwrap();
+
+    let files = LernaParser::update_workspace(temp_dir.path(), &Version::new(1, 1, 0), &WalkOptions::default()).unwrap();
+
+    assert_eq!(files, vec![temp_dir.path().join("lerna.json")]);
+    assert!(fs::read_to_string(temp_dir.path().join("lerna.json")).unwrap().contains("\"version\": \"1.1.0\""));
+    assert_eq!(fs::read_to_string(nested.join("lerna.json")).unwrap(), independent);
+}
+
+#[test]
+fn test_lerna_fanout_under_all_updates_packages_once() {
+    use update_version::{arguments::SupportedTypes, version::update_version_for};
+
+    let temp_dir = create_lerna_repo("1.0.0");
+    let options = WalkOptions { fanout: true, ..Default::default() };
+
+    let files = update_version_for(SupportedTypes::All, temp_dir.path(), &Version::new(2, 0, 0), &options).unwrap();
+
+    let mut unique = files.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), files.len());
+    assert_eq!(files.len(), 3);
+}
+
+// ============================================================================
+// Env File Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_env_file_update_leaves_other_variables() {
+    let temp_dir = TempDir::new().unwrap();
+    let env_file = temp_dir.path().join(".env");
+    let original = "# Build settings\nNAME=my-app\nNODE_VERSION=20.11.1\nVERSION=\"1.2.3\"\nREGISTRY=ghcr.io/acme\n";
+    fs::write(&env_file, original).unwrap();
+
+    let options = WalkOptions::default();
+    assert_eq!(EnvFileParser::get_matching_files(temp_dir.path(), &options).unwrap(), vec![env_file.clone()]);
+
+    EnvFileParser::update_version(temp_dir.path(), &Version::new(1, 3, 0), &options).unwrap();
+
+    let content = fs::read_to_string(&env_file).unwrap();
+    assert_eq!(content, original.replace("VERSION=\"1.2.3\"", "VERSION=\"1.3.0\""));
+}
+
+#[test]
+fn test_env_file_custom_variable_bare_value() {
+    let temp_dir = TempDir::new().unwrap();
+    let env_file = temp_dir.path().join("ci.env");
+    fs::write(&env_file, "VERSION=9.9.9\nAPP_VERSION=0.1.0\n").unwrap();
+
+    let options = WalkOptions { env_variable: Some("APP_VERSION".to_string()), ..Default::default() };
+    EnvFileParser::update_version(temp_dir.path(), &Version::new(0, 2, 0), &options).unwrap();
+
+    assert_eq!(fs::read_to_string(&env_file).unwrap(), "VERSION=9.9.9\nAPP_VERSION=0.2.0\n");
+}
+
+// ============================================================================
+// setup.cfg Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_setup_cfg_literal_version() {
+    let temp_dir = TempDir::new().unwrap();
+    let setup_cfg = temp_dir.path().join("setup.cfg");
+    let original = "[metadata]\nname = mypkg\nversion = 1.2.3\n\n[options]\npython_requires = >=3.8\ninstall_requires =\n    requests>=2.31.0\n";
+    fs::write(&setup_cfg, original).unwrap();
+
+    let options = WalkOptions::default();
+    assert_eq!(SetupCfgParser::get_current_version(temp_dir.path(), &options).unwrap(), Version::new(1, 2, 3));
+
+    SetupCfgParser::update_version(temp_dir.path(), &Version::new(1, 3, 0), &options).unwrap();
+
+    assert_eq!(fs::read_to_string(&setup_cfg).unwrap(), original.replace("version = 1.2.3", "version = 1.3.0"));
+}
+
+#[test]
+fn test_setup_cfg_attr_reference() {
+    let temp_dir = TempDir::new().unwrap();
+    let setup_cfg = temp_dir.path().join("setup.cfg");
+    let original = "[metadata]\nname = mypkg\nversion = attr: mypkg.__version__\n";
+    fs::write(&setup_cfg, original).unwrap();
+    let package = temp_dir.path().join("src").join("mypkg");
+    fs::create_dir_all(&package).unwrap();
+    let init = package.join("__init__.py");
+    fs::write(&init, "\"\"\"My package.\"\"\"\n\n__version__ = \"0.4.0\"\n").unwrap();
+
+    let options = WalkOptions::default();
+    assert_eq!(SetupCfgParser::get_matching_files(temp_dir.path(), &options).unwrap(), vec![init.clone()]);
+    assert_eq!(SetupCfgParser::get_current_version(temp_dir.path(), &options).unwrap(), Version::new(0, 4, 0));
+
+    SetupCfgParser::update_version(temp_dir.path(), &Version::new(0, 5, 0), &options).unwrap();
+
+    assert_eq!(fs::read_to_string(&init).unwrap(), "\"\"\"My package.\"\"\"\n\n__version__ = \"0.5.0\"\n");
+    assert_eq!(fs::read_to_string(&setup_cfg).unwrap(), original);
+}
+
+// ============================================================================
+// Generic JSON Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_generic_json_pointer_update() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().join("config");
+    fs::create_dir_all(&config_dir).unwrap();
+    let target = config_dir.join("app.json");
+    let original = "{\n  \"version\": \"0.0.1\",\n  \"a\": {\n    \"b\": {\n      \"version\": \"1.2.3\"\n    },\n    \"list\": [1, 2, 3]\n  }\n}\n";
+    fs::write(&target, original).unwrap();
+    // Matches the glob but has no value at the pointer
+    fs::write(config_dir.join("other.json"), "{\"version\": \"9.9.9\"}\n").unwrap();
+    // Holds the pointer but doesn't match the glob
+    let ignored = temp_dir.path().join("app.json");
+    fs::write(&ignored, "{\"a\": {\"b\": {\"version\": \"1.2.3\"}}}\n").unwrap();
+
+    let options = WalkOptions {
+        json_pointer: Some("/a/b/version".to_string()),
+        json_file_glob: Some("config/*.json".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(GenericJsonParser::get_matching_files(temp_dir.path(), &options).unwrap(), vec![target.clone()]);
+    assert_eq!(GenericJsonParser::get_current_version(temp_dir.path(), &options).unwrap(), Version::new(1, 2, 3));
+
+    GenericJsonParser::update_version(temp_dir.path(), &Version::new(1, 3, 0), &options).unwrap();
+
+    assert_eq!(fs::read_to_string(&target).unwrap(), original.replace("1.2.3", "1.3.0"));
+    assert_eq!(fs::read_to_string(&ignored).unwrap(), "{\"a\": {\"b\": {\"version\": \"1.2.3\"}}}\n");
+}
+
+#[test]
+fn test_generic_json_without_pointer_matches_nothing() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("app.json"), "{\"version\": \"1.0.0\"}\n").unwrap();
+
+    let options = WalkOptions::default();
+    assert!(GenericJsonParser::get_matching_files(temp_dir.path(), &options).unwrap().is_empty());
+}
+
+// ============================================================================
+// Generic TOML Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_generic_toml_key_path_update() {
+    let temp_dir = TempDir::new().unwrap();
+    let cargo_toml = temp_dir.path().join("Cargo.toml");
+    let original = r#"[package]
+name = "myapp"
+version = "0.1.0" # crate version, left alone
+
+# Version reported by the bundled app
+[package.metadata.app]
+name = "My App"
+version = "2.4.1"   # keep in sync with the store listing
+"#;
+    fs::write(&cargo_toml, original).unwrap();
+
+    let options = WalkOptions {
+        toml_key: Some("package.metadata.app.version".to_string()),
+        toml_file_glob: Some("Cargo.toml".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(GenericTomlParser::get_matching_files(temp_dir.path(), &options).unwrap(), vec![cargo_toml.clone()]);
+    assert_eq!(GenericTomlParser::get_current_version(temp_dir.path(), &options).unwrap(), Version::new(2, 4, 1));
+
+    GenericTomlParser::update_version(temp_dir.path(), &Version::new(2, 5, 0), &options).unwrap();
+
+    assert_eq!(fs::read_to_string(&cargo_toml).unwrap(), original.replace("2.4.1", "2.5.0"));
+}
+
+#[test]
+fn test_generic_toml_keeps_v_prefix() {
+    let temp_dir = TempDir::new().unwrap();
+    let app_toml = temp_dir.path().join("app.toml");
+    fs::write(&app_toml, "name = \"app\"\nversion = \"v1.0.0\"\n").unwrap();
+
+    let options = WalkOptions {
+        toml_key: Some("version".to_string()),
+        toml_file_glob: Some("app.toml".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(GenericTomlParser::get_current_version(temp_dir.path(), &options).unwrap(), Version::new(1, 0, 0));
+
+    GenericTomlParser::update_version(temp_dir.path(), &Version::new(2, 0, 0), &options).unwrap();
+
+    assert_eq!(fs::read_to_string(&app_toml).unwrap(), "name = \"app\"\nversion = \"v2.0.0\"\n");
+}
+
+// ============================================================================
+// pubspec.yaml Parser Integration Tests
+// ============================================================================
+
+const PUBSPEC: &str = "name: my_app\ndescription: A Flutter app.\nversion: 1.2.3+45\n\nenvironment:\n  sdk: '>=3.0.0 <4.0.0'\n";
+
+#[test]
+fn test_pubspec_flutter_bump_build_only() {
+    let temp_dir = TempDir::new().unwrap();
+    let pubspec = temp_dir.path().join("pubspec.yaml");
+    fs::write(&pubspec, PUBSPEC).unwrap();
+
+    let options = WalkOptions::default();
+    let current = PubspecParser::get_current_version(temp_dir.path(), &options).unwrap();
+    assert_eq!(current.to_string(), "1.2.3+45");
+
+    let next = PubspecParser::flutter_bump(&current, None, "rc").unwrap();
+    PubspecParser::update_version(temp_dir.path(), &next, &options).unwrap();
+
+    assert_eq!(fs::read_to_string(&pubspec).unwrap(), PUBSPEC.replace("1.2.3+45", "1.2.3+46"));
+}
+
+#[test]
+fn test_pubspec_flutter_bump_patch_and_build() {
+    let temp_dir = TempDir::new().unwrap();
+    let pubspec = temp_dir.path().join("pubspec.yaml");
+    fs::write(&pubspec, PUBSPEC).unwrap();
+
+    let options = WalkOptions::default();
+    let current = PubspecParser::get_current_version(temp_dir.path(), &options).unwrap();
+    let next = PubspecParser::flutter_bump(&current, Some(BumpKind::Patch), "rc").unwrap();
+    PubspecParser::update_version(temp_dir.path(), &next, &options).unwrap();
+
+    assert_eq!(fs::read_to_string(&pubspec).unwrap(), PUBSPEC.replace("1.2.3+45", "1.2.4+46"));
+}
+
+// ============================================================================
+// README Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_readme_updates_cargo_add_snippet() {
+    let temp_dir = TempDir::new().unwrap();
+    let readme = temp_dir.path().join("README.md");
+    let original = "# update-version\n\n## Install\n\n```sh\ncargo add update-version@1.2.3\n```\n\nRequires serde 1.2.3 or newer.\n";
+    fs::write(&readme, original).unwrap();
+
+    let options = WalkOptions {
+        readme_patterns: vec!["cargo add update-version@{version}".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(ReadmeParser::get_matching_files(temp_dir.path(), &options).unwrap(), vec![readme.clone()]);
+    assert_eq!(ReadmeParser::get_current_version(temp_dir.path(), &options).unwrap(), Version::new(1, 2, 3));
+
+    ReadmeParser::update_version(temp_dir.path(), &Version::new(1, 3, 0), &options).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(&readme).unwrap(),
+        original.replace("update-version@1.2.3", "update-version@1.3.0")
+    );
+}
+
+#[test]
+fn test_readme_without_pattern_matches_nothing() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("README.md"), "cargo add update-version@1.2.3\n").unwrap();
+
+    assert!(ReadmeParser::get_matching_files(temp_dir.path(), &WalkOptions::default()).unwrap().is_empty());
+}
+
+// ============================================================================
+// Unity Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_unity_updates_bundle_version() {
+    let temp_dir = TempDir::new().unwrap();
+    let settings_dir = temp_dir.path().join("ProjectSettings");
+    fs::create_dir_all(&settings_dir).unwrap();
+    let settings = settings_dir.join("ProjectSettings.asset");
+    let original = "%YAML 1.1\n%TAG !u! tag:unity3d.com,2011:\n--- !u!129 &1\nPlayerSettings:\n  companyName: Studio\n  productName: My Game\n  bundleVersion: 0.9.0\n  AndroidBundleVersionCode: 3\n  buildNumber:\n    Standalone: 0\n    iPhone: 5\n";
+    fs::write(&settings, original).unwrap();
+
+    let options = WalkOptions::default();
+    assert_eq!(UnityParser::get_current_version(temp_dir.path(), &options).unwrap(), Version::new(0, 9, 0));
+
+    UnityParser::update_version(temp_dir.path(), &Version::new(1, 0, 0), &options).unwrap();
+
+    assert_eq!(fs::read_to_string(&settings).unwrap(), original.replace("bundleVersion: 0.9.0", "bundleVersion: 1.0.0"));
+}
+
+// ============================================================================
+// Bukkit Plugin Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_bukkit_plugin_updates_only_version() {
+    let temp_dir = TempDir::new().unwrap();
+    let resources = temp_dir.path().join("src").join("main").join("resources");
+    fs::create_dir_all(&resources).unwrap();
+    let plugin = resources.join("plugin.yml");
+    let paper = resources.join("paper-plugin.yml");
+    let original = "name: MyPlugin\nversion: '1.4.0'\nmain: com.example.MyPlugin\napi-version: '1.20'\n";
+    fs::write(&plugin, original).unwrap();
+    fs::write(&paper, "name: MyPlugin\nversion: 1.4.0\nmain: com.example.MyPlugin\napi-version: 1.20.4\n").unwrap();
+
+    let options = WalkOptions::default();
+    assert_eq!(BukkitPluginParser::get_current_version(temp_dir.path(), &options).unwrap(), Version::new(1, 4, 0));
+
+    BukkitPluginParser::update_version(temp_dir.path(), &Version::new(1, 5, 0), &options).unwrap();
+
+    assert_eq!(fs::read_to_string(&plugin).unwrap(), original.replace("'1.4.0'", "'1.5.0'"));
+    assert_eq!(
+        fs::read_to_string(&paper).unwrap(),
+        "name: MyPlugin\nversion: 1.5.0\nmain: com.example.MyPlugin\napi-version: 1.20.4\n"
+    );
+}
+
+// ============================================================================
+// .NET Project Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_dotnet_split_prefix_and_suffix() {
+    let temp_dir = TempDir::new().unwrap();
+    let props = temp_dir.path().join("Directory.Build.props");
+    let original = "<Project>\n  <PropertyGroup>\n    <VersionPrefix>2.1.0</VersionPrefix>\n    <VersionSuffix>preview.3</VersionSuffix>\n  </PropertyGroup>\n</Project>\n";
+    fs::write(&props, original).unwrap();
+    fs::create_dir_all(temp_dir.path().join("src").join("App")).unwrap();
+    let csproj = temp_dir.path().join("src").join("App").join("App.csproj");
+    fs::write(&csproj, "<Project Sdk=\"Microsoft.NET.Sdk\">\n  <PropertyGroup>\n    <TargetFramework>net8.0</TargetFramework>\n  </PropertyGroup>\n</Project>\n").unwrap();
+
+    let options = WalkOptions::default();
+    assert_eq!(
+        DotnetProjectParser::get_current_version(temp_dir.path(), &options).unwrap(),
+        Version::parse("2.1.0-preview.3").unwrap()
+    );
+
+    DotnetProjectParser::update_version(temp_dir.path(), &Version::parse("2.2.0-rc.1").unwrap(), &options).unwrap();
+    assert_eq!(
+        fs::read_to_string(&props).unwrap(),
+        original.replace("2.1.0", "2.2.0").replace("preview.3", "rc.1")
+    );
+    assert!(!fs::read_to_string(&csproj).unwrap().contains("Version"));
+    assert_eq!(
+        DotnetProjectParser::get_current_version(temp_dir.path(), &options).unwrap(),
+        Version::parse("2.2.0-rc.1").unwrap()
+    );
+}
+
+// ============================================================================
+// CMake Parser Integration Tests
+// ============================================================================
+
+#[test]
+fn test_cmake_updates_project_version() {
+    let temp_dir = TempDir::new().unwrap();
+    let cmake_lists = temp_dir.path().join("CMakeLists.txt");
+    let original = "cmake_minimum_required(VERSION 3.16.0)\nproject(MyApp\n  VERSION 1.2.3.7\n  LANGUAGES CXX)\n\nadd_executable(my_app main.cpp)\n";
+    fs::write(&cmake_lists, original).unwrap();
+
+    let options = WalkOptions::default();
+    assert_eq!(CMakeParser::get_current_version(temp_dir.path(), &options).unwrap(), Version::new(1, 2, 3));
+
+    CMakeParser::update_version(temp_dir.path(), &Version::parse("1.3.0-rc.1").unwrap(), &options).unwrap();
+
+    assert_eq!(fs::read_to_string(&cmake_lists).unwrap(), original.replace("1.2.3.7", "1.3.0.7"));
+}
+
+#[test]
+fn test_cmake_leaves_nested_subprojects_alone() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("CMakeLists.txt"), "project(MyApp VERSION 1.2.3)
+add_subdirectory(third_party/zlib)
+").unwrap();
+    let vendored = temp_dir.path().join("third_party").join("zlib");
+    fs::create_dir_all(&vendored).unwrap();
+    let zlib = "project(zlib VERSION 1.3.1 LANGUAGES C)\n";
+    fs::write(vendored.join("CMakeLists.txt"), zlib).unwrap();
+
+    let options = WalkOptions::default();
+    let files = CMakeParser::update_version(temp_dir.path(), &Version::new(1, 2, 4), &options).unwrap();
+
+    assert_eq!(files, vec![temp_dir.path().join("CMakeLists.txt")]);
+    assert_eq!(fs::read_to_string(vendored.join("CMakeLists.txt")).unwrap(), zlib);
+}
+
+// ============================================================================
+// Version Consistency Integration Tests
+// ============================================================================
+
+#[test]
+fn test_assert_consistent_lists_divergent_files() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("Cargo.toml"), "[package]\nname = \"app\"\nversion = \"1.2.0\"\n").unwrap();
+    fs::create_dir_all(temp_dir.path().join("core")).unwrap();
+    fs::write(temp_dir.path().join("core").join("Cargo.toml"), "[package]\nname = \"core\"\nversion = \"1.1.0\"\n").unwrap();
+
+    assert_eq!(
+        TomlParser::get_current_version(temp_dir.path(), &WalkOptions::default()).unwrap(),
+        Version::new(1, 2, 0)
+    );
+
+    let options = WalkOptions { assert_consistent: true, ..Default::default() };
+    let err = TomlParser::get_current_version(temp_dir.path(), &options).unwrap_err();
+    assert!(matches!(err.downcast_ref::<ParsingError>(), Some(ParsingError::InconsistentVersionsError(_))));
+    let message = err.to_string();
+    assert!(message.contains("Cargo.toml: 1.2.0"), "{}", message);
+    assert!(message.contains("Cargo.toml: 1.1.0"), "{}", message);
+
+    fs::write(temp_dir.path().join("core").join("Cargo.toml"), "[package]\nname = \"core\"\nversion = \"1.2.0\"\n").unwrap();
+    assert_eq!(TomlParser::get_current_version(temp_dir.path(), &options).unwrap(), Version::new(1, 2, 0));
+}
+
+// ============================================================================
+// Internal Dependency Integration Tests
+// ============================================================================
+
+#[test]
+fn test_toml_update_internal_deps() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("Cargo.toml"), "[workspace]\nmembers = [\"a\", \"b\"]\n").unwrap();
+    fs::create_dir_all(temp_dir.path().join("a")).unwrap();
+    fs::create_dir_all(temp_dir.path().join("b")).unwrap();
+    let a_toml = temp_dir.path().join("a").join("Cargo.toml");
+    let b_toml = temp_dir.path().join("b").join("Cargo.toml");
+    let a_original = r#"[package]
+name = "a"
+version = "1.2.3"
+
+[dependencies]
+b = "1.2.3" # internal, no path
+serde = "1.2.3"
+b-renamed = { package = "b", version = "^1.2.3", features = ["x"] }
+
+[target.'cfg(unix)'.dev-dependencies]
+b = { version = "=1.2.3" }
+"#;
+    fs::write(&a_toml, a_original).unwrap();
+    fs::write(&b_toml, "[package]\nname = \"b\"\nversion = \"1.2.3\"\n").unwrap();
+
+    let options = WalkOptions { update_internal_deps: true, ..Default::default() };
+    let files = TomlParser::update_workspace(temp_dir.path(), &Version::new(1, 3, 0), &options).unwrap();
+    assert!(files.contains(&a_toml) && files.contains(&b_toml));
+
+    assert_eq!(
+        fs::read_to_string(&a_toml).unwrap(),
+        a_original
+            .replace("version = \"1.2.3\"", "version = \"1.3.0\"")
+            .replace("b = \"1.2.3\"", "b = \"1.3.0\"")
+            .replace("\"^1.2.3\"", "\"^1.3.0\"")
+            .replace("\"=1.2.3\"", "\"=1.3.0\"")
+    );
+    assert!(fs::read_to_string(&a_toml).unwrap().contains("serde = \"1.2.3\""));
+    assert_eq!(fs::read_to_string(&b_toml).unwrap(), "[package]\nname = \"b\"\nversion = \"1.3.0\"\n");
+}
+
+#[test]
+fn test_toml_internal_deps_skip_excluded_members() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().join("Cargo.toml");
+    let root_original = "[workspace]\nmembers = [\"core\"]\n\n[package]\nname = \"app\"\nversion = \"1.0.0\"\n\n[dependencies]\ncore = \"0.3.0\"\n";
+    fs::write(&root, root_original).unwrap();
+    fs::create_dir_all(temp_dir.path().join("core")).unwrap();
+    let core_toml = temp_dir.path().join("core").join("Cargo.toml");
+    let core_original = "[package]\nname = \"core\"\nversion = \"0.3.0\"\n";
+    fs::write(&core_toml, core_original).unwrap();
+
+    let options = WalkOptions { update_internal_deps: true, exclude_workspace_members: true, ..Default::default() };
+    let files = TomlParser::update_workspace(temp_dir.path(), &Version::new(1, 1, 0), &options).unwrap();
+
+    assert_eq!(files, vec![root.clone()]);
+    assert_eq!(fs::read_to_string(&root).unwrap(), root_original.replace("1.0.0", "1.1.0"));
+    assert_eq!(fs::read_to_string(&core_toml).unwrap(), core_original);
+}
+
+#[test]
+fn test_toml_internal_deps_off_by_default() {
+    let temp_dir = TempDir::new().unwrap();
+    let a_toml = temp_dir.path().join("Cargo.toml");
+    fs::create_dir_all(temp_dir.path().join("b")).unwrap();
+    fs::write(&a_toml, "[package]\nname = \"a\"\nversion = \"1.0.0\"\n\n[dependencies]\nb = \"1.0.0\"\n").unwrap();
+    fs::write(temp_dir.path().join("b").join("Cargo.toml"), "[package]\nname = \"b\"\nversion = \"1.0.0\"\n").unwrap();
+
+    TomlParser::update_workspace(temp_dir.path(), &Version::new(1, 1, 0), &WalkOptions::default()).unwrap();
+
+    assert!(fs::read_to_string(&a_toml).unwrap().contains("b = \"1.0.0\""));
+}
+
+// ============================================================================
+// Output Ordering Tests
+// ============================================================================
+
+#[test]
+fn test_update_version_returns_sorted_paths() {
+    let temp_dir = TempDir::new().unwrap();
+    for dir in ["", "z", "a/b", "m"] {
+        let dir = temp_dir.path().join(dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("Cargo.toml"), "[package]\nname = \"x\"\nversion = \"1.0.0\"\n").unwrap();
+    }
+
+    let files = TomlParser::update_version(temp_dir.path(), &Version::new(1, 1, 0), &WalkOptions::default()).unwrap();
+
+    let mut sorted = files.clone();
+    sorted.sort();
+    assert_eq!(files.len(), 4);
+    assert_eq!(files, sorted);
+    // The walk itself still lists the shallowest manifest first, so it is the one read for the current version
+    let walked = TomlParser::get_matching_files(temp_dir.path(), &WalkOptions::default()).unwrap();
+    assert_eq!(walked[0], temp_dir.path().join("Cargo.toml"));
+}
+
+// ============================================================================
+// File Size Limit Tests
+// ============================================================================
+
+#[test]
+fn test_max_file_size_skips_larger_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let contents = "{\n  \"version\": \"1.0.0\"\n}\n";
+    let limit = contents.len() as u64;
+    let under = temp_dir.path().join("under").join("package.json");
+    let over = temp_dir.path().join("over").join("package.json");
+    fs::create_dir_all(under.parent().unwrap()).unwrap();
+    fs::create_dir_all(over.parent().unwrap()).unwrap();
+    fs::write(&under, contents).unwrap();
+    fs::write(&over, format!("{contents} ")).unwrap();
+
+    let options = WalkOptions { max_file_size: Some(limit), ..Default::default() };
+    assert_eq!(PackageJsonParser::get_matching_files(temp_dir.path(), &options).unwrap(), vec![under.clone()]);
+
+    let files = PackageJsonParser::update_version(temp_dir.path(), &Version::new(1, 1, 0), &options).unwrap();
+
+    assert_eq!(files, vec![under.clone()]);
+    assert!(fs::read_to_string(&under).unwrap().contains("1.1.0"));
+    assert!(fs::read_to_string(&over).unwrap().contains("1.0.0"));
+}
+
+// ============================================================================
+// Workspace Member Exclusion Tests
+// ============================================================================
+
+#[test]
+fn test_toml_exclude_workspace_members() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().join("Cargo.toml");
+    let root_original = "[workspace]\nmembers = [\"crates/*\", \"tools/cli\"]\n\n[package]\nname = \"root\"\nversion = \"1.0.0\"\n";
+    fs::write(&root, root_original).unwrap();
+    let member_manifest = "[package]\nname = \"member\"\nversion = \"0.3.0\"\n";
+    for member in ["crates/core", "tools/cli"] {
+        let dir = temp_dir.path().join(member);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("Cargo.toml"), member_manifest).unwrap();
+    }
+
+    let options = WalkOptions { exclude_workspace_members: true, ..Default::default() };
+    assert_eq!(TomlParser::get_matching_files(temp_dir.path(), &options).unwrap(), vec![root.clone()]);
+
+    TomlParser::update_version(temp_dir.path(), &Version::new(1, 1, 0), &options).unwrap();
+
+    assert_eq!(fs::read_to_string(&root).unwrap(), root_original.replace("1.0.0", "1.1.0"));
+    for member in ["crates/core", "tools/cli"] {
+        assert_eq!(fs::read_to_string(temp_dir.path().join(member).join("Cargo.toml")).unwrap(), member_manifest);
+    }
+}